@@ -114,6 +114,7 @@ test('create a new asset with plugins', async (t) => {
                     basisPoints: 5,
                     creators: [{ address: umi.identity.publicKey, percentage: 100 }],
                     ruleSet: ruleSet('None'),
+                    royaltyPayments: false,
                 },
             }),
             pluginAuthorityPair({ type: 'FreezeDelegate', data: { frozen: true } }),
@@ -176,6 +177,7 @@ test('create a new asset with plugins and empty collection', async (t) => {
                     basisPoints: 5,
                     creators: [{ address: umi.identity.publicKey, percentage: 100 }],
                     ruleSet: ruleSet('None'),
+                    royaltyPayments: false,
                 },
             }),
             pluginAuthorityPair({ type: 'FreezeDelegate', data: { frozen: true } }),
@@ -329,7 +329,8 @@ test('list an asset with collection royalties', async (t) => {
                 creators: [{ address: umi.identity.publicKey, percentage: 100 }],
                 ruleSet: {
                     __kind: "None"
-                }
+                },
+                royaltyPayments: false,
             },
         })]
     }));
@@ -405,7 +406,8 @@ test('sell an asset with collection royalties', async (t) => {
                 creators: [{ address: umi.identity.publicKey, percentage: 100 }],
                 ruleSet: {
                     __kind: "None"
-                }
+                },
+                royaltyPayments: false,
             },
         })]
     }));
@@ -110,6 +110,7 @@ test('transfer an asset with plugins', async (t) => {
                     basisPoints: 5,
                     creators: [{ address: umi.identity.publicKey, percentage: 100 }],
                     ruleSet: ruleSet('None'),
+                    royaltyPayments: false,
                 },
             }),
             pluginAuthorityPair({ type: 'FreezeDelegate', data: { frozen: false } }),
@@ -170,6 +171,7 @@ test('transfer an asset with plugins and empty collection', async (t) => {
                     basisPoints: 5,
                     creators: [{ address: umi.identity.publicKey, percentage: 100 }],
                     ruleSet: ruleSet('None'),
+                    royaltyPayments: false,
                 },
             }),
             pluginAuthorityPair({ type: 'FreezeDelegate', data: { frozen: false } }),
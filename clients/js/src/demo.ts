@@ -144,6 +144,7 @@ const advancedExamples = async () => {
           },
         ],
         ruleSet: ruleSet('None'), // Compatibility rule set
+        royaltyPayments: false,
       },
     ],
   }).sendAndConfirm(umi);
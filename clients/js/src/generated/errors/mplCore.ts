@@ -607,6 +607,131 @@ export class InvalidPluginOperationError extends ProgramError {
 }
 codeToErrorMap.set(0x29, InvalidPluginOperationError);
 nameToErrorMap.set('InvalidPluginOperation', InvalidPluginOperationError);
+/** InvalidLifecycleHookResult: Could not read validation result returned by lifecycle hook */
+export class InvalidLifecycleHookResultError extends ProgramError {
+  override readonly name: string = 'InvalidLifecycleHookResult';
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super('Could not read validation result returned by lifecycle hook', program, cause);
+  }
+}
+codeToErrorMap.set(0x2a, InvalidLifecycleHookResultError);
+nameToErrorMap.set('InvalidLifecycleHookResult', InvalidLifecycleHookResultError);
+/** CannotCompressExternalPluginAdapters: Cannot compress an asset with external plugin adapters */
+export class CannotCompressExternalPluginAdaptersError extends ProgramError {
+  override readonly name: string = 'CannotCompressExternalPluginAdapters';
+
+  readonly code: number = 0x2b; // 43
+
+  constructor(program: Program, cause?: Error) {
+    super('Cannot compress an asset with external plugin adapters', program, cause);
+  }
+}
+codeToErrorMap.set(0x2b, CannotCompressExternalPluginAdaptersError);
+nameToErrorMap.set('CannotCompressExternalPluginAdapters', CannotCompressExternalPluginAdaptersError);
+/** PermanentDelegatesPreventMove: Cannot move asset to collection with permanent delegates */
+export class PermanentDelegatesPreventMoveError extends ProgramError {
+  override readonly name: string = 'PermanentDelegatesPreventMove';
+
+  readonly code: number = 0x2c; // 44
+
+  constructor(program: Program, cause?: Error) {
+    super('Cannot move asset to collection with permanent delegates', program, cause);
+  }
+}
+codeToErrorMap.set(0x2c, PermanentDelegatesPreventMoveError);
+nameToErrorMap.set('PermanentDelegatesPreventMove', PermanentDelegatesPreventMoveError);
+/** MaxSupplyReached: Master edition max supply reached */
+export class MaxSupplyReachedError extends ProgramError {
+  override readonly name: string = 'MaxSupplyReached';
+
+  readonly code: number = 0x2d; // 45
+
+  constructor(program: Program, cause?: Error) {
+    super('Master edition max supply reached', program, cause);
+  }
+}
+codeToErrorMap.set(0x2d, MaxSupplyReachedError);
+nameToErrorMap.set('MaxSupplyReached', MaxSupplyReachedError);
+/** InvalidMultisigAuthority: Multisig authority must have a threshold between one and the number of unique signers */
+export class InvalidMultisigAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidMultisigAuthority';
+
+  readonly code: number = 0x2e; // 46
+
+  constructor(program: Program, cause?: Error) {
+    super('Multisig authority must have a threshold between one and the number of unique signers', program, cause);
+  }
+}
+codeToErrorMap.set(0x2e, InvalidMultisigAuthorityError);
+nameToErrorMap.set('InvalidMultisigAuthority', InvalidMultisigAuthorityError);
+/** InvalidExecutePda: Invalid asset signer PDA, it must be derived from the asset */
+export class InvalidExecutePdaError extends ProgramError {
+  override readonly name: string = 'InvalidExecutePda';
+
+  readonly code: number = 0x2f; // 47
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid asset signer PDA, it must be derived from the asset', program, cause);
+  }
+}
+codeToErrorMap.set(0x2f, InvalidExecutePdaError);
+nameToErrorMap.set('InvalidExecutePda', InvalidExecutePdaError);
+/** InvalidRoyaltyCreator: Invalid or missing creator account for royalty payment */
+export class InvalidRoyaltyCreatorError extends ProgramError {
+  override readonly name: string = 'InvalidRoyaltyCreator';
+
+  readonly code: number = 0x30; // 48
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid or missing creator account for royalty payment', program, cause);
+  }
+}
+codeToErrorMap.set(0x30, InvalidRoyaltyCreatorError);
+nameToErrorMap.set('InvalidRoyaltyCreator', InvalidRoyaltyCreatorError);
+/** MissingInstructionsSysvar: Missing instructions sysvar needed to identify the invoking program */
+export class MissingInstructionsSysvarError extends ProgramError {
+  override readonly name: string = 'MissingInstructionsSysvar';
+
+  readonly code: number = 0x31; // 49
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing instructions sysvar needed to identify the invoking program', program, cause);
+  }
+}
+codeToErrorMap.set(0x31, MissingInstructionsSysvarError);
+nameToErrorMap.set('MissingInstructionsSysvar', MissingInstructionsSysvarError);
+/** AttributeNotFound: Attribute not found */
+export class AttributeNotFoundError extends ProgramError {
+  override readonly name: string = 'AttributeNotFound';
+
+  readonly code: number = 0x32; // 50
+
+  constructor(program: Program, cause?: Error) {
+    super('Attribute not found', program, cause);
+  }
+}
+codeToErrorMap.set(0x32, AttributeNotFoundError);
+nameToErrorMap.set('AttributeNotFound', AttributeNotFoundError);
+
+/** RoyaltyUnderpaid: Sale price is too low to pay every creator their royalties */
+export class RoyaltyUnderpaidError extends ProgramError {
+  override readonly name: string = 'RoyaltyUnderpaid';
+
+  readonly code: number = 0x33; // 51
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Sale price is too low to pay every creator their royalties',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x33, RoyaltyUnderpaidError);
+nameToErrorMap.set('RoyaltyUnderpaid', RoyaltyUnderpaidError);
 
 /**
  * Attempts to resolve a custom program error from the provided error code.
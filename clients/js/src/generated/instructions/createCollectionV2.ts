@@ -50,6 +50,8 @@ export type CreateCollectionV2InstructionAccounts = {
   payer?: Signer;
   /** The system program */
   systemProgram?: PublicKey | Pda;
+  /** The SPL Noop Program */
+  logWrapper?: PublicKey | Pda;
 };
 
 // Data.
@@ -142,6 +144,11 @@ export function createCollectionV2(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    logWrapper: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -14,11 +14,13 @@ import {
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
   array,
+  bool,
   mapSerializer,
   option,
   string,
@@ -70,6 +72,7 @@ export type CreateV2InstructionData = {
   uri: string;
   plugins: Option<Array<PluginAuthorityPair>>;
   externalPluginAdapters: Option<Array<BaseExternalPluginAdapterInitInfo>>;
+  autoNumberEdition: Option<boolean>;
 };
 
 export type CreateV2InstructionDataArgs = {
@@ -80,6 +83,7 @@ export type CreateV2InstructionDataArgs = {
   externalPluginAdapters?: OptionOrNullable<
     Array<BaseExternalPluginAdapterInitInfoArgs>
   >;
+  autoNumberEdition?: OptionOrNullable<boolean>;
 };
 
 export function getCreateV2InstructionDataSerializer(): Serializer<
@@ -102,6 +106,7 @@ export function getCreateV2InstructionDataSerializer(): Serializer<
           'externalPluginAdapters',
           option(array(getBaseExternalPluginAdapterInitInfoSerializer())),
         ],
+        ['autoNumberEdition', option(bool())],
       ],
       { description: 'CreateV2InstructionData' }
     ),
@@ -111,6 +116,7 @@ export function getCreateV2InstructionDataSerializer(): Serializer<
       dataState: value.dataState ?? DataState.AccountState,
       plugins: value.plugins ?? [],
       externalPluginAdapters: value.externalPluginAdapters ?? [],
+      autoNumberEdition: value.autoNumberEdition ?? none(),
     })
   ) as Serializer<CreateV2InstructionDataArgs, CreateV2InstructionData>;
 }
@@ -21,6 +21,9 @@ export * from './createCollectionV2';
 export * from './createV1';
 export * from './createV2';
 export * from './decompressV1';
+export * from './executeV1';
+export * from './removeAttributeV1';
+export * from './removeCollectionAttributeV1';
 export * from './removeCollectionExternalPluginAdapterV1';
 export * from './removeCollectionPluginV1';
 export * from './removeExternalPluginAdapterV1';
@@ -34,5 +37,8 @@ export * from './updateCollectionV1';
 export * from './updateExternalPluginAdapterV1';
 export * from './updatePluginV1';
 export * from './updateV1';
+export * from './updateV2';
+export * from './upsertAttributeV1';
+export * from './upsertCollectionAttributeV1';
 export * from './writeCollectionExternalPluginAdapterDataV1';
 export * from './writeExternalPluginAdapterDataV1';
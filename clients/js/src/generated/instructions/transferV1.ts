@@ -22,6 +22,7 @@ import {
   mapSerializer,
   option,
   struct,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -57,10 +58,12 @@ export type TransferV1InstructionAccounts = {
 export type TransferV1InstructionData = {
   discriminator: number;
   compressionProof: Option<CompressionProof>;
+  salePrice: Option<bigint>;
 };
 
 export type TransferV1InstructionDataArgs = {
   compressionProof?: OptionOrNullable<CompressionProofArgs>;
+  salePrice?: OptionOrNullable<number | bigint>;
 };
 
 export function getTransferV1InstructionDataSerializer(): Serializer<
@@ -76,6 +79,7 @@ export function getTransferV1InstructionDataSerializer(): Serializer<
       [
         ['discriminator', u8()],
         ['compressionProof', option(getCompressionProofSerializer())],
+        ['salePrice', option(u64())],
       ],
       { description: 'TransferV1InstructionData' }
     ),
@@ -83,6 +87,7 @@ export function getTransferV1InstructionDataSerializer(): Serializer<
       ...value,
       discriminator: 14,
       compressionProof: value.compressionProof ?? none(),
+      salePrice: value.salePrice ?? none(),
     })
   ) as Serializer<TransferV1InstructionDataArgs, TransferV1InstructionData>;
 }
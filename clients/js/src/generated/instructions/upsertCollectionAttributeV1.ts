@@ -0,0 +1,164 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpsertCollectionAttributeV1InstructionAccounts = {
+  /** The address of the asset */
+  collection: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The owner or delegate of the asset */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop Program */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type UpsertCollectionAttributeV1InstructionData = {
+  discriminator: number;
+  key: string;
+  value: string;
+};
+
+export type UpsertCollectionAttributeV1InstructionDataArgs = {
+  key: string;
+  value: string;
+};
+
+export function getUpsertCollectionAttributeV1InstructionDataSerializer(): Serializer<
+  UpsertCollectionAttributeV1InstructionDataArgs,
+  UpsertCollectionAttributeV1InstructionData
+> {
+  return mapSerializer<
+    UpsertCollectionAttributeV1InstructionDataArgs,
+    any,
+    UpsertCollectionAttributeV1InstructionData
+  >(
+    struct<UpsertCollectionAttributeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['key', string()],
+        ['value', string()],
+      ],
+      { description: 'UpsertCollectionAttributeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 33 })
+  ) as Serializer<
+    UpsertCollectionAttributeV1InstructionDataArgs,
+    UpsertCollectionAttributeV1InstructionData
+  >;
+}
+
+// Args.
+export type UpsertCollectionAttributeV1InstructionArgs =
+  UpsertCollectionAttributeV1InstructionDataArgs;
+
+// Instruction.
+export function upsertCollectionAttributeV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: UpsertCollectionAttributeV1InstructionAccounts &
+    UpsertCollectionAttributeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    collection: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpsertCollectionAttributeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpsertCollectionAttributeV1InstructionDataSerializer().serialize(
+      resolvedArgs as UpsertCollectionAttributeV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BasePluginAuthority,
+  BasePluginAuthorityArgs,
+  getBasePluginAuthoritySerializer,
+} from '.';
+
+export type BaseAttributeKeyAuthority = {
+  key: string;
+  authority: BasePluginAuthority;
+};
+
+export type BaseAttributeKeyAuthorityArgs = {
+  key: string;
+  authority: BasePluginAuthorityArgs;
+};
+
+export function getBaseAttributeKeyAuthoritySerializer(): Serializer<
+  BaseAttributeKeyAuthorityArgs,
+  BaseAttributeKeyAuthority
+> {
+  return struct<BaseAttributeKeyAuthority>(
+    [
+      ['key', string()],
+      ['authority', getBasePluginAuthoritySerializer()],
+    ],
+    { description: 'BaseAttributeKeyAuthority' }
+  ) as Serializer<BaseAttributeKeyAuthorityArgs, BaseAttributeKeyAuthority>;
+}
@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  Attribute,
+  AttributeArgs,
+  BaseAttributeKeyAuthority,
+  BaseAttributeKeyAuthorityArgs,
+  getAttributeSerializer,
+  getBaseAttributeKeyAuthoritySerializer,
+} from '.';
+
+export type BaseAttributesV2 = {
+  attributeList: Array<Attribute>;
+  keyAuthorities: Array<BaseAttributeKeyAuthority>;
+};
+
+export type BaseAttributesV2Args = {
+  attributeList: Array<AttributeArgs>;
+  keyAuthorities: Array<BaseAttributeKeyAuthorityArgs>;
+};
+
+export function getBaseAttributesV2Serializer(): Serializer<
+  BaseAttributesV2Args,
+  BaseAttributesV2
+> {
+  return struct<BaseAttributesV2>(
+    [
+      ['attributeList', array(getAttributeSerializer())],
+      ['keyAuthorities', array(getBaseAttributeKeyAuthoritySerializer())],
+    ],
+    { description: 'BaseAttributesV2' }
+  ) as Serializer<BaseAttributesV2Args, BaseAttributesV2>;
+}
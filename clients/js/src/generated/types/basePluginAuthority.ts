@@ -11,9 +11,11 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
+  array,
   dataEnum,
   publicKey as publicKeySerializer,
   struct,
+  u8,
   unit,
 } from '@metaplex-foundation/umi/serializers';
 
@@ -21,7 +23,8 @@ export type BasePluginAuthority =
   | { __kind: 'None' }
   | { __kind: 'Owner' }
   | { __kind: 'UpdateAuthority' }
-  | { __kind: 'Address'; address: PublicKey };
+  | { __kind: 'Address'; address: PublicKey }
+  | { __kind: 'Multisig'; threshold: number; signers: Array<PublicKey> };
 
 export type BasePluginAuthorityArgs = BasePluginAuthority;
 
@@ -40,6 +43,13 @@ export function getBasePluginAuthoritySerializer(): Serializer<
           ['address', publicKeySerializer()],
         ]),
       ],
+      [
+        'Multisig',
+        struct<GetDataEnumKindContent<BasePluginAuthority, 'Multisig'>>([
+          ['threshold', u8()],
+          ['signers', array(publicKeySerializer())],
+        ]),
+      ],
     ],
     { description: 'BasePluginAuthority' }
   ) as Serializer<BasePluginAuthorityArgs, BasePluginAuthority>;
@@ -59,6 +69,10 @@ export function basePluginAuthority(
   kind: 'Address',
   data: GetDataEnumKindContent<BasePluginAuthorityArgs, 'Address'>
 ): GetDataEnumKind<BasePluginAuthorityArgs, 'Address'>;
+export function basePluginAuthority(
+  kind: 'Multisig',
+  data: GetDataEnumKindContent<BasePluginAuthorityArgs, 'Multisig'>
+): GetDataEnumKind<BasePluginAuthorityArgs, 'Multisig'>;
 export function basePluginAuthority<
   K extends BasePluginAuthorityArgs['__kind'],
 >(kind: K, data?: any): Extract<BasePluginAuthorityArgs, { __kind: K }> {
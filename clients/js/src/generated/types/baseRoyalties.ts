@@ -9,6 +9,7 @@
 import {
   Serializer,
   array,
+  bool,
   struct,
   u16,
 } from '@metaplex-foundation/umi/serializers';
@@ -25,12 +26,14 @@ export type BaseRoyalties = {
   basisPoints: number;
   creators: Array<Creator>;
   ruleSet: BaseRuleSet;
+  royaltyPayments: boolean;
 };
 
 export type BaseRoyaltiesArgs = {
   basisPoints: number;
   creators: Array<CreatorArgs>;
   ruleSet: BaseRuleSetArgs;
+  royaltyPayments: boolean;
 };
 
 export function getBaseRoyaltiesSerializer(): Serializer<
@@ -42,6 +45,7 @@ export function getBaseRoyaltiesSerializer(): Serializer<
       ['basisPoints', u16()],
       ['creators', array(getCreatorSerializer())],
       ['ruleSet', getBaseRuleSetSerializer()],
+      ['royaltyPayments', bool()],
     ],
     { description: 'BaseRoyalties' }
   ) as Serializer<BaseRoyaltiesArgs, BaseRoyalties>;
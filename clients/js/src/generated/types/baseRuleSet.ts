@@ -22,7 +22,9 @@ import {
 export type BaseRuleSet =
   | { __kind: 'None' }
   | { __kind: 'ProgramAllowList'; fields: [Array<PublicKey>] }
-  | { __kind: 'ProgramDenyList'; fields: [Array<PublicKey>] };
+  | { __kind: 'ProgramDenyList'; fields: [Array<PublicKey>] }
+  | { __kind: 'InvokingProgramAllowList'; fields: [Array<PublicKey>] }
+  | { __kind: 'InvokingProgramDenyList'; fields: [Array<PublicKey>] };
 
 export type BaseRuleSetArgs = BaseRuleSet;
 
@@ -45,6 +47,18 @@ export function getBaseRuleSetSerializer(): Serializer<
           ['fields', tuple([array(publicKeySerializer())])],
         ]),
       ],
+      [
+        'InvokingProgramAllowList',
+        struct<
+          GetDataEnumKindContent<BaseRuleSet, 'InvokingProgramAllowList'>
+        >([['fields', tuple([array(publicKeySerializer())])]]),
+      ],
+      [
+        'InvokingProgramDenyList',
+        struct<
+          GetDataEnumKindContent<BaseRuleSet, 'InvokingProgramDenyList'>
+        >([['fields', tuple([array(publicKeySerializer())])]]),
+      ],
     ],
     { description: 'BaseRuleSet' }
   ) as Serializer<BaseRuleSetArgs, BaseRuleSet>;
@@ -62,6 +76,20 @@ export function baseRuleSet(
   kind: 'ProgramDenyList',
   data: GetDataEnumKindContent<BaseRuleSetArgs, 'ProgramDenyList'>['fields']
 ): GetDataEnumKind<BaseRuleSetArgs, 'ProgramDenyList'>;
+export function baseRuleSet(
+  kind: 'InvokingProgramAllowList',
+  data: GetDataEnumKindContent<
+    BaseRuleSetArgs,
+    'InvokingProgramAllowList'
+  >['fields']
+): GetDataEnumKind<BaseRuleSetArgs, 'InvokingProgramAllowList'>;
+export function baseRuleSet(
+  kind: 'InvokingProgramDenyList',
+  data: GetDataEnumKindContent<
+    BaseRuleSetArgs,
+    'InvokingProgramDenyList'
+  >['fields']
+): GetDataEnumKind<BaseRuleSetArgs, 'InvokingProgramDenyList'>;
 export function baseRuleSet<K extends BaseRuleSetArgs['__kind']>(
   kind: K,
   data?: any
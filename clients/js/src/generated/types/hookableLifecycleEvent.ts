@@ -13,6 +13,13 @@ export enum HookableLifecycleEvent {
   Transfer,
   Burn,
   Update,
+  AddPlugin,
+  RemovePlugin,
+  UpdatePlugin,
+  ApprovePluginAuthority,
+  RevokePluginAuthority,
+  Compress,
+  Decompress,
 }
 
 export type HookableLifecycleEventArgs = HookableLifecycleEvent;
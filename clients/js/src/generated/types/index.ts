@@ -11,6 +11,8 @@ export * from './attribute';
 export * from './attributes';
 export * from './autograph';
 export * from './autographSignature';
+export * from './baseAttributeKeyAuthority';
+export * from './baseAttributesV2';
 export * from './baseDataStore';
 export * from './baseDataStoreInitInfo';
 export * from './baseDataStoreUpdateInfo';
@@ -48,6 +50,12 @@ export * from './hashedAssetSchema';
 export * from './hookableLifecycleEvent';
 export * from './immutableMetadata';
 export * from './key';
+export * from './lifecycleHookInstruction';
+export * from './lockExpiry';
+export * from './mplCoreEvent';
+export * from './mplCoreEventPayloadV1';
+export * from './mplCoreEventV1';
+export * from './oraclePluginValidation';
 export * from './oracleValidation';
 export * from './permanentBurnDelegate';
 export * from './permanentFreezeDelegate';
@@ -56,8 +64,11 @@ export * from './plugin';
 export * from './pluginAuthorityPair';
 export * from './pluginType';
 export * from './registryRecord';
+export * from './soulbound';
+export * from './timeLock';
 export * from './transferDelegate';
 export * from './updateDelegate';
+export * from './useDelegate';
 export * from './validationResult';
 export * from './verifiedCreators';
 export * from './verifiedCreatorsSignature';
@@ -0,0 +1,76 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  option,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  HookableLifecycleEvent,
+  HookableLifecycleEventArgs,
+  PluginType,
+  PluginTypeArgs,
+  getHookableLifecycleEventSerializer,
+  getPluginTypeSerializer,
+} from '.';
+
+export type LifecycleHookInstruction = {
+  __kind: 'ExecuteV1';
+  event: HookableLifecycleEvent;
+  pluginType: Option<PluginType>;
+};
+
+export type LifecycleHookInstructionArgs = {
+  __kind: 'ExecuteV1';
+  event: HookableLifecycleEventArgs;
+  pluginType: OptionOrNullable<PluginTypeArgs>;
+};
+
+export function getLifecycleHookInstructionSerializer(): Serializer<
+  LifecycleHookInstructionArgs,
+  LifecycleHookInstruction
+> {
+  return dataEnum<LifecycleHookInstruction>(
+    [
+      [
+        'ExecuteV1',
+        struct<GetDataEnumKindContent<LifecycleHookInstruction, 'ExecuteV1'>>([
+          ['event', getHookableLifecycleEventSerializer()],
+          ['pluginType', option(getPluginTypeSerializer())],
+        ]),
+      ],
+    ],
+    { description: 'LifecycleHookInstruction' }
+  ) as Serializer<LifecycleHookInstructionArgs, LifecycleHookInstruction>;
+}
+
+// Data Enum Helpers.
+export function lifecycleHookInstruction(
+  kind: 'ExecuteV1',
+  data: GetDataEnumKindContent<LifecycleHookInstructionArgs, 'ExecuteV1'>
+): GetDataEnumKind<LifecycleHookInstructionArgs, 'ExecuteV1'>;
+export function lifecycleHookInstruction<
+  K extends LifecycleHookInstructionArgs['__kind']
+>(kind: K, data?: any): Extract<LifecycleHookInstructionArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isLifecycleHookInstruction<
+  K extends LifecycleHookInstruction['__kind']
+>(
+  kind: K,
+  value: LifecycleHookInstruction
+): value is LifecycleHookInstruction & { __kind: K } {
+  return value.__kind === kind;
+}
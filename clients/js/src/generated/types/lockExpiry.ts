@@ -0,0 +1,73 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  i64,
+  struct,
+  tuple,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type LockExpiry =
+  | { __kind: 'UnixTimestamp'; fields: [bigint] }
+  | { __kind: 'Slot'; fields: [bigint] };
+
+export type LockExpiryArgs =
+  | { __kind: 'UnixTimestamp'; fields: [number | bigint] }
+  | { __kind: 'Slot'; fields: [number | bigint] };
+
+export function getLockExpirySerializer(): Serializer<
+  LockExpiryArgs,
+  LockExpiry
+> {
+  return dataEnum<LockExpiry>(
+    [
+      [
+        'UnixTimestamp',
+        struct<GetDataEnumKindContent<LockExpiry, 'UnixTimestamp'>>([
+          ['fields', tuple([i64()])],
+        ]),
+      ],
+      [
+        'Slot',
+        struct<GetDataEnumKindContent<LockExpiry, 'Slot'>>([
+          ['fields', tuple([u64()])],
+        ]),
+      ],
+    ],
+    { description: 'LockExpiry' }
+  ) as Serializer<LockExpiryArgs, LockExpiry>;
+}
+
+// Data Enum Helpers.
+export function lockExpiry(
+  kind: 'UnixTimestamp',
+  data: GetDataEnumKindContent<LockExpiryArgs, 'UnixTimestamp'>['fields']
+): GetDataEnumKind<LockExpiryArgs, 'UnixTimestamp'>;
+export function lockExpiry(
+  kind: 'Slot',
+  data: GetDataEnumKindContent<LockExpiryArgs, 'Slot'>['fields']
+): GetDataEnumKind<LockExpiryArgs, 'Slot'>;
+export function lockExpiry<K extends LockExpiryArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<LockExpiryArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isLockExpiry<K extends LockExpiry['__kind']>(
+  kind: K,
+  value: LockExpiry
+): value is LockExpiry & { __kind: K } {
+  return value.__kind === kind;
+}
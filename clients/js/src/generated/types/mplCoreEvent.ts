@@ -0,0 +1,62 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  MplCoreEventV1,
+  MplCoreEventV1Args,
+  getMplCoreEventV1Serializer,
+} from '.';
+
+export type MplCoreEvent = { __kind: 'V1'; fields: [MplCoreEventV1] };
+
+export type MplCoreEventArgs = { __kind: 'V1'; fields: [MplCoreEventV1Args] };
+
+export function getMplCoreEventSerializer(): Serializer<
+  MplCoreEventArgs,
+  MplCoreEvent
+> {
+  return dataEnum<MplCoreEvent>(
+    [
+      [
+        'V1',
+        struct<GetDataEnumKindContent<MplCoreEvent, 'V1'>>([
+          ['fields', tuple([getMplCoreEventV1Serializer()])],
+        ]),
+      ],
+    ],
+    { description: 'MplCoreEvent' }
+  ) as Serializer<MplCoreEventArgs, MplCoreEvent>;
+}
+
+// Data Enum Helpers.
+export function mplCoreEvent(
+  kind: 'V1',
+  data: GetDataEnumKindContent<MplCoreEventArgs, 'V1'>['fields']
+): GetDataEnumKind<MplCoreEventArgs, 'V1'>;
+export function mplCoreEvent<K extends MplCoreEventArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<MplCoreEventArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isMplCoreEvent<K extends MplCoreEvent['__kind']>(
+  kind: K,
+  value: MplCoreEvent
+): value is MplCoreEvent & { __kind: K } {
+  return value.__kind === kind;
+}
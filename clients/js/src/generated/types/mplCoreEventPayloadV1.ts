@@ -0,0 +1,341 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  unit,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BaseExternalPluginAdapterKey,
+  BaseExternalPluginAdapterKeyArgs,
+  BasePluginAuthority,
+  BasePluginAuthorityArgs,
+  BaseUpdateAuthority,
+  BaseUpdateAuthorityArgs,
+  PluginType,
+  PluginTypeArgs,
+  getBaseExternalPluginAdapterKeySerializer,
+  getBasePluginAuthoritySerializer,
+  getBaseUpdateAuthoritySerializer,
+  getPluginTypeSerializer,
+} from '.';
+
+export type MplCoreEventPayloadV1 =
+  | {
+      __kind: 'Create';
+      owner: PublicKey;
+      updateAuthority: BaseUpdateAuthority;
+    }
+  | { __kind: 'Transfer'; newOwner: PublicKey }
+  | {
+      __kind: 'Update';
+      newName: Option<string>;
+      newUri: Option<string>;
+      newUpdateAuthority: Option<BaseUpdateAuthority>;
+    }
+  | { __kind: 'Burn' }
+  | {
+      __kind: 'AddPlugin';
+      pluginType: PluginType;
+      authority: BasePluginAuthority;
+    }
+  | { __kind: 'RemovePlugin'; pluginType: PluginType }
+  | { __kind: 'UpdatePlugin'; pluginType: PluginType }
+  | {
+      __kind: 'ApprovePluginAuthority';
+      pluginType: PluginType;
+      newAuthority: BasePluginAuthority;
+    }
+  | { __kind: 'RevokePluginAuthority'; pluginType: PluginType }
+  | { __kind: 'AddExternalPluginAdapter'; key: BaseExternalPluginAdapterKey }
+  | { __kind: 'RemoveExternalPluginAdapter'; key: BaseExternalPluginAdapterKey }
+  | { __kind: 'UpdateExternalPluginAdapter'; key: BaseExternalPluginAdapterKey }
+  | {
+      __kind: 'WriteExternalPluginAdapterData';
+      key: BaseExternalPluginAdapterKey;
+    }
+  | { __kind: 'CreateCollection'; updateAuthority: PublicKey }
+  | { __kind: 'Decompress'; owner: PublicKey }
+  | { __kind: 'Execute'; programId: PublicKey };
+
+export type MplCoreEventPayloadV1Args =
+  | {
+      __kind: 'Create';
+      owner: PublicKey;
+      updateAuthority: BaseUpdateAuthorityArgs;
+    }
+  | { __kind: 'Transfer'; newOwner: PublicKey }
+  | {
+      __kind: 'Update';
+      newName: OptionOrNullable<string>;
+      newUri: OptionOrNullable<string>;
+      newUpdateAuthority: OptionOrNullable<BaseUpdateAuthorityArgs>;
+    }
+  | { __kind: 'Burn' }
+  | {
+      __kind: 'AddPlugin';
+      pluginType: PluginTypeArgs;
+      authority: BasePluginAuthorityArgs;
+    }
+  | { __kind: 'RemovePlugin'; pluginType: PluginTypeArgs }
+  | { __kind: 'UpdatePlugin'; pluginType: PluginTypeArgs }
+  | {
+      __kind: 'ApprovePluginAuthority';
+      pluginType: PluginTypeArgs;
+      newAuthority: BasePluginAuthorityArgs;
+    }
+  | { __kind: 'RevokePluginAuthority'; pluginType: PluginTypeArgs }
+  | {
+      __kind: 'AddExternalPluginAdapter';
+      key: BaseExternalPluginAdapterKeyArgs;
+    }
+  | {
+      __kind: 'RemoveExternalPluginAdapter';
+      key: BaseExternalPluginAdapterKeyArgs;
+    }
+  | {
+      __kind: 'UpdateExternalPluginAdapter';
+      key: BaseExternalPluginAdapterKeyArgs;
+    }
+  | {
+      __kind: 'WriteExternalPluginAdapterData';
+      key: BaseExternalPluginAdapterKeyArgs;
+    }
+  | { __kind: 'CreateCollection'; updateAuthority: PublicKey }
+  | { __kind: 'Decompress'; owner: PublicKey }
+  | { __kind: 'Execute'; programId: PublicKey };
+
+export function getMplCoreEventPayloadV1Serializer(): Serializer<
+  MplCoreEventPayloadV1Args,
+  MplCoreEventPayloadV1
+> {
+  return dataEnum<MplCoreEventPayloadV1>(
+    [
+      [
+        'Create',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'Create'>>([
+          ['owner', publicKeySerializer()],
+          ['updateAuthority', getBaseUpdateAuthoritySerializer()],
+        ]),
+      ],
+      [
+        'Transfer',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'Transfer'>>([
+          ['newOwner', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'Update',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'Update'>>([
+          ['newName', option(string())],
+          ['newUri', option(string())],
+          ['newUpdateAuthority', option(getBaseUpdateAuthoritySerializer())],
+        ]),
+      ],
+      ['Burn', unit()],
+      [
+        'AddPlugin',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'AddPlugin'>>([
+          ['pluginType', getPluginTypeSerializer()],
+          ['authority', getBasePluginAuthoritySerializer()],
+        ]),
+      ],
+      [
+        'RemovePlugin',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'RemovePlugin'>>(
+          [['pluginType', getPluginTypeSerializer()]]
+        ),
+      ],
+      [
+        'UpdatePlugin',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'UpdatePlugin'>>(
+          [['pluginType', getPluginTypeSerializer()]]
+        ),
+      ],
+      [
+        'ApprovePluginAuthority',
+        struct<
+          GetDataEnumKindContent<
+            MplCoreEventPayloadV1,
+            'ApprovePluginAuthority'
+          >
+        >([
+          ['pluginType', getPluginTypeSerializer()],
+          ['newAuthority', getBasePluginAuthoritySerializer()],
+        ]),
+      ],
+      [
+        'RevokePluginAuthority',
+        struct<
+          GetDataEnumKindContent<MplCoreEventPayloadV1, 'RevokePluginAuthority'>
+        >([['pluginType', getPluginTypeSerializer()]]),
+      ],
+      [
+        'AddExternalPluginAdapter',
+        struct<
+          GetDataEnumKindContent<
+            MplCoreEventPayloadV1,
+            'AddExternalPluginAdapter'
+          >
+        >([['key', getBaseExternalPluginAdapterKeySerializer()]]),
+      ],
+      [
+        'RemoveExternalPluginAdapter',
+        struct<
+          GetDataEnumKindContent<
+            MplCoreEventPayloadV1,
+            'RemoveExternalPluginAdapter'
+          >
+        >([['key', getBaseExternalPluginAdapterKeySerializer()]]),
+      ],
+      [
+        'UpdateExternalPluginAdapter',
+        struct<
+          GetDataEnumKindContent<
+            MplCoreEventPayloadV1,
+            'UpdateExternalPluginAdapter'
+          >
+        >([['key', getBaseExternalPluginAdapterKeySerializer()]]),
+      ],
+      [
+        'WriteExternalPluginAdapterData',
+        struct<
+          GetDataEnumKindContent<
+            MplCoreEventPayloadV1,
+            'WriteExternalPluginAdapterData'
+          >
+        >([['key', getBaseExternalPluginAdapterKeySerializer()]]),
+      ],
+      [
+        'CreateCollection',
+        struct<
+          GetDataEnumKindContent<MplCoreEventPayloadV1, 'CreateCollection'>
+        >([['updateAuthority', publicKeySerializer()]]),
+      ],
+      [
+        'Decompress',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'Decompress'>>([
+          ['owner', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'Execute',
+        struct<GetDataEnumKindContent<MplCoreEventPayloadV1, 'Execute'>>([
+          ['programId', publicKeySerializer()],
+        ]),
+      ],
+    ],
+    { description: 'MplCoreEventPayloadV1' }
+  ) as Serializer<MplCoreEventPayloadV1Args, MplCoreEventPayloadV1>;
+}
+
+// Data Enum Helpers.
+export function mplCoreEventPayloadV1(
+  kind: 'Create',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'Create'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'Create'>;
+export function mplCoreEventPayloadV1(
+  kind: 'Transfer',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'Transfer'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'Transfer'>;
+export function mplCoreEventPayloadV1(
+  kind: 'Update',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'Update'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'Update'>;
+export function mplCoreEventPayloadV1(
+  kind: 'Burn'
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'Burn'>;
+export function mplCoreEventPayloadV1(
+  kind: 'AddPlugin',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'AddPlugin'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'AddPlugin'>;
+export function mplCoreEventPayloadV1(
+  kind: 'RemovePlugin',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'RemovePlugin'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'RemovePlugin'>;
+export function mplCoreEventPayloadV1(
+  kind: 'UpdatePlugin',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'UpdatePlugin'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'UpdatePlugin'>;
+export function mplCoreEventPayloadV1(
+  kind: 'ApprovePluginAuthority',
+  data: GetDataEnumKindContent<
+    MplCoreEventPayloadV1Args,
+    'ApprovePluginAuthority'
+  >
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'ApprovePluginAuthority'>;
+export function mplCoreEventPayloadV1(
+  kind: 'RevokePluginAuthority',
+  data: GetDataEnumKindContent<
+    MplCoreEventPayloadV1Args,
+    'RevokePluginAuthority'
+  >
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'RevokePluginAuthority'>;
+export function mplCoreEventPayloadV1(
+  kind: 'AddExternalPluginAdapter',
+  data: GetDataEnumKindContent<
+    MplCoreEventPayloadV1Args,
+    'AddExternalPluginAdapter'
+  >
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'AddExternalPluginAdapter'>;
+export function mplCoreEventPayloadV1(
+  kind: 'RemoveExternalPluginAdapter',
+  data: GetDataEnumKindContent<
+    MplCoreEventPayloadV1Args,
+    'RemoveExternalPluginAdapter'
+  >
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'RemoveExternalPluginAdapter'>;
+export function mplCoreEventPayloadV1(
+  kind: 'UpdateExternalPluginAdapter',
+  data: GetDataEnumKindContent<
+    MplCoreEventPayloadV1Args,
+    'UpdateExternalPluginAdapter'
+  >
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'UpdateExternalPluginAdapter'>;
+export function mplCoreEventPayloadV1(
+  kind: 'WriteExternalPluginAdapterData',
+  data: GetDataEnumKindContent<
+    MplCoreEventPayloadV1Args,
+    'WriteExternalPluginAdapterData'
+  >
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'WriteExternalPluginAdapterData'>;
+export function mplCoreEventPayloadV1(
+  kind: 'CreateCollection',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'CreateCollection'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'CreateCollection'>;
+export function mplCoreEventPayloadV1(
+  kind: 'Decompress',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'Decompress'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'Decompress'>;
+export function mplCoreEventPayloadV1(
+  kind: 'Execute',
+  data: GetDataEnumKindContent<MplCoreEventPayloadV1Args, 'Execute'>
+): GetDataEnumKind<MplCoreEventPayloadV1Args, 'Execute'>;
+export function mplCoreEventPayloadV1<
+  K extends MplCoreEventPayloadV1Args['__kind'],
+>(kind: K, data?: any): Extract<MplCoreEventPayloadV1Args, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isMplCoreEventPayloadV1<
+  K extends MplCoreEventPayloadV1['__kind'],
+>(
+  kind: K,
+  value: MplCoreEventPayloadV1
+): value is MplCoreEventPayloadV1 & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  MplCoreEventPayloadV1,
+  MplCoreEventPayloadV1Args,
+  getMplCoreEventPayloadV1Serializer,
+} from '.';
+
+export type MplCoreEventV1 = {
+  asset: PublicKey;
+  seq: Option<bigint>;
+  payload: MplCoreEventPayloadV1;
+};
+
+export type MplCoreEventV1Args = {
+  asset: PublicKey;
+  seq: OptionOrNullable<number | bigint>;
+  payload: MplCoreEventPayloadV1Args;
+};
+
+export function getMplCoreEventV1Serializer(): Serializer<
+  MplCoreEventV1Args,
+  MplCoreEventV1
+> {
+  return struct<MplCoreEventV1>(
+    [
+      ['asset', publicKeySerializer()],
+      ['seq', option(u64())],
+      ['payload', getMplCoreEventPayloadV1Serializer()],
+    ],
+    { description: 'MplCoreEventV1' }
+  ) as Serializer<MplCoreEventV1Args, MplCoreEventV1>;
+}
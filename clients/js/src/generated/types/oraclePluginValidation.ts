@@ -0,0 +1,53 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  tuple,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ExternalValidationResult,
+  ExternalValidationResultArgs,
+  PluginType,
+  PluginTypeArgs,
+  getExternalValidationResultSerializer,
+  getPluginTypeSerializer,
+} from '.';
+
+export type OraclePluginValidation = {
+  default: ExternalValidationResult;
+  pluginTypes: Array<[PluginType, ExternalValidationResult]>;
+};
+
+export type OraclePluginValidationArgs = {
+  default: ExternalValidationResultArgs;
+  pluginTypes: Array<[PluginTypeArgs, ExternalValidationResultArgs]>;
+};
+
+export function getOraclePluginValidationSerializer(): Serializer<
+  OraclePluginValidationArgs,
+  OraclePluginValidation
+> {
+  return struct<OraclePluginValidation>(
+    [
+      ['default', getExternalValidationResultSerializer()],
+      [
+        'pluginTypes',
+        array(
+          tuple([
+            getPluginTypeSerializer(),
+            getExternalValidationResultSerializer(),
+          ])
+        ),
+      ],
+    ],
+    { description: 'OraclePluginValidation' }
+  ) as Serializer<OraclePluginValidationArgs, OraclePluginValidation>;
+}
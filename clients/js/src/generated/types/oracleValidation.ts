@@ -17,7 +17,10 @@ import {
 import {
   ExternalValidationResult,
   ExternalValidationResultArgs,
+  OraclePluginValidation,
+  OraclePluginValidationArgs,
   getExternalValidationResultSerializer,
+  getOraclePluginValidationSerializer,
 } from '.';
 
 export type OracleValidation =
@@ -28,6 +31,20 @@ export type OracleValidation =
       transfer: ExternalValidationResult;
       burn: ExternalValidationResult;
       update: ExternalValidationResult;
+    }
+  | {
+      __kind: 'V2';
+      create: ExternalValidationResult;
+      transfer: ExternalValidationResult;
+      burn: ExternalValidationResult;
+      update: ExternalValidationResult;
+      addPlugin: OraclePluginValidation;
+      removePlugin: OraclePluginValidation;
+      updatePlugin: OraclePluginValidation;
+      approvePluginAuthority: OraclePluginValidation;
+      revokePluginAuthority: OraclePluginValidation;
+      compress: ExternalValidationResult;
+      decompress: ExternalValidationResult;
     };
 
 export type OracleValidationArgs =
@@ -38,6 +55,20 @@ export type OracleValidationArgs =
       transfer: ExternalValidationResultArgs;
       burn: ExternalValidationResultArgs;
       update: ExternalValidationResultArgs;
+    }
+  | {
+      __kind: 'V2';
+      create: ExternalValidationResultArgs;
+      transfer: ExternalValidationResultArgs;
+      burn: ExternalValidationResultArgs;
+      update: ExternalValidationResultArgs;
+      addPlugin: OraclePluginValidationArgs;
+      removePlugin: OraclePluginValidationArgs;
+      updatePlugin: OraclePluginValidationArgs;
+      approvePluginAuthority: OraclePluginValidationArgs;
+      revokePluginAuthority: OraclePluginValidationArgs;
+      compress: ExternalValidationResultArgs;
+      decompress: ExternalValidationResultArgs;
     };
 
 export function getOracleValidationSerializer(): Serializer<
@@ -56,6 +87,22 @@ export function getOracleValidationSerializer(): Serializer<
           ['update', getExternalValidationResultSerializer()],
         ]),
       ],
+      [
+        'V2',
+        struct<GetDataEnumKindContent<OracleValidation, 'V2'>>([
+          ['create', getExternalValidationResultSerializer()],
+          ['transfer', getExternalValidationResultSerializer()],
+          ['burn', getExternalValidationResultSerializer()],
+          ['update', getExternalValidationResultSerializer()],
+          ['addPlugin', getOraclePluginValidationSerializer()],
+          ['removePlugin', getOraclePluginValidationSerializer()],
+          ['updatePlugin', getOraclePluginValidationSerializer()],
+          ['approvePluginAuthority', getOraclePluginValidationSerializer()],
+          ['revokePluginAuthority', getOraclePluginValidationSerializer()],
+          ['compress', getExternalValidationResultSerializer()],
+          ['decompress', getExternalValidationResultSerializer()],
+        ]),
+      ],
     ],
     { description: 'OracleValidation' }
   ) as Serializer<OracleValidationArgs, OracleValidation>;
@@ -69,6 +116,10 @@ export function oracleValidation(
   kind: 'V1',
   data: GetDataEnumKindContent<OracleValidationArgs, 'V1'>
 ): GetDataEnumKind<OracleValidationArgs, 'V1'>;
+export function oracleValidation(
+  kind: 'V2',
+  data: GetDataEnumKindContent<OracleValidationArgs, 'V2'>
+): GetDataEnumKind<OracleValidationArgs, 'V2'>;
 export function oracleValidation<K extends OracleValidationArgs['__kind']>(
   kind: K,
   data?: any
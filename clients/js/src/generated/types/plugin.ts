@@ -21,6 +21,8 @@ import {
   AttributesArgs,
   Autograph,
   AutographArgs,
+  BaseAttributesV2,
+  BaseAttributesV2Args,
   BaseMasterEdition,
   BaseMasterEditionArgs,
   BaseRoyalties,
@@ -39,15 +41,22 @@ import {
   PermanentFreezeDelegateArgs,
   PermanentTransferDelegate,
   PermanentTransferDelegateArgs,
+  Soulbound,
+  SoulboundArgs,
+  TimeLock,
+  TimeLockArgs,
   TransferDelegate,
   TransferDelegateArgs,
   UpdateDelegate,
   UpdateDelegateArgs,
+  UseDelegate,
+  UseDelegateArgs,
   VerifiedCreators,
   VerifiedCreatorsArgs,
   getAddBlockerSerializer,
   getAttributesSerializer,
   getAutographSerializer,
+  getBaseAttributesV2Serializer,
   getBaseMasterEditionSerializer,
   getBaseRoyaltiesSerializer,
   getBurnDelegateSerializer,
@@ -57,8 +66,11 @@ import {
   getPermanentBurnDelegateSerializer,
   getPermanentFreezeDelegateSerializer,
   getPermanentTransferDelegateSerializer,
+  getSoulboundSerializer,
+  getTimeLockSerializer,
   getTransferDelegateSerializer,
   getUpdateDelegateSerializer,
+  getUseDelegateSerializer,
   getVerifiedCreatorsSerializer,
 } from '.';
 
@@ -77,7 +89,11 @@ export type Plugin =
   | { __kind: 'AddBlocker'; fields: [AddBlocker] }
   | { __kind: 'ImmutableMetadata'; fields: [ImmutableMetadata] }
   | { __kind: 'VerifiedCreators'; fields: [VerifiedCreators] }
-  | { __kind: 'Autograph'; fields: [Autograph] };
+  | { __kind: 'Autograph'; fields: [Autograph] }
+  | { __kind: 'TimeLock'; fields: [TimeLock] }
+  | { __kind: 'Soulbound'; fields: [Soulbound] }
+  | { __kind: 'UseDelegate'; fields: [UseDelegate] }
+  | { __kind: 'AttributesV2'; fields: [BaseAttributesV2] };
 
 export type PluginArgs =
   | { __kind: 'Royalties'; fields: [BaseRoyaltiesArgs] }
@@ -97,7 +113,11 @@ export type PluginArgs =
   | { __kind: 'AddBlocker'; fields: [AddBlockerArgs] }
   | { __kind: 'ImmutableMetadata'; fields: [ImmutableMetadataArgs] }
   | { __kind: 'VerifiedCreators'; fields: [VerifiedCreatorsArgs] }
-  | { __kind: 'Autograph'; fields: [AutographArgs] };
+  | { __kind: 'Autograph'; fields: [AutographArgs] }
+  | { __kind: 'TimeLock'; fields: [TimeLockArgs] }
+  | { __kind: 'Soulbound'; fields: [SoulboundArgs] }
+  | { __kind: 'UseDelegate'; fields: [UseDelegateArgs] }
+  | { __kind: 'AttributesV2'; fields: [BaseAttributesV2Args] };
 
 export function getPluginSerializer(): Serializer<PluginArgs, Plugin> {
   return dataEnum<Plugin>(
@@ -192,6 +212,30 @@ export function getPluginSerializer(): Serializer<PluginArgs, Plugin> {
           ['fields', tuple([getAutographSerializer()])],
         ]),
       ],
+      [
+        'TimeLock',
+        struct<GetDataEnumKindContent<Plugin, 'TimeLock'>>([
+          ['fields', tuple([getTimeLockSerializer()])],
+        ]),
+      ],
+      [
+        'Soulbound',
+        struct<GetDataEnumKindContent<Plugin, 'Soulbound'>>([
+          ['fields', tuple([getSoulboundSerializer()])],
+        ]),
+      ],
+      [
+        'UseDelegate',
+        struct<GetDataEnumKindContent<Plugin, 'UseDelegate'>>([
+          ['fields', tuple([getUseDelegateSerializer()])],
+        ]),
+      ],
+      [
+        'AttributesV2',
+        struct<GetDataEnumKindContent<Plugin, 'AttributesV2'>>([
+          ['fields', tuple([getBaseAttributesV2Serializer()])],
+        ]),
+      ],
     ],
     { description: 'Plugin' }
   ) as Serializer<PluginArgs, Plugin>;
@@ -261,6 +305,22 @@ export function plugin(
   kind: 'Autograph',
   data: GetDataEnumKindContent<PluginArgs, 'Autograph'>['fields']
 ): GetDataEnumKind<PluginArgs, 'Autograph'>;
+export function plugin(
+  kind: 'TimeLock',
+  data: GetDataEnumKindContent<PluginArgs, 'TimeLock'>['fields']
+): GetDataEnumKind<PluginArgs, 'TimeLock'>;
+export function plugin(
+  kind: 'Soulbound',
+  data: GetDataEnumKindContent<PluginArgs, 'Soulbound'>['fields']
+): GetDataEnumKind<PluginArgs, 'Soulbound'>;
+export function plugin(
+  kind: 'UseDelegate',
+  data: GetDataEnumKindContent<PluginArgs, 'UseDelegate'>['fields']
+): GetDataEnumKind<PluginArgs, 'UseDelegate'>;
+export function plugin(
+  kind: 'AttributesV2',
+  data: GetDataEnumKindContent<PluginArgs, 'AttributesV2'>['fields']
+): GetDataEnumKind<PluginArgs, 'AttributesV2'>;
 export function plugin<K extends PluginArgs['__kind']>(
   kind: K,
   data?: any
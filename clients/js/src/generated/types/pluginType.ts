@@ -24,6 +24,10 @@ export enum PluginType {
   ImmutableMetadata,
   VerifiedCreators,
   Autograph,
+  TimeLock,
+  Soulbound,
+  UseDelegate,
+  AttributesV2,
 }
 
 export type PluginTypeArgs = PluginType;
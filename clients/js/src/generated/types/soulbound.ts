@@ -0,0 +1,19 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct } from '@metaplex-foundation/umi/serializers';
+
+export type Soulbound = {};
+
+export type SoulboundArgs = Soulbound;
+
+export function getSoulboundSerializer(): Serializer<SoulboundArgs, Soulbound> {
+  return struct<Soulbound>([], {
+    description: 'Soulbound',
+  }) as Serializer<SoulboundArgs, Soulbound>;
+}
@@ -0,0 +1,20 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct } from '@metaplex-foundation/umi/serializers';
+import { LockExpiry, LockExpiryArgs, getLockExpirySerializer } from '.';
+
+export type TimeLock = { expiry: LockExpiry };
+
+export type TimeLockArgs = { expiry: LockExpiryArgs };
+
+export function getTimeLockSerializer(): Serializer<TimeLockArgs, TimeLock> {
+  return struct<TimeLock>([['expiry', getLockExpirySerializer()]], {
+    description: 'TimeLock',
+  }) as Serializer<TimeLockArgs, TimeLock>;
+}
@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type UseDelegate = { user: PublicKey; expiry: bigint };
+
+export type UseDelegateArgs = { user: PublicKey; expiry: number | bigint };
+
+export function getUseDelegateSerializer(): Serializer<
+  UseDelegateArgs,
+  UseDelegate
+> {
+  return struct<UseDelegate>(
+    [
+      ['user', publicKeySerializer()],
+      ['expiry', i64()],
+    ],
+    { description: 'UseDelegate' }
+  ) as Serializer<UseDelegateArgs, UseDelegate>;
+}
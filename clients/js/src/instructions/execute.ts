@@ -0,0 +1,74 @@
+import {
+  AccountMeta,
+  Context,
+  Instruction,
+  PublicKey,
+  Signer,
+  SignerMeta,
+} from '@metaplex-foundation/umi';
+import {
+  string,
+  publicKey as publicKeySerializer,
+} from '@metaplex-foundation/umi/serializers';
+import { AssetV1, CollectionV1, executeV1 } from '../generated';
+
+export const ASSET_SIGNER_SEED = 'mpl-core-execute';
+
+/**
+ * Find the signer PDA of an asset, which the program signs for in `executeV1`.
+ */
+export const findAssetSignerPda = (
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  { asset }: { asset: PublicKey }
+) => {
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize(ASSET_SIGNER_SEED),
+    publicKeySerializer().serialize(asset),
+  ]);
+};
+
+export type ExecuteArgs = Omit<
+  Parameters<typeof executeV1>[1],
+  'asset' | 'collection' | 'assetSigner' | 'programId' | 'instructionData'
+> & {
+  asset: Pick<AssetV1, 'publicKey'>;
+  collection?: Pick<CollectionV1, 'publicKey'>;
+  /** The instruction to invoke with the asset signer PDA as a signer. */
+  instruction: Instruction;
+  /** Any other signers required by the instruction. */
+  signers?: Signer[];
+};
+
+export const execute = (
+  context: Pick<Context, 'payer' | 'programs' | 'eddsa'>,
+  { asset, collection, instruction, signers = [], ...args }: ExecuteArgs
+) => {
+  const [assetSigner] = findAssetSignerPda(context, {
+    asset: asset.publicKey,
+  });
+
+  // The program signs for the asset signer, so only other signers are
+  // required on the outer transaction.
+  const remainingAccounts = instruction.keys.map(
+    (key): AccountMeta | SignerMeta => {
+      const signer = signers.find((s) => s.publicKey === key.pubkey);
+      if (key.isSigner && key.pubkey !== assetSigner && signer) {
+        return { signer, isWritable: key.isWritable };
+      }
+      return { ...key, isSigner: key.isSigner && key.pubkey !== assetSigner };
+    }
+  );
+
+  return executeV1(context, {
+    ...args,
+    asset: asset.publicKey,
+    collection: collection?.publicKey,
+    assetSigner,
+    programId: instruction.programId,
+    instructionData: instruction.data,
+  }).addRemainingAccounts(remainingAccounts);
+};
@@ -1,14 +1,25 @@
-import { Context, publicKey } from '@metaplex-foundation/umi';
+import { Context, isOption, publicKey } from '@metaplex-foundation/umi';
 import { CollectionV1, transferV1, AssetV1 } from '../generated';
-import { findExtraAccounts } from '../plugins';
+import { findExtraAccounts, ruleSetToBase } from '../plugins';
 import { deriveExternalPluginAdapters } from '../helpers';
+import { unwrapOption } from '../utils';
+
+const SYSVAR_INSTRUCTIONS_ID = publicKey(
+  'Sysvar1nstructions1111111111111111111111111'
+);
 
 export type TransferArgs = Omit<
   Parameters<typeof transferV1>[1],
   'asset' | 'collection'
 > & {
-  asset: Pick<AssetV1, 'publicKey' | 'owner' | 'oracles' | 'lifecycleHooks'>;
-  collection?: Pick<CollectionV1, 'publicKey' | 'oracles' | 'lifecycleHooks'>;
+  asset: Pick<
+    AssetV1,
+    'publicKey' | 'owner' | 'oracles' | 'lifecycleHooks' | 'royalties'
+  >;
+  collection?: Pick<
+    CollectionV1,
+    'publicKey' | 'oracles' | 'lifecycleHooks' | 'royalties'
+  >;
 };
 
 export const transfer = (
@@ -32,9 +43,43 @@ export const transfer = (
     }
   );
 
+  // Priced sales pay royalties to the creators, passed as remaining accounts,
+  // when the creators opted in to royalty payments.
+  const salePrice = isOption(args.salePrice)
+    ? unwrapOption(args.salePrice)
+    : args.salePrice;
+  const royalties = asset.royalties ?? collection?.royalties;
+  const creatorAccounts =
+    salePrice && royalties?.royaltyPayments
+      ? royalties.creators.map((creator) => ({
+          pubkey: creator.address,
+          isSigner: false,
+          isWritable: true,
+        }))
+      : [];
+
+  // Invoking program rule sets on either the asset or the collection read the
+  // calling program from the instructions sysvar.
+  const readsInvokingProgram = [asset.royalties, collection?.royalties].some(
+    (r) => {
+      const kind = r ? ruleSetToBase(r.ruleSet).__kind : undefined;
+      return (
+        kind === 'InvokingProgramAllowList' ||
+        kind === 'InvokingProgramDenyList'
+      );
+    }
+  );
+  const sysvarAccounts = readsInvokingProgram
+    ? [{ pubkey: SYSVAR_INSTRUCTIONS_ID, isSigner: false, isWritable: false }]
+    : [];
+
   return transferV1(context, {
     ...args,
     asset: asset.publicKey,
     collection: collection?.publicKey,
-  }).addRemainingAccounts(extraAccounts);
+  }).addRemainingAccounts([
+    ...extraAccounts,
+    ...creatorAccounts,
+    ...sysvarAccounts,
+  ]);
 };
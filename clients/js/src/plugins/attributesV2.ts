@@ -0,0 +1,38 @@
+import { Attribute, BaseAttributesV2 } from '../generated';
+import {
+  PluginAuthority,
+  pluginAuthorityFromBase,
+  pluginAuthorityToBase,
+} from './pluginAuthority';
+
+export type AttributeKeyAuthority = {
+  key: string;
+  authority: PluginAuthority;
+};
+
+export type AttributesV2 = {
+  attributeList: Attribute[];
+  keyAuthorities: AttributeKeyAuthority[];
+};
+
+export type AttributesV2Args = AttributesV2;
+
+export function attributesV2ToBase(a: AttributesV2Args): BaseAttributesV2 {
+  return {
+    attributeList: a.attributeList,
+    keyAuthorities: a.keyAuthorities.map((k) => ({
+      key: k.key,
+      authority: pluginAuthorityToBase(k.authority),
+    })),
+  };
+}
+
+export function attributesV2FromBase(a: BaseAttributesV2): AttributesV2 {
+  return {
+    attributeList: a.attributeList,
+    keyAuthorities: a.keyAuthorities.map((k) => ({
+      key: k.key,
+      authority: pluginAuthorityFromBase(k.authority),
+    })),
+  };
+}
@@ -14,3 +14,4 @@ export * from './seed';
 export * from './extraAccount';
 export * from './validationResultsOffset';
 export * from './masterEdition';
+export * from './attributesV2';
@@ -26,6 +26,7 @@ import {
 } from './pluginAuthority';
 import { royaltiesFromBase, royaltiesToBase } from './royalties';
 import { masterEditionFromBase, masterEditionToBase } from './masterEdition';
+import { attributesV2FromBase, attributesV2ToBase } from './attributesV2';
 
 export function formPluginHeaderV1(
   pluginRegistryOffset: bigint
@@ -48,6 +49,12 @@ export function createPlugin(args: CreatePluginArgs): BasePlugin {
       ],
     };
   }
+  if (args.type === 'AttributesV2') {
+    return {
+      __kind: args.type,
+      fields: [attributesV2ToBase(args.data)],
+    };
+  }
   return {
     __kind: args.type,
     fields: [(args as any).data || {}],
@@ -91,6 +98,12 @@ export function createPluginV2(args: AssetAllPluginArgsV2): BasePlugin {
       fields: [masterEditionToBase(args)],
     };
   }
+  if (type === 'AttributesV2') {
+    return {
+      __kind: type,
+      fields: [attributesV2ToBase(args)],
+    };
+  }
 
   return {
     __kind: type,
@@ -150,6 +163,16 @@ export function mapPlugin({
     };
   }
 
+  if (plug.__kind === 'AttributesV2') {
+    return {
+      [pluginKey]: {
+        authority,
+        offset,
+        ...attributesV2FromBase(plug.fields[0]),
+      },
+    };
+  }
+
   return {
     [pluginKey]: {
       authority,
@@ -1,8 +1,19 @@
 /* eslint-disable no-bitwise */
 import { ExternalCheckResult, HookableLifecycleEvent } from '../generated';
-import { capitalizeFirstLetter } from '../utils';
+import { capitalizeFirstLetter, lowercaseFirstLetter } from '../utils';
 
-export type LifecycleEvent = 'create' | 'update' | 'transfer' | 'burn';
+export type LifecycleEvent =
+  | 'create'
+  | 'update'
+  | 'transfer'
+  | 'burn'
+  | 'addPlugin'
+  | 'removePlugin'
+  | 'updatePlugin'
+  | 'approvePluginAuthority'
+  | 'revokePluginAuthority'
+  | 'compress'
+  | 'decompress';
 
 //  ExternalCheckResult is a bit array
 export enum CheckResult {
@@ -65,7 +76,9 @@ export function lifecycleCheckKeyToEnum(
 export function hookableLifecycleEventToLifecycleCheckKey(
   event: HookableLifecycleEvent
 ): keyof LifecycleChecks {
-  return HookableLifecycleEvent[event].toLowerCase() as keyof LifecycleChecks;
+  return lowercaseFirstLetter(
+    HookableLifecycleEvent[event]
+  ) as keyof LifecycleChecks;
 }
 
 export function lifecycleChecksToBase(
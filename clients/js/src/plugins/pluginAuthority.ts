@@ -4,6 +4,8 @@ import { BasePluginAuthority } from '../generated';
 export type PluginAuthority = {
   type: PluginAuthorityType;
   address?: PublicKey;
+  threshold?: number;
+  signers?: PublicKey[];
 };
 
 export type PluginAuthorityType = BasePluginAuthority['__kind'];
@@ -15,6 +17,13 @@ export function pluginAuthorityToBase(u: PluginAuthority): BasePluginAuthority {
       address: u.address as PublicKey,
     };
   }
+  if (u.type === 'Multisig') {
+    return {
+      __kind: 'Multisig',
+      threshold: u.threshold as number,
+      signers: u.signers as PublicKey[],
+    };
+  }
   return {
     __kind: u.type,
   };
@@ -23,6 +32,13 @@ export function pluginAuthorityToBase(u: PluginAuthority): BasePluginAuthority {
 export function pluginAuthorityFromBase(
   authority: BasePluginAuthority
 ): PluginAuthority {
+  if (authority.__kind === 'Multisig') {
+    return {
+      type: authority.__kind,
+      threshold: authority.threshold,
+      signers: authority.signers,
+    };
+  }
   return {
     type: authority.__kind,
     address: (authority as any).address,
@@ -14,6 +14,14 @@ export type UnwrappedRuleSet =
   | {
       type: 'ProgramDenyList';
       addresses: PublicKey[];
+    }
+  | {
+      type: 'InvokingProgramAllowList';
+      addresses: PublicKey[];
+    }
+  | {
+      type: 'InvokingProgramDenyList';
+      addresses: PublicKey[];
     };
 
 export type RuleSet = UnwrappedRuleSet | BaseRuleSet;
@@ -35,7 +43,9 @@ export function ruleSetToBase(r: RuleSet): BaseRuleSet {
 
   if (
     ruleSet.type === 'ProgramAllowList' ||
-    ruleSet.type === 'ProgramDenyList'
+    ruleSet.type === 'ProgramDenyList' ||
+    ruleSet.type === 'InvokingProgramAllowList' ||
+    ruleSet.type === 'InvokingProgramDenyList'
   ) {
     return {
       __kind: ruleSet.type,
@@ -66,6 +76,18 @@ export function royaltiesFromBase(r: BaseRoyalties): Royalties {
       type: 'ProgramDenyList',
       addresses: r.ruleSet.fields[0],
     };
+  } else if (r.ruleSet.__kind === 'InvokingProgramAllowList') {
+    ruleSet = {
+      ...r.ruleSet,
+      type: 'InvokingProgramAllowList',
+      addresses: r.ruleSet.fields[0],
+    };
+  } else if (r.ruleSet.__kind === 'InvokingProgramDenyList') {
+    ruleSet = {
+      ...r.ruleSet,
+      type: 'InvokingProgramDenyList',
+      addresses: r.ruleSet.fields[0],
+    };
   } else {
     ruleSet = {
       ...r.ruleSet,
@@ -25,10 +25,16 @@ import {
   VerifiedCreatorsArgs,
   Autograph,
   VerifiedCreators,
+  TimeLock,
+  TimeLockArgs,
+  Soulbound,
+  UseDelegate,
+  UseDelegateArgs,
 } from '../generated';
 import { RoyaltiesArgs, RoyaltiesPlugin } from './royalties';
 import { PluginAuthority } from './pluginAuthority';
 import { MasterEdition, MasterEditionArgs } from './masterEdition';
+import { AttributesV2, AttributesV2Args } from './attributesV2';
 
 // for backwards compatibility
 export { pluginAuthority, updateAuthority, ruleSet };
@@ -88,6 +94,21 @@ export type CreatePluginArgs =
     }
   | {
       type: 'AddBlocker';
+    }
+  | {
+      type: 'TimeLock';
+      data: TimeLockArgs;
+    }
+  | {
+      type: 'Soulbound';
+    }
+  | {
+      type: 'UseDelegate';
+      data: UseDelegateArgs;
+    }
+  | {
+      type: 'AttributesV2';
+      data: AttributesV2Args;
     };
 
 export type AuthorityArgsV2 = {
@@ -106,7 +127,13 @@ export type CreateOnlyPluginArgsV2 =
     }
   | ({
       type: 'Edition';
-    } & EditionArgs);
+    } & EditionArgs)
+  | ({
+      type: 'TimeLock';
+    } & TimeLockArgs)
+  | {
+      type: 'Soulbound';
+    };
 
 export type OwnerManagedPluginArgsV2 =
   | ({
@@ -120,7 +147,10 @@ export type OwnerManagedPluginArgsV2 =
     }
   | ({
       type: 'Autograph';
-    } & AutographArgs);
+    } & AutographArgs)
+  | ({
+      type: 'UseDelegate';
+    } & UseDelegateArgs);
 
 export type AuthorityManagedPluginArgsV2 =
   | ({
@@ -143,7 +173,10 @@ export type AuthorityManagedPluginArgsV2 =
     }
   | ({
       type: 'VerifiedCreators';
-    } & VerifiedCreatorsArgs);
+    } & VerifiedCreatorsArgs)
+  | ({
+      type: 'AttributesV2';
+    } & AttributesV2Args);
 
 export type AssetAddablePluginArgsV2 =
   | OwnerManagedPluginArgsV2
@@ -181,6 +214,10 @@ export type AddBlockerPlugin = BasePlugin & AddBlocker;
 export type ImmutableMetadataPlugin = BasePlugin & ImmutableMetadata;
 export type VerifiedCreatorsPlugin = BasePlugin & VerifiedCreators;
 export type AutographPlugin = BasePlugin & Autograph;
+export type TimeLockPlugin = BasePlugin & TimeLock;
+export type SoulboundPlugin = BasePlugin & Soulbound;
+export type UseDelegatePlugin = BasePlugin & UseDelegate;
+export type AttributesV2Plugin = BasePlugin & AttributesV2;
 
 export type CommonPluginsList = {
   attributes?: AttributesPlugin;
@@ -193,6 +230,9 @@ export type CommonPluginsList = {
   immutableMetadata?: ImmutableMetadataPlugin;
   autograph?: AutographPlugin;
   verifiedCreators?: VerifiedCreatorsPlugin;
+  timeLock?: TimeLockPlugin;
+  soulbound?: SoulboundPlugin;
+  attributesV2?: AttributesV2Plugin;
 };
 
 export type AssetPluginsList = {
@@ -200,6 +240,7 @@ export type AssetPluginsList = {
   burnDelegate?: BurnDelegatePlugin;
   transferDelegate?: TransferDelegatePlugin;
   edition?: EditionPlugin;
+  useDelegate?: UseDelegatePlugin;
 } & CommonPluginsList;
 
 export type CollectionPluginsList = {
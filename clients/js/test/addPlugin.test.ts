@@ -191,6 +191,7 @@ test('it can add a plugin to a collection', async (t) => {
         basisPoints: 5,
         creators: [{ address: umi.identity.publicKey, percentage: 100 }],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
@@ -206,6 +207,7 @@ test('it can add a plugin to a collection', async (t) => {
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 });
@@ -262,6 +264,7 @@ test('it can add an authority-managed plugin to an asset via delegate authority'
           },
         ],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
     authority: delegate,
@@ -285,6 +288,7 @@ test('it can add an authority-managed plugin to an asset via delegate authority'
         },
       ],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 });
@@ -312,6 +316,7 @@ test('it can add an authority-managed plugin to an asset with the collection upd
           },
         ],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
     authority: collectionAuth,
@@ -335,6 +340,7 @@ test('it can add an authority-managed plugin to an asset with the collection upd
         },
       ],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 });
@@ -396,6 +402,7 @@ test('it cannot add authority-managed plugin to an asset by owner', async (t) =>
           },
         ],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
@@ -423,6 +430,7 @@ test('it can add a plugin to a collection with a plugin', async (t) => {
             },
           ],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -444,6 +452,7 @@ test('it can add a plugin to a collection with a plugin', async (t) => {
         },
       ],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 
@@ -471,6 +480,7 @@ test('it can add a plugin to a collection with a plugin', async (t) => {
         },
       ],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
     updateDelegate: {
       authority: {
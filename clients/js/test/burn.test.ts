@@ -1,8 +1,15 @@
-import { generateSigner, sol } from '@metaplex-foundation/umi';
+import { generateSigner, publicKey, sol } from '@metaplex-foundation/umi';
 import test from 'ava';
 
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
-import { burnCollectionV1, burnV1, pluginAuthorityPair } from '../src';
+import {
+  burnCollectionV1,
+  burnV1,
+  compressV1,
+  fetchCollectionV1,
+  pluginAuthorityPair,
+  updateAuthority,
+} from '../src';
 import {
   DEFAULT_ASSET,
   DEFAULT_COLLECTION,
@@ -294,3 +301,38 @@ test('it can burn asset with different payer', async (t) => {
 
   t.true(lamportsAfter.basisPoints > lamportsBefore.basisPoints);
 });
+
+test('it can burn a compressed asset in a collection', async (t) => {
+  // Given a compressed asset in a collection.
+  const umi = await createUmi();
+  const { asset, collection } = await createAssetWithCollection(umi, {});
+  t.is(collection.currentSize, 1);
+
+  await compressV1(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    authority: umi.identity,
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+  }).sendAndConfirm(umi);
+
+  // When we burn the asset using its compression proof.
+  await burnV1(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    systemProgram: publicKey('11111111111111111111111111111111'),
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+    compressionProof: {
+      owner: umi.identity.publicKey,
+      updateAuthority: updateAuthority('Collection', [collection.publicKey]),
+      name: asset.name,
+      uri: asset.uri,
+      seq: 1,
+      plugins: [],
+    },
+  }).sendAndConfirm(umi);
+
+  // Then the asset is burned and the collection size is decremented.
+  await assertBurned(t, umi, asset.publicKey);
+  const afterCollection = await fetchCollectionV1(umi, collection.publicKey);
+  t.is(afterCollection.currentSize, 0);
+});
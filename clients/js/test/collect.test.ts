@@ -16,7 +16,7 @@ import {
   pluginAuthorityPair,
   removePluginV1,
 } from '../src';
-import { createAsset, createUmi } from './_setupRaw';
+import { createAsset, createCollection, createUmi } from './_setupRaw';
 
 const recipient1 = publicKey('8AT6o8Qk5T9QnZvPThMrF9bcCQLTGkyGvVZZzHgCw11v');
 const recipient2 = publicKey('MmHsqX4LxTfifxoH8BVRLUKrwDn1LPCac6YcCZTHhwt');
@@ -131,3 +131,79 @@ test.serial('it can collect burned asset', async (t) => {
   t.deepEqual(subtractAmounts(balEnd1, balStart1), sol(0.0015 / 2));
   t.deepEqual(subtractAmounts(balEnd2, balStart2), sol(0.0015 / 2));
 });
+
+test.serial('it can collect an asset with plugins', async (t) => {
+  const umi = await createUmi();
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Attributes',
+        data: {
+          attributeList: [{ key: 'rarity', value: 'legendary' }],
+        },
+      }),
+      pluginAuthorityPair({
+        type: 'FreezeDelegate',
+        data: { frozen: false },
+      }),
+    ],
+  });
+  const balStart1 = await umi.rpc.getBalance(recipient1);
+  const balStart2 = await umi.rpc.getBalance(recipient2);
+  await collect(umi, {})
+    .addRemainingAccounts({
+      isSigner: false,
+      isWritable: true,
+      pubkey: asset.publicKey,
+    })
+    .sendAndConfirm(umi);
+  const balEnd1 = await umi.rpc.getBalance(recipient1);
+  const balEnd2 = await umi.rpc.getBalance(recipient2);
+  t.is(await hasCollectAmount(umi, asset.publicKey), false);
+  t.deepEqual(subtractAmounts(balEnd1, balStart1), sol(0.0015 / 2));
+  t.deepEqual(subtractAmounts(balEnd2, balStart2), sol(0.0015 / 2));
+
+  const account = await umi.rpc.getAccount(asset.publicKey);
+  t.assert(account.exists);
+  if (account.exists) {
+    const rent = await umi.rpc.getRent(account.data.length);
+    t.is(account.lamports.basisPoints, rent.basisPoints);
+  }
+});
+
+test.serial('it can collect from a collection', async (t) => {
+  const umi = await createUmi();
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Attributes',
+        data: {
+          attributeList: [{ key: 'rarity', value: 'legendary' }],
+        },
+      }),
+    ],
+  });
+  await umi.rpc.airdrop(collection.publicKey, sol(0.0015));
+
+  const balStart1 = await umi.rpc.getBalance(recipient1);
+  const balStart2 = await umi.rpc.getBalance(recipient2);
+  await collect(umi, {})
+    .addRemainingAccounts({
+      isSigner: false,
+      isWritable: true,
+      pubkey: collection.publicKey,
+    })
+    .sendAndConfirm(umi);
+  const balEnd1 = await umi.rpc.getBalance(recipient1);
+  const balEnd2 = await umi.rpc.getBalance(recipient2);
+  t.is(await hasCollectAmount(umi, collection.publicKey), false);
+  t.deepEqual(subtractAmounts(balEnd1, balStart1), sol(0.0015 / 2));
+  t.deepEqual(subtractAmounts(balEnd2, balStart2), sol(0.0015 / 2));
+
+  const account = await umi.rpc.getAccount(collection.publicKey);
+  t.assert(account.exists);
+  if (account.exists) {
+    const rent = await umi.rpc.getRent(account.data.length);
+    t.is(account.lamports.basisPoints, rent.basisPoints);
+  }
+});
@@ -1,4 +1,4 @@
-import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import { generateSigner, publicKey, some } from '@metaplex-foundation/umi';
 import test from 'ava';
 import { getAssetV1AccountDataSerializer } from '../src/hooked';
 import {
@@ -14,8 +14,9 @@ import {
 } from '../src';
 
 import { createAsset, createUmi } from './_setupRaw';
+import { createAsset as createAssetWithPlugins } from './_setupSdk';
 
-test.skip('it can compress an asset without any plugins as the owner', async (t) => {
+test('it can compress an asset without any plugins as the owner', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
   const asset = await createAsset(umi);
@@ -32,9 +33,11 @@ test.skip('it can compress an asset without any plugins as the owner', async (t)
   const afterAsset = await fetchHashedAssetV1(umi, asset.publicKey);
   // console.log("Account State:", afterAsset);
 
-  // And the hash matches the expected value.
+  // And the hash matches the expected value, including the initialized seq.
   const hashedAssetSchema: HashedAssetSchema = {
-    assetHash: hash(getAssetV1AccountDataSerializer().serialize(asset)),
+    assetHash: hash(
+      getAssetV1AccountDataSerializer().serialize({ ...asset, seq: some(1) })
+    ),
     pluginHashes: [],
   };
 
@@ -44,7 +47,7 @@ test.skip('it can compress an asset without any plugins as the owner', async (t)
   t.deepEqual(afterAsset.hash, hashedAsset);
 });
 
-test.skip('it cannot compress an asset if not the owner', async (t) => {
+test('it cannot compress an asset if not the owner', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
   const assetAddress = generateSigner(umi);
@@ -87,38 +90,35 @@ test.skip('it cannot compress an asset if not the owner', async (t) => {
   });
 });
 
-test('it cannot compress an asset because it is not available', async (t) => {
-  // Given a Umi instance and a new signer.
+test('it cannot compress an asset with external plugin adapters', async (t) => {
+  // Given a Umi instance and an asset with a data store.
   const umi = await createUmi();
-  const assetAddress = generateSigner(umi);
-
-  // When we create a new account.
-  await createV1(umi, {
-    dataState: DataState.AccountState,
-    asset: assetAddress,
-    name: 'Test Bread',
-    uri: 'https://example.com/bread',
-  }).sendAndConfirm(umi);
-
-  // Then an account was created with the correct data.
-  const beforeAsset = await fetchAssetV1(umi, assetAddress.publicKey);
-  // console.log("Account State:", beforeAsset);
-  t.like(beforeAsset, <AssetV1>{
-    publicKey: assetAddress.publicKey,
-    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
-    owner: umi.identity.publicKey,
-    name: 'Test Bread',
-    uri: 'https://example.com/bread',
+  const asset = await createAssetWithPlugins(umi, {
+    plugins: [
+      {
+        type: 'DataStore',
+        dataAuthority: { type: 'UpdateAuthority' },
+      },
+    ],
   });
 
-  // And when we compress the asset.
+  // When we try to compress the asset.
   const result = compressV1(umi, {
-    asset: assetAddress.publicKey,
+    asset: asset.publicKey,
     authority: umi.identity,
     logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'NotAvailable' });
+  // Then it fails and the asset is unchanged.
+  await t.throwsAsync(result, {
+    name: 'CannotCompressExternalPluginAdapters',
+  });
+
+  const afterAsset = await fetchAssetV1(umi, asset.publicKey);
+  t.like(afterAsset, <AssetV1>{
+    publicKey: asset.publicKey,
+    owner: umi.identity.publicKey,
+  });
 });
 
 test('it cannot use an invalid system program', async (t) => {
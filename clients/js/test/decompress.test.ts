@@ -1,4 +1,4 @@
-import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import { generateSigner, publicKey, some } from '@metaplex-foundation/umi';
 import test from 'ava';
 import { getAssetV1AccountDataSerializer } from '../src/hooked';
 import {
@@ -16,7 +16,7 @@ import {
 } from '../src';
 import { createUmi } from './_setupRaw';
 
-test.skip('it can decompress a previously compressed asset as the owner', async (t) => {
+test('it can decompress a previously compressed asset as the owner', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
   const assetAddress = generateSigner(umi);
@@ -53,9 +53,14 @@ test.skip('it can decompress a previously compressed asset as the owner', async
     assetAddress.publicKey
   );
 
-  // And the hash matches the expected value.
+  // And the hash matches the expected value, including the initialized seq.
   const hashedAssetSchema: HashedAssetSchema = {
-    assetHash: hash(getAssetV1AccountDataSerializer().serialize(beforeAsset)),
+    assetHash: hash(
+      getAssetV1AccountDataSerializer().serialize({
+        ...beforeAsset,
+        seq: some(1),
+      })
+    ),
     pluginHashes: [],
   };
 
@@ -90,6 +95,7 @@ test.skip('it can decompress a previously compressed asset as the owner', async
     owner: umi.identity.publicKey,
     name: 'Test Bread',
     uri: 'https://example.com/bread',
+    seq: some(2n),
   });
 });
 
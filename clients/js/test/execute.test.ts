@@ -0,0 +1,166 @@
+import test from 'ava';
+import { createNoopSigner, generateSigner, sol } from '@metaplex-foundation/umi';
+import { transferSol } from '@metaplex-foundation/mpl-toolbox';
+import {
+  execute,
+  executeV1,
+  findAssetSignerPda,
+  pluginAuthorityPair,
+  transferV1,
+} from '../src';
+import { assertAsset, createAsset, createUmi } from './_setupRaw';
+
+test('it can execute a SOL transfer as the asset signer', async (t) => {
+  // Given an asset whose signer PDA holds some SOL.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const recipient = generateSigner(umi);
+  const asset = await createAsset(umi, { owner });
+  const [assetSigner] = findAssetSignerPda(umi, { asset: asset.publicKey });
+
+  await transferSol(umi, {
+    destination: assetSigner,
+    amount: sol(0.5),
+  }).sendAndConfirm(umi);
+
+  // When the owner executes a transfer from the asset signer.
+  const [instruction] = transferSol(umi, {
+    source: createNoopSigner(assetSigner),
+    destination: recipient.publicKey,
+    amount: sol(0.1),
+  }).getInstructions();
+
+  await execute(umi, {
+    asset,
+    authority: owner,
+    instruction,
+  }).sendAndConfirm(umi);
+
+  // Then the recipient received the SOL from the asset signer.
+  t.deepEqual(await umi.rpc.getBalance(recipient.publicKey), sol(0.1));
+  t.deepEqual(await umi.rpc.getBalance(assetSigner), sol(0.4));
+});
+
+test('it can transfer an asset owned by another asset', async (t) => {
+  // Given a backpack asset that owns an item asset.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+  const backpack = await createAsset(umi, { owner });
+  const [backpackSigner] = findAssetSignerPda(umi, {
+    asset: backpack.publicKey,
+  });
+  const item = await createAsset(umi, { owner: backpackSigner });
+
+  // When the backpack owner executes a transfer of the item.
+  const [instruction] = transferV1(umi, {
+    asset: item.publicKey,
+    newOwner: newOwner.publicKey,
+    authority: createNoopSigner(backpackSigner),
+  }).getInstructions();
+
+  await execute(umi, {
+    asset: backpack,
+    authority: owner,
+    instruction,
+  }).sendAndConfirm(umi);
+
+  // Then the item has a new owner.
+  await assertAsset(t, umi, {
+    asset: item.publicKey,
+    owner: newOwner.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+  });
+});
+
+test('it cannot execute as the asset signer if not the owner', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const attacker = generateSigner(umi);
+  const asset = await createAsset(umi, { owner });
+  const [assetSigner] = findAssetSignerPda(umi, { asset: asset.publicKey });
+
+  await transferSol(umi, {
+    destination: assetSigner,
+    amount: sol(0.5),
+  }).sendAndConfirm(umi);
+
+  const [instruction] = transferSol(umi, {
+    source: createNoopSigner(assetSigner),
+    destination: attacker.publicKey,
+    amount: sol(0.1),
+  }).getInstructions();
+
+  const result = execute(umi, {
+    asset,
+    authority: attacker,
+    instruction,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'NoApprovals' });
+});
+
+test('it cannot execute as the signer of a frozen asset', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const recipient = generateSigner(umi);
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'FreezeDelegate',
+        data: { frozen: true },
+      }),
+    ],
+  });
+  const [assetSigner] = findAssetSignerPda(umi, { asset: asset.publicKey });
+
+  await transferSol(umi, {
+    destination: assetSigner,
+    amount: sol(0.5),
+  }).sendAndConfirm(umi);
+
+  const [instruction] = transferSol(umi, {
+    source: createNoopSigner(assetSigner),
+    destination: recipient.publicKey,
+    amount: sol(0.1),
+  }).getInstructions();
+
+  const result = execute(umi, {
+    asset,
+    authority: owner,
+    instruction,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('it cannot execute with an incorrect asset signer', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const asset = await createAsset(umi, { owner });
+  const otherAsset = await createAsset(umi, { owner });
+  const [otherSigner] = findAssetSignerPda(umi, {
+    asset: otherAsset.publicKey,
+  });
+
+  const [instruction] = transferSol(umi, {
+    source: createNoopSigner(otherSigner),
+    destination: owner.publicKey,
+    amount: sol(0.1),
+  }).getInstructions();
+
+  const result = executeV1(umi, {
+    asset: asset.publicKey,
+    assetSigner: otherSigner,
+    authority: owner,
+    programId: instruction.programId,
+    instructionData: instruction.data,
+  })
+    .addRemainingAccounts(
+      instruction.keys.map((key) => ({ ...key, isSigner: false }))
+    )
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidExecutePda' });
+});
@@ -21,7 +21,12 @@ import {
   preconfiguredAssetPdaCustomOffsetSet,
   close,
 } from '@metaplex-foundation/mpl-core-oracle-example';
-import { generateSigner, sol } from '@metaplex-foundation/umi';
+import {
+  generateSigner,
+  PublicKey,
+  sol,
+  Umi,
+} from '@metaplex-foundation/umi';
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import { createAccount } from '@metaplex-foundation/mpl-toolbox';
 import {
@@ -46,6 +51,13 @@ import {
   fetchAssetV1,
   ExternalValidationResult,
   ruleSet,
+  removePlugin,
+  ExternalPluginAdapterSchema,
+  getOracleValidationSerializer,
+  writeExternalPluginAdapterDataV1,
+  OraclePluginValidationArgs,
+  OracleValidationArgs,
+  PluginType,
 } from '../../src';
 
 const createUmi = async () =>
@@ -177,6 +189,7 @@ test('it can add multiple oracles and internal plugins to asset', async (t) => {
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   }).sendAndConfirm(umi);
 
@@ -223,6 +236,7 @@ test('it can add multiple oracles and internal plugins to asset', async (t) => {
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
     freezeDelegate: {
       authority: {
@@ -3035,3 +3049,367 @@ test('it empty account does not default to valid oracle', async (t) => {
 
   await t.throwsAsync(result, { name: 'UninitializedOracleAccount' });
 });
+
+const pluginValidation = (
+  defaultResult: ExternalValidationResult,
+  pluginTypes: [PluginType, ExternalValidationResult][] = []
+): OraclePluginValidationArgs => ({ default: defaultResult, pluginTypes });
+
+// The example oracle program only writes `V1` validations, so `V2` validations are written into
+// a DataStore on a holder asset and the oracle reads them using a custom results offset.
+const createV2OracleAccount = async (
+  umi: Umi,
+  results: Partial<
+    Omit<Extract<OracleValidationArgs, { __kind: 'V2' }>, '__kind'>
+  >
+): Promise<{ address: PublicKey; offset: bigint }> => {
+  const holder = await createAsset(umi, {
+    plugins: [
+      {
+        type: 'DataStore',
+        dataAuthority: { type: 'Address', address: umi.identity.publicKey },
+        schema: ExternalPluginAdapterSchema.Binary,
+      },
+    ],
+  });
+
+  const marker = new Uint8Array([0xca, 0xfe, 0xba, 0xbe]);
+  const validation = getOracleValidationSerializer().serialize({
+    __kind: 'V2',
+    create: ExternalValidationResult.Pass,
+    transfer: ExternalValidationResult.Pass,
+    burn: ExternalValidationResult.Pass,
+    update: ExternalValidationResult.Pass,
+    addPlugin: pluginValidation(ExternalValidationResult.Pass),
+    removePlugin: pluginValidation(ExternalValidationResult.Pass),
+    updatePlugin: pluginValidation(ExternalValidationResult.Pass),
+    approvePluginAuthority: pluginValidation(ExternalValidationResult.Pass),
+    revokePluginAuthority: pluginValidation(ExternalValidationResult.Pass),
+    compress: ExternalValidationResult.Pass,
+    decompress: ExternalValidationResult.Pass,
+    ...results,
+  });
+
+  await writeExternalPluginAdapterDataV1(umi, {
+    asset: holder.publicKey,
+    key: {
+      __kind: 'DataStore',
+      fields: [{ __kind: 'Address', address: umi.identity.publicKey }],
+    },
+    data: new Uint8Array([...marker, ...validation]),
+  }).sendAndConfirm(umi);
+
+  const account = await umi.rpc.getAccount(holder.publicKey);
+  if (!account.exists) throw new Error('Holder account not found');
+  const { data } = account;
+  const index = data.findIndex((_, i) =>
+    marker.every((byte, j) => data[i + j] === byte)
+  );
+
+  return {
+    address: holder.publicKey,
+    offset: BigInt(index + marker.length),
+  };
+};
+
+test('it can use oracle to deny removing a freeze delegate', async (t) => {
+  const umi = await createUmi();
+  const oracle = await createV2OracleAccount(umi, {
+    removePlugin: pluginValidation(ExternalValidationResult.Rejected),
+  });
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      {
+        type: 'FreezeDelegate',
+        frozen: false,
+      },
+      {
+        type: 'Oracle',
+        resultsOffset: {
+          type: 'Custom',
+          offset: oracle.offset,
+        },
+        lifecycleChecks: {
+          removePlugin: [CheckResult.CAN_REJECT],
+        },
+        baseAddress: oracle.address,
+      },
+    ],
+  });
+
+  const result = removePlugin(umi, {
+    asset: asset.publicKey,
+    plugin: { type: 'FreezeDelegate' },
+  })
+    .addRemainingAccounts({
+      pubkey: oracle.address,
+      isSigner: false,
+      isWritable: false,
+    })
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    freezeDelegate: {
+      authority: {
+        type: 'Owner',
+      },
+      frozen: false,
+    },
+    oracles: [
+      {
+        type: 'Oracle',
+        resultsOffset: {
+          type: 'Custom',
+          offset: oracle.offset,
+        },
+        authority: {
+          type: 'UpdateAuthority',
+        },
+        baseAddress: oracle.address,
+        lifecycleChecks: {
+          removePlugin: [CheckResult.CAN_REJECT],
+        },
+        baseAddressConfig: undefined,
+      },
+    ],
+  });
+});
+
+test('it can use oracle to deny removing one plugin type while allowing another', async (t) => {
+  const umi = await createUmi();
+  const oracle = await createV2OracleAccount(umi, {
+    removePlugin: pluginValidation(ExternalValidationResult.Pass, [
+      [PluginType.FreezeDelegate, ExternalValidationResult.Rejected],
+    ]),
+  });
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      {
+        type: 'FreezeDelegate',
+        frozen: false,
+      },
+      {
+        type: 'BurnDelegate',
+      },
+      {
+        type: 'Oracle',
+        resultsOffset: {
+          type: 'Custom',
+          offset: oracle.offset,
+        },
+        lifecycleChecks: {
+          removePlugin: [CheckResult.CAN_REJECT],
+        },
+        baseAddress: oracle.address,
+      },
+    ],
+  });
+
+  await removePlugin(umi, {
+    asset: asset.publicKey,
+    plugin: { type: 'BurnDelegate' },
+  })
+    .addRemainingAccounts({
+      pubkey: oracle.address,
+      isSigner: false,
+      isWritable: false,
+    })
+    .sendAndConfirm(umi);
+
+  const result = removePlugin(umi, {
+    asset: asset.publicKey,
+    plugin: { type: 'FreezeDelegate' },
+  })
+    .addRemainingAccounts({
+      pubkey: oracle.address,
+      isSigner: false,
+      isWritable: false,
+    })
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    freezeDelegate: {
+      authority: {
+        type: 'Owner',
+      },
+      frozen: false,
+    },
+    burnDelegate: undefined,
+  });
+});
+
+test('it can use oracle on collection to deny adding a transfer delegate', async (t) => {
+  const umi = await createUmi();
+  const oracle = await createV2OracleAccount(umi, {
+    addPlugin: pluginValidation(ExternalValidationResult.Pass, [
+      [PluginType.TransferDelegate, ExternalValidationResult.Rejected],
+    ]),
+  });
+
+  const { asset, collection } = await createAssetWithCollection(
+    umi,
+    {},
+    {
+      plugins: [
+        {
+          type: 'Oracle',
+          resultsOffset: {
+            type: 'Custom',
+            offset: oracle.offset,
+          },
+          lifecycleChecks: {
+            addPlugin: [CheckResult.CAN_REJECT],
+          },
+          baseAddress: oracle.address,
+        },
+      ],
+    }
+  );
+
+  const result = addPlugin(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    plugin: {
+      type: 'TransferDelegate',
+    },
+  })
+    .addRemainingAccounts({
+      pubkey: oracle.address,
+      isSigner: false,
+      isWritable: false,
+    })
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: collection.publicKey },
+    transferDelegate: undefined,
+  });
+});
+
+test('it can add a plugin when oracle passes the add plugin event', async (t) => {
+  const umi = await createUmi();
+  const oracle = await createV2OracleAccount(umi, {
+    removePlugin: pluginValidation(ExternalValidationResult.Rejected),
+  });
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      {
+        type: 'Oracle',
+        resultsOffset: {
+          type: 'Custom',
+          offset: oracle.offset,
+        },
+        lifecycleChecks: {
+          addPlugin: [CheckResult.CAN_REJECT],
+          removePlugin: [CheckResult.CAN_REJECT],
+        },
+        baseAddress: oracle.address,
+      },
+    ],
+  });
+
+  await addPlugin(umi, {
+    asset: asset.publicKey,
+    plugin: {
+      type: 'FreezeDelegate',
+      frozen: false,
+    },
+  })
+    .addRemainingAccounts({
+      pubkey: oracle.address,
+      isSigner: false,
+      isWritable: false,
+    })
+    .sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    freezeDelegate: {
+      authority: {
+        type: 'Owner',
+      },
+      frozen: false,
+    },
+  });
+});
+
+test('it passes plugin lifecycle events for V1 oracle accounts', async (t) => {
+  const umi = await createUmi();
+  const account = generateSigner(umi);
+
+  await fixedAccountInit(umi, {
+    account,
+    signer: umi.identity,
+    payer: umi.identity,
+    args: {
+      oracleData: {
+        __kind: 'V1',
+        create: ExternalValidationResult.Rejected,
+        update: ExternalValidationResult.Rejected,
+        transfer: ExternalValidationResult.Rejected,
+        burn: ExternalValidationResult.Rejected,
+      },
+    },
+  }).sendAndConfirm(umi);
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      {
+        type: 'Oracle',
+        resultsOffset: {
+          type: 'Anchor',
+        },
+        lifecycleChecks: {
+          addPlugin: [CheckResult.CAN_REJECT],
+        },
+        baseAddress: account.publicKey,
+      },
+    ],
+  });
+
+  await addPlugin(umi, {
+    asset: asset.publicKey,
+    plugin: {
+      type: 'FreezeDelegate',
+      frozen: false,
+    },
+  })
+    .addRemainingAccounts({
+      pubkey: account.publicKey,
+      isSigner: false,
+      isWritable: false,
+    })
+    .sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    freezeDelegate: {
+      authority: {
+        type: 'Owner',
+      },
+      frozen: false,
+    },
+  });
+});
@@ -1,8 +1,11 @@
 import test from 'ava';
+import { generateSigner } from '@metaplex-foundation/umi';
 import {
   createPlugin,
   pluginAuthorityPair,
+  removeAttributeV1,
   updatePluginV1,
+  upsertAttributeV1,
 } from '../../../src';
 import {
   DEFAULT_ASSET,
@@ -190,3 +193,428 @@ test('it can add then remove attributes to an asset', async (t) => {
     },
   });
 });
+
+test('it can let the owner write an attribute key while the update authority writes the rest', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner: owner.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'AttributesV2',
+        data: {
+          attributeList: [
+            { key: 'name', value: 'hero' },
+            { key: 'strength', value: '10' },
+          ],
+          keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+        },
+      }),
+    ],
+  });
+
+  // The owner renames their character.
+  await updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'AttributesV2',
+      data: {
+        attributeList: [
+          { key: 'name', value: 'villain' },
+          { key: 'strength', value: '10' },
+        ],
+        keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+      },
+    }),
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  // The update authority raises the stats.
+  await updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'AttributesV2',
+      data: {
+        attributeList: [
+          { key: 'name', value: 'villain' },
+          { key: 'strength', value: '20' },
+        ],
+        keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+      },
+    }),
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+    attributesV2: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      attributeList: [
+        { key: 'name', value: 'villain' },
+        { key: 'strength', value: '20' },
+      ],
+      keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+    },
+  });
+});
+
+test('it cannot write attribute keys controlled by another authority', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner: owner.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'AttributesV2',
+        data: {
+          attributeList: [
+            { key: 'name', value: 'hero' },
+            { key: 'strength', value: '10' },
+          ],
+          keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+        },
+      }),
+    ],
+  });
+
+  // The owner cannot change the stats.
+  const ownerResult = updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'AttributesV2',
+      data: {
+        attributeList: [
+          { key: 'name', value: 'hero' },
+          { key: 'strength', value: '99' },
+        ],
+        keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+      },
+    }),
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(ownerResult, { name: 'InvalidAuthority' });
+
+  // The update authority cannot rename the character.
+  const updateAuthorityResult = updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'AttributesV2',
+      data: {
+        attributeList: [
+          { key: 'name', value: 'villain' },
+          { key: 'strength', value: '10' },
+        ],
+        keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+      },
+    }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(updateAuthorityResult, { name: 'InvalidAuthority' });
+
+  // The owner cannot take over the stats.
+  const keyAuthoritiesResult = updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'AttributesV2',
+      data: {
+        attributeList: [
+          { key: 'name', value: 'hero' },
+          { key: 'strength', value: '10' },
+        ],
+        keyAuthorities: [
+          { key: 'name', authority: { type: 'Owner' } },
+          { key: 'strength', authority: { type: 'Owner' } },
+        ],
+      },
+    }),
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(keyAuthoritiesResult, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+    attributesV2: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      attributeList: [
+        { key: 'name', value: 'hero' },
+        { key: 'strength', value: '10' },
+      ],
+      keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+    },
+  });
+});
+
+test('it can upsert a single attribute in place', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+
+  // The freeze delegate comes after the attributes and has to be shifted.
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Attributes',
+        data: {
+          attributeList: [
+            { key: 'level', value: '1' },
+            { key: 'class', value: 'mage' },
+          ],
+        },
+      }),
+      pluginAuthorityPair({
+        type: 'FreezeDelegate',
+        data: { frozen: false },
+      }),
+    ],
+  });
+
+  await upsertAttributeV1(umi, {
+    asset: asset.publicKey,
+    key: 'level',
+    value: '100',
+  }).sendAndConfirm(umi);
+
+  await upsertAttributeV1(umi, {
+    asset: asset.publicKey,
+    key: 'guild',
+    value: 'metaplex',
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    attributes: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      attributeList: [
+        { key: 'level', value: '100' },
+        { key: 'class', value: 'mage' },
+        { key: 'guild', value: 'metaplex' },
+      ],
+    },
+    freezeDelegate: {
+      authority: {
+        type: 'Owner',
+      },
+      frozen: false,
+    },
+  });
+});
+
+test('it can remove a single attribute in place', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Attributes',
+        data: {
+          attributeList: [
+            { key: 'level', value: '1' },
+            { key: 'class', value: 'mage' },
+          ],
+        },
+      }),
+      pluginAuthorityPair({
+        type: 'FreezeDelegate',
+        data: { frozen: false },
+      }),
+    ],
+  });
+
+  await removeAttributeV1(umi, {
+    asset: asset.publicKey,
+    key: 'level',
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    attributes: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      attributeList: [{ key: 'class', value: 'mage' }],
+    },
+    freezeDelegate: {
+      authority: {
+        type: 'Owner',
+      },
+      frozen: false,
+    },
+  });
+
+  const result = removeAttributeV1(umi, {
+    asset: asset.publicKey,
+    key: 'level',
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'AttributeNotFound' });
+});
+
+test('it can only upsert attribute keys the signer controls', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner: owner.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'AttributesV2',
+        data: {
+          attributeList: [{ key: 'level', value: '1' }],
+          keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+        },
+      }),
+    ],
+  });
+
+  await upsertAttributeV1(umi, {
+    asset: asset.publicKey,
+    key: 'name',
+    value: 'hero',
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  const result = upsertAttributeV1(umi, {
+    asset: asset.publicKey,
+    key: 'level',
+    value: '100',
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+    attributesV2: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      attributeList: [
+        { key: 'level', value: '1' },
+        { key: 'name', value: 'hero' },
+      ],
+      keyAuthorities: [{ key: 'name', authority: { type: 'Owner' } }],
+    },
+  });
+});
+
+test('it cannot create an asset with duplicate attribute keys', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+
+  const result = createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Attributes',
+        data: {
+          attributeList: [
+            { key: 'level', value: '1' },
+            { key: 'level', value: '100' },
+          ],
+        },
+      }),
+    ],
+  });
+
+  await t.throwsAsync(result, { name: 'InvalidPluginSetting' });
+});
+
+test('it cannot update attributes to have duplicate keys', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner: owner.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'AttributesV2',
+        data: {
+          attributeList: [{ key: 'level', value: '1' }],
+          keyAuthorities: [{ key: 'level', authority: { type: 'Owner' } }],
+        },
+      }),
+    ],
+  });
+
+  // A second entry for a key must not hide a change to its value from the
+  // key's authority.
+  const result = updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'AttributesV2',
+      data: {
+        attributeList: [
+          { key: 'level', value: '1' },
+          { key: 'level', value: '100' },
+        ],
+        keyAuthorities: [{ key: 'level', authority: { type: 'Owner' } }],
+      },
+    }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidPluginSetting' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+    attributesV2: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      attributeList: [{ key: 'level', value: '1' }],
+      keyAuthorities: [{ key: 'level', authority: { type: 'Owner' } }],
+    },
+  });
+});
+
+test('it cannot create attributes with a multisig key authority with duplicate signers', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const signer = generateSigner(umi);
+
+  const result = createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'AttributesV2',
+        data: {
+          attributeList: [],
+          keyAuthorities: [
+            {
+              key: 'name',
+              authority: {
+                type: 'Multisig',
+                threshold: 2,
+                signers: [signer.publicKey, signer.publicKey],
+              },
+            },
+          ],
+        },
+      }),
+    ],
+  });
+
+  await t.throwsAsync(result, { name: 'InvalidMultisigAuthority' });
+});
@@ -689,3 +689,112 @@ test('it can add another plugin on asset with permanent transfer plugin', async
     },
   });
 });
+
+test('it can transfer an asset as a multisig permanent transfer delegate', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+  const signerA = generateSigner(umi);
+  const signerB = generateSigner(umi);
+  const signerC = generateSigner(umi);
+
+  // Given an asset whose permanent transfer delegate is a 2-of-3 multisig.
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'PermanentTransferDelegate',
+        authority: {
+          __kind: 'Multisig',
+          threshold: 2,
+          signers: [signerA.publicKey, signerB.publicKey, signerC.publicKey],
+        },
+      }),
+    ],
+  });
+
+  // When two of the listed signers approve the transfer.
+  await transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    authority: signerA,
+  })
+    .addRemainingAccounts({ signer: signerC, isWritable: false })
+    .sendAndConfirm(umi);
+
+  // Then the asset has moved to the new owner.
+  await assertAsset(t, umi, {
+    ...asset,
+    asset: asset.publicKey,
+    owner: newOwner.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+    permanentTransferDelegate: {
+      authority: {
+        type: 'Multisig',
+        threshold: 2,
+        signers: [signerA.publicKey, signerB.publicKey, signerC.publicKey],
+      },
+    },
+  });
+});
+
+test('it cannot transfer an asset as a multisig delegate below threshold', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+  const signerA = generateSigner(umi);
+  const signerB = generateSigner(umi);
+  const signerC = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'PermanentTransferDelegate',
+        authority: {
+          __kind: 'Multisig',
+          threshold: 2,
+          signers: [signerA.publicKey, signerB.publicKey, signerC.publicKey],
+        },
+      }),
+    ],
+  });
+
+  // When only one of the listed signers approves the transfer.
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    authority: signerA,
+  }).sendAndConfirm(umi);
+
+  // Then it fails and the owner is unchanged.
+  await t.throwsAsync(result, { name: 'NoApprovals' });
+
+  await assertAsset(t, umi, {
+    ...asset,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+  });
+});
+
+test('it cannot create a multisig authority with an invalid threshold', async (t) => {
+  const umi = await createUmi();
+  const signerA = generateSigner(umi);
+  const signerB = generateSigner(umi);
+
+  const result = createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'PermanentTransferDelegate',
+        authority: {
+          __kind: 'Multisig',
+          threshold: 3,
+          signers: [signerA.publicKey, signerB.publicKey],
+        },
+      }),
+    ],
+  });
+
+  await t.throwsAsync(result, { name: 'InvalidMultisigAuthority' });
+});
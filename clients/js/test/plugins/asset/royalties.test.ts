@@ -8,8 +8,10 @@ import {
   MPL_CORE_PROGRAM_ID,
   addPluginV1,
   createPlugin,
+  fetchAssetV1,
   pluginAuthorityPair,
   ruleSet,
+  transfer,
   transferV1,
   updatePluginV1,
 } from '../../../src';
@@ -34,6 +36,7 @@ test('it can transfer an asset with royalties', async (t) => {
           basisPoints: 5,
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -56,6 +59,7 @@ test('it can transfer an asset with royalties', async (t) => {
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 
@@ -84,6 +88,7 @@ test('it can transfer an asset with collection royalties', async (t) => {
             basisPoints: 5,
             creators: [{ address: umi.identity.publicKey, percentage: 100 }],
             ruleSet: ruleSet('None'),
+            royaltyPayments: false,
           },
         }),
       ],
@@ -111,6 +116,7 @@ test('it can transfer an asset with collection royalties', async (t) => {
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 
@@ -140,6 +146,7 @@ test('it can transfer an asset with royalties to an allowlisted program address'
           ruleSet: ruleSet('ProgramAllowList', [
             [SPL_SYSTEM_PROGRAM_ID, MPL_CORE_PROGRAM_ID],
           ]),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -163,6 +170,7 @@ test('it can transfer an asset with royalties to an allowlisted program address'
       ruleSet: ruleSet('ProgramAllowList', [
         [SPL_SYSTEM_PROGRAM_ID, MPL_CORE_PROGRAM_ID],
       ]),
+      royaltyPayments: false,
     },
   });
 
@@ -193,6 +201,7 @@ test('it can transfer an asset with collection royalties to an allowlisted progr
             ruleSet: ruleSet('ProgramAllowList', [
               [SPL_SYSTEM_PROGRAM_ID, MPL_CORE_PROGRAM_ID],
             ]),
+            royaltyPayments: false,
           },
         }),
       ],
@@ -222,6 +231,7 @@ test('it can transfer an asset with collection royalties to an allowlisted progr
       ruleSet: ruleSet('ProgramAllowList', [
         [SPL_SYSTEM_PROGRAM_ID, MPL_CORE_PROGRAM_ID],
       ]),
+      royaltyPayments: false,
     },
   });
 
@@ -256,6 +266,7 @@ test('it cannot transfer an asset with royalties to a program address not on the
           basisPoints: 5,
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           ruleSet: ruleSet('ProgramAllowList', [[SPL_SYSTEM_PROGRAM_ID]]),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -273,6 +284,7 @@ test('it cannot transfer an asset with royalties to a program address not on the
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('ProgramAllowList', [[SPL_SYSTEM_PROGRAM_ID]]),
+      royaltyPayments: false,
     },
   });
 
@@ -301,6 +313,7 @@ test('it cannot transfer an asset with collection royalties to a program address
             basisPoints: 5,
             creators: [{ address: umi.identity.publicKey, percentage: 100 }],
             ruleSet: ruleSet('ProgramAllowList', [[SPL_SYSTEM_PROGRAM_ID]]),
+            royaltyPayments: false,
           },
         }),
       ],
@@ -328,6 +341,7 @@ test('it cannot transfer an asset with collection royalties to a program address
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('ProgramAllowList', [[SPL_SYSTEM_PROGRAM_ID]]),
+      royaltyPayments: false,
     },
   });
 
@@ -362,6 +376,7 @@ test('it can transfer an asset with royalties to a program address not on the de
           basisPoints: 5,
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           ruleSet: ruleSet('ProgramDenyList', [[SPL_TOKEN_PROGRAM_ID]]),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -380,6 +395,7 @@ test('it can transfer an asset with royalties to a program address not on the de
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('ProgramDenyList', [[SPL_TOKEN_PROGRAM_ID]]),
+      royaltyPayments: false,
     },
   });
 
@@ -408,6 +424,7 @@ test('it can transfer an asset with collection royalties to a program address no
             basisPoints: 5,
             creators: [{ address: umi.identity.publicKey, percentage: 100 }],
             ruleSet: ruleSet('ProgramDenyList', [[SPL_TOKEN_PROGRAM_ID]]),
+            royaltyPayments: false,
           },
         }),
       ],
@@ -435,6 +452,7 @@ test('it can transfer an asset with collection royalties to a program address no
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('ProgramDenyList', [[SPL_TOKEN_PROGRAM_ID]]),
+      royaltyPayments: false,
     },
   });
 
@@ -466,6 +484,7 @@ test('it cannot transfer an asset with royalties to a denylisted program', async
           basisPoints: 5,
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           ruleSet: ruleSet('ProgramDenyList', [[MPL_CORE_PROGRAM_ID]]),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -500,6 +519,7 @@ test('it cannot transfer an asset with collection royalties to a program address
             basisPoints: 5,
             creators: [{ address: umi.identity.publicKey, percentage: 100 }],
             ruleSet: ruleSet('ProgramDenyList', [[MPL_CORE_PROGRAM_ID]]),
+            royaltyPayments: false,
           },
         }),
       ],
@@ -527,6 +547,7 @@ test('it cannot transfer an asset with collection royalties to a program address
       basisPoints: 5,
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       ruleSet: ruleSet('ProgramDenyList', [[MPL_CORE_PROGRAM_ID]]),
+      royaltyPayments: false,
     },
   });
 
@@ -561,6 +582,7 @@ test('it cannot create royalty percentages that dont add up to 100', async (t) =
             { address: creator2.publicKey, percentage: 20 },
           ],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -580,6 +602,7 @@ test('it cannot create royalty basis points greater than 10000', async (t) => {
           basisPoints: 10001,
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -609,6 +632,7 @@ test('it cannot create royalty with duplicate creators', async (t) => {
             },
           ],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -635,6 +659,7 @@ test('it cannot add royalty percentages that dont add up to 100', async (t) => {
           { address: creator2.publicKey, percentage: 20 },
         ],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
@@ -659,6 +684,7 @@ test('it cannot add royalty percentages that has duplicate creators', async (t)
           { address: creator1.publicKey, percentage: 80 },
         ],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
@@ -678,6 +704,7 @@ test('it cannot add royalty basis points greater than 10000', async (t) => {
         basisPoints: 10001,
         creators: [{ address: umi.identity.publicKey, percentage: 100 }],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
@@ -702,6 +729,7 @@ test('it cannot update royalty percentages that do not add up to 100', async (t)
             { address: creator2.publicKey, percentage: 80 },
           ],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -718,6 +746,7 @@ test('it cannot update royalty percentages that do not add up to 100', async (t)
           { address: creator2.publicKey, percentage: 20 },
         ],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
@@ -736,6 +765,7 @@ test('it cannot update royalty basis points greater than 10000', async (t) => {
           basisPoints: 100,
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -749,6 +779,7 @@ test('it cannot update royalty basis points greater than 10000', async (t) => {
         basisPoints: 10001,
         creators: [{ address: umi.identity.publicKey, percentage: 100 }],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
@@ -768,6 +799,7 @@ test('it cannot update royalty with duplicate creators', async (t) => {
           basisPoints: 100,
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -790,9 +822,378 @@ test('it cannot update royalty with duplicate creators', async (t) => {
           },
         ],
         ruleSet: ruleSet('None'),
+        royaltyPayments: false,
       },
     }),
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, { name: 'InvalidPluginSetting' });
 });
+
+test('it pays royalties to the creators on a priced transfer', async (t) => {
+  // Given a Umi instance and two creators that opted in to royalty payments.
+  const umi = await createUmi();
+  const creatorA = generateSigner(umi);
+  const creatorB = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 500,
+          creators: [
+            { address: creatorA.publicKey, percentage: 60 },
+            { address: creatorB.publicKey, percentage: 40 },
+          ],
+          ruleSet: ruleSet('None'),
+          royaltyPayments: true,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  // When the asset is sold for 1 SOL.
+  await transfer(umi, {
+    asset,
+    newOwner: newOwner.publicKey,
+    salePrice: 1_000_000_000n,
+  }).sendAndConfirm(umi);
+
+  // Then 5% of the price was split between the creators.
+  const balanceA = await umi.rpc.getBalance(creatorA.publicKey);
+  const balanceB = await umi.rpc.getBalance(creatorB.publicKey);
+  t.is(balanceA.basisPoints, 30_000_000n);
+  t.is(balanceB.basisPoints, 20_000_000n);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: publicKey,
+    owner: newOwner.publicKey,
+  });
+});
+
+test('it cannot make a priced transfer without the creator accounts', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const creator = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 500,
+          creators: [{ address: creator.publicKey, percentage: 100 }],
+          ruleSet: ruleSet('None'),
+          royaltyPayments: true,
+        },
+      }),
+    ],
+  });
+
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    salePrice: 1_000_000_000n,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidRoyaltyCreator' });
+});
+
+test('it cannot make a priced transfer too low to pay the royalties', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const creator = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 500,
+          creators: [{ address: creator.publicKey, percentage: 100 }],
+          ruleSet: ruleSet('None'),
+          royaltyPayments: true,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  // When the asset is sold for a price that rounds the royalty down to zero.
+  const result = transfer(umi, {
+    asset,
+    newOwner: newOwner.publicKey,
+    salePrice: 1n,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'RoyaltyUnderpaid' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: publicKey,
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it pays royalties on a priced transfer allowed by the rule set', async (t) => {
+  // Given a Umi instance and a creator that opted in with an allowlist.
+  const umi = await createUmi();
+  const creator = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 500,
+          creators: [{ address: creator.publicKey, percentage: 100 }],
+          ruleSet: ruleSet('ProgramAllowList', [[SPL_SYSTEM_PROGRAM_ID]]),
+          royaltyPayments: true,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  // When the asset is sold to a wallet.
+  await transfer(umi, {
+    asset,
+    newOwner: newOwner.publicKey,
+    salePrice: 1_000_000_000n,
+  }).sendAndConfirm(umi);
+
+  // Then the creator was paid.
+  t.is((await umi.rpc.getBalance(creator.publicKey)).basisPoints, 50_000_000n);
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: publicKey,
+    owner: newOwner.publicKey,
+  });
+});
+
+test('it cannot make a priced transfer to a program address not on the allowlist', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const creator = generateSigner(umi);
+  const programOwner = generateSigner(umi);
+
+  // Here we're creating a new owner that's program owned, so we're just going to use another asset.
+  const programOwned = await createAsset(umi, {
+    owner: programOwner.publicKey,
+  });
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 500,
+          creators: [{ address: creator.publicKey, percentage: 100 }],
+          ruleSet: ruleSet('ProgramAllowList', [[SPL_SYSTEM_PROGRAM_ID]]),
+          royaltyPayments: true,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  // When a sale price is passed, the rule set still applies.
+  const result = transfer(umi, {
+    asset,
+    newOwner: programOwned.publicKey,
+    salePrice: 1_000_000_000n,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: publicKey,
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it cannot bypass the denylist with a sale price of one lamport', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const creator = generateSigner(umi);
+
+  // Here we're creating a new owner that's program owned, so we're just going to use another asset.
+  const programOwned = await createAsset(umi);
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 500,
+          creators: [{ address: creator.publicKey, percentage: 100 }],
+          ruleSet: ruleSet('ProgramDenyList', [[MPL_CORE_PROGRAM_ID]]),
+          royaltyPayments: true,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  const result = transfer(umi, {
+    asset,
+    newOwner: programOwned.publicKey,
+    salePrice: 1n,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  // Then the creator was not paid and the asset did not move.
+  t.is((await umi.rpc.getBalance(creator.publicKey)).basisPoints, 0n);
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: publicKey,
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it can transfer an asset when the invoking program is on the allowlist', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 5,
+          creators: [{ address: umi.identity.publicKey, percentage: 100 }],
+          ruleSet: {
+            type: 'InvokingProgramAllowList',
+            addresses: [MPL_CORE_PROGRAM_ID],
+          },
+          royaltyPayments: false,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  // When the transfer is not made through a CPI, MPL Core is the invoking program.
+  await transfer(umi, {
+    asset,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: publicKey,
+    owner: newOwner.publicKey,
+  });
+});
+
+test('it cannot transfer an asset when the invoking program is not on the allowlist', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 5,
+          creators: [{ address: umi.identity.publicKey, percentage: 100 }],
+          ruleSet: {
+            type: 'InvokingProgramAllowList',
+            addresses: [SPL_TOKEN_PROGRAM_ID],
+          },
+          royaltyPayments: false,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  const result = transfer(umi, {
+    asset,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    asset: publicKey,
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it cannot transfer an asset when the invoking program is on the denylist', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+
+  const { publicKey } = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 5,
+          creators: [{ address: umi.identity.publicKey, percentage: 100 }],
+          ruleSet: {
+            type: 'InvokingProgramDenyList',
+            addresses: [MPL_CORE_PROGRAM_ID],
+          },
+          royaltyPayments: false,
+        },
+      }),
+    ],
+  });
+  const asset = await fetchAssetV1(umi, publicKey);
+
+  const result = transfer(umi, {
+    asset,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    asset: publicKey,
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it cannot check an invoking program rule set without the instructions sysvar', async (t) => {
+  // Given a Umi instance and a new signer.
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Royalties',
+        data: {
+          basisPoints: 5,
+          creators: [{ address: umi.identity.publicKey, percentage: 100 }],
+          ruleSet: {
+            type: 'InvokingProgramDenyList',
+            addresses: [SPL_TOKEN_PROGRAM_ID],
+          },
+          royaltyPayments: false,
+        },
+      }),
+    ],
+  });
+
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'MissingInstructionsSysvar' });
+});
@@ -0,0 +1,203 @@
+import test from 'ava';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  addPluginV1,
+  addressPluginAuthority,
+  burnV1,
+  createPlugin,
+  nonePluginAuthority,
+  pluginAuthorityPair,
+  transferV1,
+} from '../../../src';
+import {
+  DEFAULT_ASSET,
+  assertAsset,
+  assertBurned,
+  createAsset,
+  createUmi,
+} from '../../_setupRaw';
+
+test('it cannot transfer a soulbound asset', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [pluginAuthorityPair({ type: 'Soulbound' })],
+  });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+    soulbound: {
+      authority: {
+        type: 'None',
+      },
+    },
+  });
+
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+  });
+});
+
+test('it can burn a soulbound asset', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [pluginAuthorityPair({ type: 'Soulbound' })],
+  });
+
+  await burnV1(umi, {
+    asset: asset.publicKey,
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  await assertBurned(t, umi, asset.publicKey);
+});
+
+test('it can transfer a soulbound asset with the recovery authority', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+  const recovery = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Soulbound',
+        authority: addressPluginAuthority(recovery.publicKey),
+      }),
+    ],
+  });
+
+  await transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    authority: recovery,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: newOwner.publicKey,
+    soulbound: {
+      authority: {
+        type: 'Address',
+        address: recovery.publicKey,
+      },
+    },
+  });
+});
+
+test('it cannot recover a soulbound asset without a recovery authority', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Soulbound',
+        authority: nonePluginAuthority(),
+      }),
+    ],
+  });
+
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('it cannot transfer a soulbound asset as the update authority by default', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [pluginAuthorityPair({ type: 'Soulbound' })],
+  });
+
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    authority: umi.identity,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+  });
+});
+
+test('it cannot recover a frozen soulbound asset', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+  const recovery = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    owner,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Soulbound',
+        authority: addressPluginAuthority(recovery.publicKey),
+      }),
+      pluginAuthorityPair({
+        type: 'FreezeDelegate',
+        data: { frozen: true },
+      }),
+    ],
+  });
+
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    authority: recovery,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: owner.publicKey,
+  });
+});
+
+test('it cannot add the soulbound plugin after creation', async (t) => {
+  const umi = await createUmi();
+  const owner = generateSigner(umi);
+
+  const asset = await createAsset(umi, { owner });
+
+  const result = addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({ type: 'Soulbound' }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
@@ -0,0 +1,229 @@
+import test from 'ava';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  PluginType,
+  addPluginV1,
+  burnV1,
+  createPlugin,
+  lockExpiry,
+  pluginAuthorityPair,
+  removePluginV1,
+  transferV1,
+  updatePluginV1,
+} from '../../../src';
+import {
+  DEFAULT_ASSET,
+  assertAsset,
+  assertBurned,
+  createAsset,
+  createUmi,
+} from '../../_setupRaw';
+
+const now = () => BigInt(Math.floor(Date.now() / 1000));
+
+test('it can create an asset with a time lock', async (t) => {
+  const umi = await createUmi();
+  const expiry = now() + 3600n;
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'TimeLock',
+        data: { expiry: lockExpiry('UnixTimestamp', [expiry]) },
+      }),
+    ],
+  });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+    timeLock: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      expiry: { __kind: 'UnixTimestamp', fields: [expiry] },
+    },
+  });
+});
+
+test('it cannot transfer or burn an asset before the lock expires', async (t) => {
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'TimeLock',
+        data: { expiry: lockExpiry('UnixTimestamp', [now() + 3600n]) },
+      }),
+    ],
+  });
+
+  const transferResult = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(transferResult, { name: 'InvalidAuthority' });
+
+  const burnResult = burnV1(umi, {
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(burnResult, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it cannot transfer an asset before the lock slot', async (t) => {
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+  const slot = BigInt(await umi.rpc.getSlot()) + 10_000n;
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'TimeLock',
+        data: { expiry: lockExpiry('Slot', [slot]) },
+      }),
+    ],
+  });
+
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('it can transfer and burn an asset once the lock has expired', async (t) => {
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'TimeLock',
+        data: { expiry: lockExpiry('UnixTimestamp', [now() - 3600n]) },
+      }),
+    ],
+  });
+
+  await transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: newOwner.publicKey,
+  });
+
+  await burnV1(umi, {
+    asset: asset.publicKey,
+    authority: newOwner,
+  }).sendAndConfirm(umi);
+
+  await assertBurned(t, umi, asset.publicKey);
+});
+
+test('it cannot add a time lock after creation', async (t) => {
+  const umi = await createUmi();
+
+  const asset = await createAsset(umi, {});
+
+  const result = addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'TimeLock',
+      data: { expiry: lockExpiry('UnixTimestamp', [now() + 3600n]) },
+    }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('it can only remove a time lock once it has expired', async (t) => {
+  const umi = await createUmi();
+
+  const locked = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'TimeLock',
+        data: { expiry: lockExpiry('UnixTimestamp', [now() + 3600n]) },
+      }),
+    ],
+  });
+
+  const result = removePluginV1(umi, {
+    asset: locked.publicKey,
+    pluginType: PluginType.TimeLock,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  const expired = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'TimeLock',
+        data: { expiry: lockExpiry('UnixTimestamp', [now() - 3600n]) },
+      }),
+    ],
+  });
+
+  await removePluginV1(umi, {
+    asset: expired.publicKey,
+    pluginType: PluginType.TimeLock,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: expired.publicKey,
+    owner: umi.identity.publicKey,
+    timeLock: undefined,
+  });
+});
+
+test('it cannot update the expiry of a time lock', async (t) => {
+  const umi = await createUmi();
+  const expiry = now() - 3600n;
+
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'TimeLock',
+        data: { expiry: lockExpiry('UnixTimestamp', [expiry]) },
+      }),
+    ],
+  });
+
+  const result = updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'TimeLock',
+      data: { expiry: lockExpiry('UnixTimestamp', [now() + 3600n]) },
+    }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    timeLock: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      expiry: { __kind: 'UnixTimestamp', fields: [expiry] },
+    },
+  });
+});
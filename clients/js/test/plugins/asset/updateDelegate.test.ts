@@ -8,6 +8,7 @@ import {
   updateV1,
   addressPluginAuthority,
   revokePluginAuthorityV1,
+  removePluginV1,
   PluginType,
 } from '../../../src';
 import {
@@ -42,53 +43,115 @@ test('it can create an asset with updateDelegate', async (t) => {
   });
 });
 
-test('it cannot create an asset with updateDelegate with additional delegates', async (t) => {
+test('it can create an asset with updateDelegate with additional delegates', async (t) => {
   const umi = await createUmi();
+  const additionalDelegate = generateSigner(umi);
 
-  const result = createAsset(umi, {
+  const asset = await createAsset(umi, {
     plugins: [
       pluginAuthorityPair({
         type: 'UpdateDelegate',
-        data: { additionalDelegates: [generateSigner(umi).publicKey] },
+        data: { additionalDelegates: [additionalDelegate.publicKey] },
       }),
     ],
   });
 
-  await t.throwsAsync(result, { name: 'NotAvailable' });
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+    updateDelegate: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      additionalDelegates: [additionalDelegate.publicKey],
+    },
+  });
 });
 
-test('it cannot add updateDelegate to asset with additional delegates', async (t) => {
+test('it can add updateDelegate to asset with additional delegates', async (t) => {
   const umi = await createUmi();
   const asset = await createAsset(umi);
+  const additionalDelegate = generateSigner(umi);
 
-  const result = addPluginV1(umi, {
+  await addPluginV1(umi, {
     asset: asset.publicKey,
     plugin: createPlugin({
       type: 'UpdateDelegate',
-      data: { additionalDelegates: [generateSigner(umi).publicKey] },
+      data: { additionalDelegates: [additionalDelegate.publicKey] },
     }),
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'NotAvailable' });
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+    updateDelegate: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      additionalDelegates: [additionalDelegate.publicKey],
+    },
+  });
+});
+
+test('it can update updateDelegate on asset with additional delegates', async (t) => {
+  const umi = await createUmi();
+  const asset = await createAsset(umi);
+  const additionalDelegate = generateSigner(umi);
+
+  await addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UpdateDelegate',
+    }),
+  }).sendAndConfirm(umi);
+
+  await updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UpdateDelegate',
+      data: { additionalDelegates: [additionalDelegate.publicKey] },
+    }),
+  }).sendAndConfirm(umi);
 
   await assertAsset(t, umi, {
     ...DEFAULT_ASSET,
     asset: asset.publicKey,
     owner: umi.identity.publicKey,
     updateAuthority: { type: 'Address', address: umi.identity.publicKey },
-    updateDelegate: undefined,
+    updateDelegate: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      additionalDelegates: [additionalDelegate.publicKey],
+    },
   });
 });
 
-test('it cannot update updateDelegate on asset with additional delegates', async (t) => {
+test('it updateDelegate authority can manage additional delegates', async (t) => {
   const umi = await createUmi();
   const asset = await createAsset(umi);
+  const updateDelegate = generateSigner(umi);
+  const additionalDelegate = generateSigner(umi);
 
   await addPluginV1(umi, {
     asset: asset.publicKey,
     plugin: createPlugin({
       type: 'UpdateDelegate',
     }),
+    initAuthority: addressPluginAuthority(updateDelegate.publicKey),
+  }).sendAndConfirm(umi);
+
+  await updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UpdateDelegate',
+      data: { additionalDelegates: [additionalDelegate.publicKey] },
+    }),
+    authority: updateDelegate,
   }).sendAndConfirm(umi);
 
   await assertAsset(t, umi, {
@@ -97,33 +160,138 @@ test('it cannot update updateDelegate on asset with additional delegates', async
     owner: umi.identity.publicKey,
     updateAuthority: { type: 'Address', address: umi.identity.publicKey },
     updateDelegate: {
+      authority: {
+        type: 'Address',
+        address: updateDelegate.publicKey,
+      },
+      additionalDelegates: [additionalDelegate.publicKey],
+    },
+  });
+});
+
+test('it additional delegate can update an asset', async (t) => {
+  const umi = await createUmi();
+  const additionalDelegate = generateSigner(umi);
+  const asset = await createAsset(umi, {
+    name: 'short',
+    uri: 'https://short.com',
+    plugins: [
+      pluginAuthorityPair({
+        type: 'UpdateDelegate',
+        data: { additionalDelegates: [additionalDelegate.publicKey] },
+      }),
+    ],
+  });
+
+  await updateV1(umi, {
+    asset: asset.publicKey,
+    newName: 'Test Bread 2',
+    newUri: 'https://example.com/bread2',
+    authority: additionalDelegate,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+    name: 'Test Bread 2',
+    uri: 'https://example.com/bread2',
+  });
+});
+
+test('it additional delegate can add, update and remove plugins', async (t) => {
+  const umi = await createUmi();
+  const additionalDelegate = generateSigner(umi);
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'UpdateDelegate',
+        data: { additionalDelegates: [additionalDelegate.publicKey] },
+      }),
+    ],
+  });
+
+  await addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'Attributes',
+      data: { attributeList: [{ key: 'level', value: '1' }] },
+    }),
+    authority: additionalDelegate,
+  }).sendAndConfirm(umi);
+
+  await updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'Attributes',
+      data: { attributeList: [{ key: 'level', value: '2' }] },
+    }),
+    authority: additionalDelegate,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    attributes: {
       authority: {
         type: 'UpdateAuthority',
       },
-      additionalDelegates: [],
+      attributeList: [{ key: 'level', value: '2' }],
     },
   });
 
+  await removePluginV1(umi, {
+    asset: asset.publicKey,
+    pluginType: PluginType.Attributes,
+    authority: additionalDelegate,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    attributes: undefined,
+  });
+});
+
+test('it additional delegate cannot update the additional delegates', async (t) => {
+  const umi = await createUmi();
+  const additionalDelegate = generateSigner(umi);
+  const asset = await createAsset(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'UpdateDelegate',
+        data: { additionalDelegates: [additionalDelegate.publicKey] },
+      }),
+    ],
+  });
+
   const result = updatePluginV1(umi, {
     asset: asset.publicKey,
     plugin: createPlugin({
       type: 'UpdateDelegate',
-      data: { additionalDelegates: [generateSigner(umi).publicKey] },
+      data: {
+        additionalDelegates: [
+          additionalDelegate.publicKey,
+          generateSigner(umi).publicKey,
+        ],
+      },
     }),
+    authority: additionalDelegate,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'NotAvailable' });
+  await t.throwsAsync(result, { name: 'NoApprovals' });
 
   await assertAsset(t, umi, {
     ...DEFAULT_ASSET,
     asset: asset.publicKey,
     owner: umi.identity.publicKey,
-    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
     updateDelegate: {
       authority: {
         type: 'UpdateAuthority',
       },
-      additionalDelegates: [],
+      additionalDelegates: [additionalDelegate.publicKey],
     },
   });
 });
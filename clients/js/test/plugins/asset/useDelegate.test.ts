@@ -0,0 +1,177 @@
+import test from 'ava';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  PluginType,
+  addPluginV1,
+  burnV1,
+  createPlugin,
+  removePluginV1,
+  transferV1,
+  updatePluginV1,
+} from '../../../src';
+import {
+  DEFAULT_ASSET,
+  assertAsset,
+  createAsset,
+  createUmi,
+} from '../../_setupRaw';
+
+const now = () => BigInt(Math.floor(Date.now() / 1000));
+
+test('it can rent out an asset with a use delegate', async (t) => {
+  const umi = await createUmi();
+  const user = generateSigner(umi);
+  const expiry = now() + 3600n;
+
+  const asset = await createAsset(umi, {});
+
+  await addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UseDelegate',
+      data: { user: user.publicKey, expiry },
+    }),
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+    useDelegate: {
+      authority: {
+        type: 'Owner',
+      },
+      user: user.publicKey,
+      expiry,
+    },
+  });
+});
+
+test('it cannot transfer or burn an asset during an active rental', async (t) => {
+  const umi = await createUmi();
+  const user = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {});
+
+  await addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UseDelegate',
+      data: { user: user.publicKey, expiry: now() + 3600n },
+    }),
+  }).sendAndConfirm(umi);
+
+  const transferResult = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(transferResult, { name: 'InvalidAuthority' });
+
+  const burnResult = burnV1(umi, {
+    asset: asset.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(burnResult, { name: 'InvalidAuthority' });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+  });
+});
+
+test('it cannot remove or update an active rental as the owner', async (t) => {
+  const umi = await createUmi();
+  const user = generateSigner(umi);
+
+  const asset = await createAsset(umi, {});
+
+  await addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UseDelegate',
+      data: { user: user.publicKey, expiry: now() + 3600n },
+    }),
+  }).sendAndConfirm(umi);
+
+  const removeResult = removePluginV1(umi, {
+    asset: asset.publicKey,
+    pluginType: PluginType.UseDelegate,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(removeResult, { name: 'InvalidAuthority' });
+
+  const updateResult = updatePluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UseDelegate',
+      data: { user: user.publicKey, expiry: now() },
+    }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(updateResult, { name: 'InvalidAuthority' });
+});
+
+test('it can end a rental early as the user', async (t) => {
+  const umi = await createUmi();
+  const user = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {});
+
+  await addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UseDelegate',
+      data: { user: user.publicKey, expiry: now() + 3600n },
+    }),
+  }).sendAndConfirm(umi);
+
+  await removePluginV1(umi, {
+    asset: asset.publicKey,
+    pluginType: PluginType.UseDelegate,
+    authority: user,
+  }).sendAndConfirm(umi);
+
+  await transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: newOwner.publicKey,
+    useDelegate: undefined,
+  });
+});
+
+test('it can reclaim and transfer an asset after the rental expires', async (t) => {
+  const umi = await createUmi();
+  const user = generateSigner(umi);
+  const newOwner = generateSigner(umi);
+
+  const asset = await createAsset(umi, {});
+
+  await addPluginV1(umi, {
+    asset: asset.publicKey,
+    plugin: createPlugin({
+      type: 'UseDelegate',
+      data: { user: user.publicKey, expiry: now() - 3600n },
+    }),
+  }).sendAndConfirm(umi);
+
+  await transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: newOwner.publicKey,
+  });
+});
@@ -0,0 +1,60 @@
+import test from 'ava';
+
+import {
+  DEFAULT_COLLECTION,
+  assertCollection,
+  createUmi,
+} from '../../_setupRaw';
+import { createCollection } from '../../_setupSdk';
+import {
+  removeCollectionAttributeV1,
+  upsertCollectionAttributeV1,
+} from '../../../src';
+
+test('it can upsert and remove a single collection attribute in place', async (t) => {
+  const umi = await createUmi();
+  const collection = await createCollection(umi, {
+    plugins: [
+      {
+        type: 'Attributes',
+        attributeList: [
+          { key: 'season', value: '1' },
+          { key: 'theme', value: 'space' },
+        ],
+      },
+      {
+        type: 'PermanentFreezeDelegate',
+        frozen: false,
+      },
+    ],
+  });
+
+  await upsertCollectionAttributeV1(umi, {
+    collection: collection.publicKey,
+    key: 'season',
+    value: '2',
+  }).sendAndConfirm(umi);
+
+  await removeCollectionAttributeV1(umi, {
+    collection: collection.publicKey,
+    key: 'theme',
+  }).sendAndConfirm(umi);
+
+  await assertCollection(t, umi, {
+    ...DEFAULT_COLLECTION,
+    collection: collection.publicKey,
+    updateAuthority: umi.identity.publicKey,
+    attributes: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      attributeList: [{ key: 'season', value: '2' }],
+    },
+    permanentFreezeDelegate: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      frozen: false,
+    },
+  });
+});
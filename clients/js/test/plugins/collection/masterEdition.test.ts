@@ -1,6 +1,8 @@
 import test from 'ava';
+import { generateSigner } from '@metaplex-foundation/umi';
 
 import {
+  createV2,
   pluginAuthorityPair,
   updatePluginAuthority,
   createPlugin,
@@ -8,7 +10,9 @@ import {
   addCollectionPluginV1,
 } from '../../../src';
 import {
+  DEFAULT_ASSET,
   DEFAULT_COLLECTION,
+  assertAsset,
   assertCollection,
   createAsset,
   createCollection,
@@ -153,3 +157,283 @@ test('it cannot create asset with masterEdition', async (t) => {
     name: 'InvalidPlugin',
   });
 });
+
+test('it cannot create asset in collection past masterEdition max supply', async (t) => {
+  const umi = await createUmi();
+
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'MasterEdition',
+        data: {
+          maxSupply: 1,
+          name: null,
+          uri: null,
+        },
+      }),
+    ],
+  });
+
+  await createAsset(umi, {
+    collection: collection.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Edition',
+        data: { number: 1 },
+      }),
+    ],
+  });
+
+  const result = createAsset(umi, {
+    collection: collection.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Edition',
+        data: { number: 2 },
+      }),
+    ],
+  });
+
+  await t.throwsAsync(result, {
+    name: 'MaxSupplyReached',
+  });
+
+  await assertCollection(t, umi, {
+    ...DEFAULT_COLLECTION,
+    collection: collection.publicKey,
+    updateAuthority: umi.identity.publicKey,
+    numMinted: 1,
+    currentSize: 1,
+  });
+});
+
+test('it can create assets in collection with masterEdition without max supply', async (t) => {
+  const umi = await createUmi();
+
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'MasterEdition',
+        data: {
+          maxSupply: null,
+          name: null,
+          uri: null,
+        },
+      }),
+    ],
+  });
+
+  await createAsset(umi, { collection: collection.publicKey });
+  await createAsset(umi, { collection: collection.publicKey });
+
+  await assertCollection(t, umi, {
+    ...DEFAULT_COLLECTION,
+    collection: collection.publicKey,
+    updateAuthority: umi.identity.publicKey,
+    numMinted: 2,
+    currentSize: 2,
+  });
+});
+
+test('it assigns edition number from the collection counter', async (t) => {
+  const umi = await createUmi();
+
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'MasterEdition',
+        data: {
+          maxSupply: 10,
+          name: null,
+          uri: null,
+        },
+      }),
+    ],
+  });
+
+  const first = generateSigner(umi);
+  await createV2(umi, {
+    ...DEFAULT_ASSET,
+    asset: first,
+    collection: collection.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Edition',
+        data: { number: 0 },
+      }),
+    ],
+    autoNumberEdition: true,
+  }).sendAndConfirm(umi);
+
+  // The number passed in is ignored when auto numbering is requested.
+  const second = generateSigner(umi);
+  await createV2(umi, {
+    ...DEFAULT_ASSET,
+    asset: second,
+    collection: collection.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Edition',
+        data: { number: 7 },
+      }),
+    ],
+    autoNumberEdition: true,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: first.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: collection.publicKey },
+    edition: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      number: 1,
+    },
+  });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: second.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: collection.publicKey },
+    edition: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      number: 2,
+    },
+  });
+});
+
+test('it keeps edition number zero without auto numbering', async (t) => {
+  const umi = await createUmi();
+
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'MasterEdition',
+        data: {
+          maxSupply: 10,
+          name: null,
+          uri: null,
+        },
+      }),
+    ],
+  });
+
+  const asset = await createAsset(umi, {
+    collection: collection.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Edition',
+        data: { number: 0 },
+      }),
+    ],
+  });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: collection.publicKey },
+    edition: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      number: 0,
+    },
+  });
+});
+
+test('it cannot auto number an edition without a masterEdition', async (t) => {
+  const umi = await createUmi();
+
+  const collection = await createCollection(umi);
+
+  const result = createV2(umi, {
+    ...DEFAULT_ASSET,
+    asset: generateSigner(umi),
+    collection: collection.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Edition',
+        data: { number: 0 },
+      }),
+    ],
+    autoNumberEdition: true,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, {
+    name: 'PluginNotFound',
+  });
+});
+
+test('it cannot auto number an asset without an edition plugin', async (t) => {
+  const umi = await createUmi();
+
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'MasterEdition',
+        data: {
+          maxSupply: 10,
+          name: null,
+          uri: null,
+        },
+      }),
+    ],
+  });
+
+  const result = createV2(umi, {
+    ...DEFAULT_ASSET,
+    asset: generateSigner(umi),
+    collection: collection.publicKey,
+    autoNumberEdition: true,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, {
+    name: 'PluginNotFound',
+  });
+});
+
+test('it keeps an explicit edition number in a masterEdition collection', async (t) => {
+  const umi = await createUmi();
+
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'MasterEdition',
+        data: {
+          maxSupply: 10,
+          name: null,
+          uri: null,
+        },
+      }),
+    ],
+  });
+
+  const asset = await createAsset(umi, {
+    collection: collection.publicKey,
+    plugins: [
+      pluginAuthorityPair({
+        type: 'Edition',
+        data: { number: 7 },
+      }),
+    ],
+  });
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: collection.publicKey },
+    edition: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      number: 7,
+    },
+  });
+});
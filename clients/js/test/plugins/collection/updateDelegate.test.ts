@@ -106,51 +106,42 @@ test('it can add updateDelegate to collection and then approve', async (t) => {
   });
 });
 
-test('it cannot create a collection with updateDelegate with additional delegates', async (t) => {
+test('it can create a collection with updateDelegate with additional delegates', async (t) => {
   const umi = await createUmi();
+  const additionalDelegate = generateSigner(umi);
 
-  const result = createCollection(umi, {
+  const collection = await createCollection(umi, {
     plugins: [
       pluginAuthorityPair({
         type: 'UpdateDelegate',
-        data: { additionalDelegates: [generateSigner(umi).publicKey] },
+        data: { additionalDelegates: [additionalDelegate.publicKey] },
       }),
     ],
   });
 
-  await t.throwsAsync(result, { name: 'NotAvailable' });
-});
-
-test('it cannot add updateDelegate to collection with additional delegates', async (t) => {
-  const umi = await createUmi();
-  const collection = await createCollection(umi);
-
-  const result = addCollectionPluginV1(umi, {
-    collection: collection.publicKey,
-    plugin: createPlugin({
-      type: 'UpdateDelegate',
-      data: { additionalDelegates: [generateSigner(umi).publicKey] },
-    }),
-  }).sendAndConfirm(umi);
-
-  await t.throwsAsync(result, { name: 'NotAvailable' });
-
   await assertCollection(t, umi, {
     ...DEFAULT_COLLECTION,
     collection: collection.publicKey,
     updateAuthority: umi.identity.publicKey,
-    updateDelegate: undefined,
+    updateDelegate: {
+      authority: {
+        type: 'UpdateAuthority',
+      },
+      additionalDelegates: [additionalDelegate.publicKey],
+    },
   });
 });
 
-test('it cannot update updateDelegate on collection with additional delegates', async (t) => {
+test('it can add updateDelegate to collection with additional delegates', async (t) => {
   const umi = await createUmi();
   const collection = await createCollection(umi);
+  const additionalDelegate = generateSigner(umi);
 
   await addCollectionPluginV1(umi, {
     collection: collection.publicKey,
     plugin: createPlugin({
       type: 'UpdateDelegate',
+      data: { additionalDelegates: [additionalDelegate.publicKey] },
     }),
   }).sendAndConfirm(umi);
 
@@ -162,19 +153,30 @@ test('it cannot update updateDelegate on collection with additional delegates',
       authority: {
         type: 'UpdateAuthority',
       },
-      additionalDelegates: [],
+      additionalDelegates: [additionalDelegate.publicKey],
     },
   });
+});
+
+test('it can update updateDelegate on collection with additional delegates', async (t) => {
+  const umi = await createUmi();
+  const collection = await createCollection(umi);
+  const additionalDelegate = generateSigner(umi);
 
-  const result = updateCollectionPluginV1(umi, {
+  await addCollectionPluginV1(umi, {
     collection: collection.publicKey,
     plugin: createPlugin({
       type: 'UpdateDelegate',
-      data: { additionalDelegates: [generateSigner(umi).publicKey] },
     }),
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'NotAvailable' });
+  await updateCollectionPluginV1(umi, {
+    collection: collection.publicKey,
+    plugin: createPlugin({
+      type: 'UpdateDelegate',
+      data: { additionalDelegates: [additionalDelegate.publicKey] },
+    }),
+  }).sendAndConfirm(umi);
 
   await assertCollection(t, umi, {
     ...DEFAULT_COLLECTION,
@@ -184,11 +186,46 @@ test('it cannot update updateDelegate on collection with additional delegates',
       authority: {
         type: 'UpdateAuthority',
       },
-      additionalDelegates: [],
+      additionalDelegates: [additionalDelegate.publicKey],
     },
   });
 });
 
+test('it additional delegate on collection can update an asset', async (t) => {
+  const umi = await createUmi();
+  const additionalDelegate = generateSigner(umi);
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'UpdateDelegate',
+        data: { additionalDelegates: [additionalDelegate.publicKey] },
+      }),
+    ],
+  });
+  const asset = await createAsset(umi, {
+    collection: collection.publicKey,
+    name: 'short',
+    uri: 'https://short.com',
+  });
+
+  await updateV1(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    newName: 'Test Bread 2',
+    newUri: 'https://example.com/bread2',
+    authority: additionalDelegate,
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: collection.publicKey },
+    name: 'Test Bread 2',
+    uri: 'https://example.com/bread2',
+  });
+});
+
 test('it updateDelegate on collection can update an asset', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
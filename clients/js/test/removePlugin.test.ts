@@ -110,6 +110,7 @@ test('it can remove authority managed plugin from asset in collection using upda
             creators: [{ address: umi.identity.publicKey, percentage: 100 }],
             basisPoints: 5,
             ruleSet: ruleSet('None'),
+            royaltyPayments: false,
           },
         }),
       ],
@@ -195,6 +196,7 @@ test('it can remove authority managed plugin from collection using delegate auth
             creators: [{ address: umi.identity.publicKey, percentage: 100 }],
             basisPoints: 5,
             ruleSet: ruleSet('None'),
+            royaltyPayments: false,
           },
         }),
       ],
@@ -276,6 +278,7 @@ test('it cannot remove authority managed plugin if the delegate authority is not
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           basisPoints: 5,
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -300,6 +303,7 @@ test('it cannot remove authority managed plugin if the delegate authority is not
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       basisPoints: 5,
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 });
@@ -316,6 +320,7 @@ test('it cannot remove authority managed collection plugin if the delegate autho
           creators: [{ address: umi.identity.publicKey, percentage: 100 }],
           basisPoints: 5,
           ruleSet: ruleSet('None'),
+          royaltyPayments: false,
         },
       }),
     ],
@@ -339,6 +344,7 @@ test('it cannot remove authority managed collection plugin if the delegate autho
       creators: [{ address: umi.identity.publicKey, percentage: 100 }],
       basisPoints: 5,
       ruleSet: ruleSet('None'),
+      royaltyPayments: false,
     },
   });
 });
@@ -49,6 +49,7 @@ test('it can create asset and collection with all update auth managed party plug
           type: 'ProgramDenyList',
           addresses: [owner.publicKey],
         },
+        royaltyPayments: false,
         authority: {
           type: 'Address',
           address: owner.publicKey,
@@ -103,6 +104,7 @@ test('it can create asset and collection with all update auth managed party plug
         type: 'ProgramDenyList',
         addresses: [owner.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'Address',
         address: owner.publicKey,
@@ -169,6 +171,7 @@ test('it can create asset and collection with all update auth managed party plug
           type: 'ProgramDenyList',
           addresses: [owner.publicKey],
         },
+        royaltyPayments: false,
         authority: {
           type: 'Address',
           address: owner.publicKey,
@@ -227,6 +230,7 @@ test('it can create asset and collection with all update auth managed party plug
         type: 'ProgramDenyList',
         addresses: [owner.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'Address',
         address: owner.publicKey,
@@ -280,6 +284,7 @@ test('it can create all owner and update auth managed party plugins to asset', a
           type: 'ProgramDenyList',
           addresses: [umi.identity.publicKey],
         },
+        royaltyPayments: false,
         authority: {
           type: 'Address',
           address: umi.identity.publicKey,
@@ -351,6 +356,7 @@ test('it can create all owner and update auth managed party plugins to asset', a
         type: 'ProgramDenyList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'Address',
         address: umi.identity.publicKey,
@@ -426,6 +432,7 @@ test('it can add and remove all owner and update auth managed party plugins to a
         type: 'ProgramDenyList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'Address',
         address: umi.identity.publicKey,
@@ -479,6 +486,7 @@ test('it can add and remove all owner and update auth managed party plugins to a
         type: 'ProgramDenyList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'Address',
         address: umi.identity.publicKey,
@@ -551,6 +559,7 @@ test('it can update all updatable plugins on asset', async (t) => {
           type: 'ProgramDenyList',
           addresses: [umi.identity.publicKey],
         },
+        royaltyPayments: false,
       },
       {
         type: 'Attributes',
@@ -590,6 +599,7 @@ test('it can update all updatable plugins on asset', async (t) => {
         type: 'ProgramAllowList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
     },
     {
       type: 'Attributes',
@@ -638,6 +648,7 @@ test('it can update all updatable plugins on asset', async (t) => {
         type: 'ProgramAllowList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'UpdateAuthority',
       },
@@ -691,6 +702,7 @@ test('it can update all updatable plugins on collection', async (t) => {
           type: 'ProgramDenyList',
           addresses: [umi.identity.publicKey],
         },
+        royaltyPayments: false,
       },
       {
         type: 'Attributes',
@@ -728,6 +740,7 @@ test('it can update all updatable plugins on collection', async (t) => {
         type: 'ProgramAllowList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
     },
     {
       type: 'Attributes',
@@ -773,6 +786,7 @@ test('it can update all updatable plugins on collection', async (t) => {
         type: 'ProgramAllowList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'UpdateAuthority',
       },
@@ -823,6 +837,7 @@ test('it can add and remove all update auth managed party plugins to collection'
         type: 'ProgramDenyList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'Address',
         address: umi.identity.publicKey,
@@ -863,6 +878,7 @@ test('it can add and remove all update auth managed party plugins to collection'
         type: 'ProgramDenyList',
         addresses: [umi.identity.publicKey],
       },
+      royaltyPayments: false,
       authority: {
         type: 'Address',
         address: umi.identity.publicKey,
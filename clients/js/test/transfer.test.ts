@@ -1,7 +1,17 @@
-import { generateSigner } from '@metaplex-foundation/umi';
+import { generateSigner, publicKey, some } from '@metaplex-foundation/umi';
 import test from 'ava';
 
-import { pluginAuthority, pluginAuthorityPair, transferV1 } from '../src';
+import { getAssetV1AccountDataSerializer } from '../src/hooked';
+import {
+  compressV1,
+  fetchHashedAssetV1,
+  getHashedAssetSchemaSerializer,
+  hash,
+  pluginAuthority,
+  pluginAuthorityPair,
+  transferV1,
+  updateAuthority,
+} from '../src';
 import {
   assertAsset,
   createAsset,
@@ -338,3 +348,122 @@ test('it cannot use an invalid noop program', async (t) => {
 
   await t.throwsAsync(result, { name: 'InvalidLogWrapperProgram' });
 });
+
+test('it can transfer a compressed asset as the owner', async (t) => {
+  // Given a Umi instance and a compressed asset.
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+  const asset = await createAsset(umi);
+
+  await compressV1(umi, {
+    asset: asset.publicKey,
+    authority: umi.identity,
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+  }).sendAndConfirm(umi);
+
+  // When we transfer the asset using its compression proof.
+  await transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    systemProgram: publicKey('11111111111111111111111111111111'),
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+    compressionProof: {
+      owner: umi.identity.publicKey,
+      updateAuthority: updateAuthority('Address', [umi.identity.publicKey]),
+      name: asset.name,
+      uri: asset.uri,
+      seq: 1,
+      plugins: [],
+    },
+  }).sendAndConfirm(umi);
+
+  // Then the asset is rehashed with the new owner and an incremented seq.
+  const afterAsset = await fetchHashedAssetV1(umi, asset.publicKey);
+  const expectedHash = hash(
+    getHashedAssetSchemaSerializer().serialize({
+      assetHash: hash(
+        getAssetV1AccountDataSerializer().serialize({
+          ...asset,
+          owner: newOwner.publicKey,
+          seq: some(2),
+        })
+      ),
+      pluginHashes: [],
+    })
+  );
+  t.deepEqual(afterAsset.hash, expectedHash);
+});
+
+test('it cannot transfer a compressed asset if not the owner', async (t) => {
+  // Given a Umi instance and a compressed asset.
+  const umi = await createUmi();
+  const attacker = generateSigner(umi);
+  const asset = await createAsset(umi);
+
+  await compressV1(umi, {
+    asset: asset.publicKey,
+    authority: umi.identity,
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+  }).sendAndConfirm(umi);
+  const beforeAsset = await fetchHashedAssetV1(umi, asset.publicKey);
+
+  // When the attacker tries to transfer the asset to themselves.
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: attacker.publicKey,
+    authority: attacker,
+    systemProgram: publicKey('11111111111111111111111111111111'),
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+    compressionProof: {
+      owner: umi.identity.publicKey,
+      updateAuthority: updateAuthority('Address', [umi.identity.publicKey]),
+      name: asset.name,
+      uri: asset.uri,
+      seq: 1,
+      plugins: [],
+    },
+  }).sendAndConfirm(umi);
+
+  // Then it fails and the hash is unchanged.
+  await t.throwsAsync(result, { name: 'NoApprovals' });
+
+  const afterAsset = await fetchHashedAssetV1(umi, asset.publicKey);
+  t.deepEqual(afterAsset.hash, beforeAsset.hash);
+});
+
+test('it cannot make a priced transfer of a compressed asset', async (t) => {
+  // Given a Umi instance and a compressed asset.
+  const umi = await createUmi();
+  const newOwner = generateSigner(umi);
+  const asset = await createAsset(umi);
+
+  await compressV1(umi, {
+    asset: asset.publicKey,
+    authority: umi.identity,
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+  }).sendAndConfirm(umi);
+  const beforeAsset = await fetchHashedAssetV1(umi, asset.publicKey);
+
+  // When we try to sell the asset.
+  const result = transferV1(umi, {
+    asset: asset.publicKey,
+    newOwner: newOwner.publicKey,
+    systemProgram: publicKey('11111111111111111111111111111111'),
+    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
+    compressionProof: {
+      owner: umi.identity.publicKey,
+      updateAuthority: updateAuthority('Address', [umi.identity.publicKey]),
+      name: asset.name,
+      uri: asset.uri,
+      seq: 1,
+      plugins: [],
+    },
+    salePrice: 1_000_000_000n,
+  }).sendAndConfirm(umi);
+
+  // Then it fails and the hash is unchanged.
+  await t.throwsAsync(result, { name: 'NotAvailable' });
+
+  const afterAsset = await fetchHashedAssetV1(umi, asset.publicKey);
+  t.deepEqual(afterAsset.hash, beforeAsset.hash);
+});
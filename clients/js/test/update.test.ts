@@ -3,12 +3,14 @@ import test from 'ava';
 import { generateSigner } from '@metaplex-foundation/umi';
 import {
   updateV1,
+  updateV2,
   pluginAuthorityPair,
   updateAuthority,
   updateCollectionV1,
 } from '../src';
 import {
   assertAsset,
+  assertCollection,
   createAsset,
   createAssetWithCollection,
   createCollection,
@@ -181,7 +183,7 @@ test('it can update an asset update authority', async (t) => {
   });
 });
 
-test('it cannot update an asset update authority to be part of a collection (right now)', async (t) => {
+test('it cannot add an asset to a collection using UpdateV1', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
   const asset = await createAsset(umi);
@@ -199,7 +201,7 @@ test('it cannot update an asset update authority to be part of a collection (rig
   await t.throwsAsync(result, { name: 'NotAvailable' });
 });
 
-test('it cannot remove an asset from a collection (right now)', async (t) => {
+test('it cannot remove an asset from a collection using UpdateV1', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
   const { asset, collection } = await createAssetWithCollection(umi, {}, {});
@@ -215,6 +217,236 @@ test('it cannot remove an asset from a collection (right now)', async (t) => {
   await t.throwsAsync(result, { name: 'NotAvailable' });
 });
 
+test('it can add an asset to a collection', async (t) => {
+  // Given a standalone asset and a collection with the same update authority.
+  const umi = await createUmi();
+  const asset = await createAsset(umi);
+  const collection = await createCollection(umi);
+
+  // When we move the asset into the collection.
+  await updateV2(umi, {
+    asset: asset.publicKey,
+    newCollection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Collection', [collection.publicKey]),
+  }).sendAndConfirm(umi);
+
+  // Then the asset belongs to the collection and its size is updated, but it
+  // does not count as minted.
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: collection.publicKey },
+  });
+  await assertCollection(t, umi, {
+    collection: collection.publicKey,
+    numMinted: 0,
+    currentSize: 1,
+  });
+});
+
+test('it can remove an asset from a collection', async (t) => {
+  // Given an asset in a collection.
+  const umi = await createUmi();
+  const { asset, collection } = await createAssetWithCollection(umi, {}, {});
+
+  // When we remove the asset from the collection.
+  await updateV2(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Address', [umi.identity.publicKey]),
+  }).sendAndConfirm(umi);
+
+  // Then the asset is standalone and the collection size is decremented.
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+  });
+  await assertCollection(t, umi, {
+    collection: collection.publicKey,
+    numMinted: 1,
+    currentSize: 0,
+  });
+});
+
+test('it can move an asset between collections', async (t) => {
+  // Given an asset in a collection and a second collection.
+  const umi = await createUmi();
+  const { asset, collection } = await createAssetWithCollection(umi, {}, {});
+  const newCollection = await createCollection(umi);
+
+  // When we move the asset to the second collection.
+  await updateV2(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    newCollection: newCollection.publicKey,
+    newUpdateAuthority: updateAuthority('Collection', [
+      newCollection.publicKey,
+    ]),
+  }).sendAndConfirm(umi);
+
+  // Then the asset belongs to the second collection and both sizes are updated.
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Collection', address: newCollection.publicKey },
+  });
+  await assertCollection(t, umi, {
+    collection: collection.publicKey,
+    numMinted: 1,
+    currentSize: 0,
+  });
+  await assertCollection(t, umi, {
+    collection: newCollection.publicKey,
+    numMinted: 0,
+    currentSize: 1,
+  });
+});
+
+test('it cannot add an asset to a collection without its update authority', async (t) => {
+  // Given a standalone asset and a collection with another update authority.
+  const umi = await createUmi();
+  const asset = await createAsset(umi);
+  const collectionAuthority = generateSigner(umi);
+  const collection = await createCollection(umi, {
+    updateAuthority: collectionAuthority.publicKey,
+  });
+
+  // When we try to move the asset into the collection.
+  const result = updateV2(umi, {
+    asset: asset.publicKey,
+    newCollection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Collection', [collection.publicKey]),
+  }).sendAndConfirm(umi);
+
+  // Then it fails and nothing changes.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await assertAsset(t, umi, {
+    ...DEFAULT_ASSET,
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+  });
+  await assertCollection(t, umi, {
+    collection: collection.publicKey,
+    numMinted: 0,
+    currentSize: 0,
+  });
+});
+
+test('it cannot add an asset to a collection with permanent delegates', async (t) => {
+  // Given a standalone asset and a collection with a permanent delegate.
+  const umi = await createUmi();
+  const asset = await createAsset(umi);
+  const collection = await createCollection(umi, {
+    plugins: [
+      pluginAuthorityPair({
+        type: 'PermanentFreezeDelegate',
+        data: { frozen: false },
+      }),
+    ],
+  });
+
+  // When we try to move the asset into the collection.
+  const result = updateV2(umi, {
+    asset: asset.publicKey,
+    newCollection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Collection', [collection.publicKey]),
+  }).sendAndConfirm(umi);
+
+  // Then it fails.
+  await t.throwsAsync(result, { name: 'PermanentDelegatesPreventMove' });
+});
+
+test('it cannot add an asset to a collection past its masterEdition max supply', async (t) => {
+  // Given a collection that has minted its max supply of editions.
+  const umi = await createUmi();
+  const { collection } = await createAssetWithCollection(
+    umi,
+    {},
+    {
+      plugins: [
+        pluginAuthorityPair({
+          type: 'MasterEdition',
+          data: { maxSupply: 1, name: null, uri: null },
+        }),
+      ],
+    }
+  );
+  const asset = await createAsset(umi);
+
+  // When we try to move a standalone asset into the collection.
+  const result = updateV2(umi, {
+    asset: asset.publicKey,
+    newCollection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Collection', [collection.publicKey]),
+  }).sendAndConfirm(umi);
+
+  // Then it fails and the collection size is unchanged.
+  await t.throwsAsync(result, { name: 'MaxSupplyReached' });
+  await assertCollection(t, umi, {
+    collection: collection.publicKey,
+    numMinted: 1,
+    currentSize: 1,
+  });
+});
+
+test('it does not use up masterEdition supply when moving an asset in and out', async (t) => {
+  // Given a collection that can mint one more edition and a standalone asset.
+  const umi = await createUmi();
+  const { collection } = await createAssetWithCollection(
+    umi,
+    {},
+    {
+      plugins: [
+        pluginAuthorityPair({
+          type: 'MasterEdition',
+          data: { maxSupply: 2, name: null, uri: null },
+        }),
+      ],
+    }
+  );
+  const asset = await createAsset(umi);
+
+  // When we move the asset into and out of the collection twice.
+  await updateV2(umi, {
+    asset: asset.publicKey,
+    newCollection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Collection', [collection.publicKey]),
+  }).sendAndConfirm(umi);
+  await updateV2(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Address', [umi.identity.publicKey]),
+  }).sendAndConfirm(umi);
+  await updateV2(umi, {
+    asset: asset.publicKey,
+    newCollection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Collection', [collection.publicKey]),
+  }).sendAndConfirm(umi);
+  await updateV2(umi, {
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    newUpdateAuthority: updateAuthority('Address', [umi.identity.publicKey]),
+  }).sendAndConfirm(umi);
+
+  // Then no supply was used and the last edition can still be minted.
+  await assertCollection(t, umi, {
+    collection: collection.publicKey,
+    numMinted: 1,
+    currentSize: 1,
+  });
+  await createAsset(umi, { collection: collection.publicKey });
+  await assertCollection(t, umi, {
+    collection: collection.publicKey,
+    numMinted: 2,
+    currentSize: 2,
+  });
+});
+
 test('it cannot update an asset using wrong authority', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
@@ -0,0 +1,376 @@
+use solana_program::pubkey::Pubkey;
+use std::collections::HashMap;
+
+use crate::{
+    types::{
+        ExternalPluginAdapter, ExternalPluginAdapterKey, Plugin, PluginAuthority, PluginType,
+        UpdateAuthority,
+    },
+    Asset, Collection, ExternalPluginAdaptersList, IndexableAsset, IndexableCheckResult,
+    LifecycleChecks, PluginsList,
+};
+
+/// The old and new value of a field that changed between two versions of an account.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValueChange<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: PartialEq> ValueChange<T> {
+    fn compare(old: T, new: T) -> Option<Self> {
+        (old != new).then_some(Self { old, new })
+    }
+}
+
+/// A change to a single plugin.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PluginChange {
+    Added {
+        authority: PluginAuthority,
+        plugin: Plugin,
+    },
+    Removed {
+        authority: PluginAuthority,
+        plugin: Plugin,
+    },
+    Updated {
+        authority: Option<ValueChange<PluginAuthority>>,
+        plugin: Option<ValueChange<Plugin>>,
+    },
+}
+
+/// A change to a single external plugin adapter.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExternalPluginAdapterChange {
+    Added { adapter: ExternalPluginAdapter },
+    Removed { adapter: ExternalPluginAdapter },
+    Updated(Box<ExternalPluginAdapterUpdate>),
+}
+
+/// The fields of an external plugin adapter that changed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExternalPluginAdapterUpdate {
+    pub authority: Option<ValueChange<Option<PluginAuthority>>>,
+    pub adapter: Option<ValueChange<ExternalPluginAdapter>>,
+    pub lifecycle_checks: Option<ValueChange<Option<LifecycleChecks>>>,
+    pub data: Option<ValueChange<Option<String>>>,
+}
+
+/// The changes between two versions of an asset or collection account.
+///
+/// Plugins are keyed by `PluginType` and external plugin adapters by `ExternalPluginAdapterKey`,
+/// listed in the order of the new version followed by the removed ones.  Plugins and
+/// adapters of types unknown to this client are not compared, and neither is the `seq`.
+///
+/// `Asset` and `Collection` do not carry the registry authority or the data of an external plugin
+/// adapter, so those changes are only reported when diffing `IndexableAsset`s.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccountDiff {
+    pub owner: Option<ValueChange<Option<Pubkey>>>,
+    pub update_authority: Option<ValueChange<UpdateAuthority>>,
+    pub name: Option<ValueChange<String>>,
+    pub uri: Option<ValueChange<String>>,
+    pub num_minted: Option<ValueChange<Option<u32>>>,
+    pub current_size: Option<ValueChange<Option<u32>>>,
+    pub plugins: HashMap<PluginType, PluginChange>,
+    pub external_plugin_adapters: Vec<(ExternalPluginAdapterKey, ExternalPluginAdapterChange)>,
+}
+
+impl AccountDiff {
+    /// Returns true if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn between(old: AccountSnapshot, new: AccountSnapshot) -> Self {
+        let mut plugins = HashMap::new();
+        let mut old_plugins = old.plugins;
+        for (plugin_type, (authority, plugin)) in new.plugins {
+            let change = match old_plugins.remove(&plugin_type) {
+                Some((old_authority, old_plugin)) => {
+                    let authority = ValueChange::compare(old_authority, authority);
+                    let plugin = ValueChange::compare(old_plugin, plugin);
+                    if authority.is_none() && plugin.is_none() {
+                        continue;
+                    }
+                    PluginChange::Updated { authority, plugin }
+                }
+                None => PluginChange::Added { authority, plugin },
+            };
+            plugins.insert(plugin_type, change);
+        }
+        for (plugin_type, (authority, plugin)) in old_plugins {
+            plugins.insert(plugin_type, PluginChange::Removed { authority, plugin });
+        }
+
+        let mut external_plugin_adapters = vec![];
+        let mut old_adapters = old.external_plugin_adapters;
+        for (key, adapter) in new.external_plugin_adapters {
+            let change = match old_adapters.iter().position(|(old_key, _)| *old_key == key) {
+                Some(index) => {
+                    let (_, old_adapter) = old_adapters.remove(index);
+                    let authority = ValueChange::compare(old_adapter.authority, adapter.authority);
+                    let lifecycle_checks = ValueChange::compare(
+                        old_adapter.lifecycle_checks,
+                        adapter.lifecycle_checks,
+                    );
+                    let data = ValueChange::compare(old_adapter.data, adapter.data);
+                    let adapter = ValueChange::compare(old_adapter.adapter, adapter.adapter);
+                    if authority.is_none()
+                        && adapter.is_none()
+                        && lifecycle_checks.is_none()
+                        && data.is_none()
+                    {
+                        continue;
+                    }
+                    ExternalPluginAdapterChange::Updated(Box::new(ExternalPluginAdapterUpdate {
+                        authority,
+                        adapter,
+                        lifecycle_checks,
+                        data,
+                    }))
+                }
+                None => ExternalPluginAdapterChange::Added {
+                    adapter: adapter.adapter,
+                },
+            };
+            external_plugin_adapters.push((key, change));
+        }
+        for (key, adapter) in old_adapters {
+            external_plugin_adapters.push((
+                key,
+                ExternalPluginAdapterChange::Removed {
+                    adapter: adapter.adapter,
+                },
+            ));
+        }
+
+        Self {
+            owner: ValueChange::compare(old.owner, new.owner),
+            update_authority: ValueChange::compare(old.update_authority, new.update_authority),
+            name: ValueChange::compare(old.name, new.name),
+            uri: ValueChange::compare(old.uri, new.uri),
+            num_minted: ValueChange::compare(old.num_minted, new.num_minted),
+            current_size: ValueChange::compare(old.current_size, new.current_size),
+            plugins,
+            external_plugin_adapters,
+        }
+    }
+}
+
+impl Asset {
+    /// Compute the changes from this version of the asset to a newer one.
+    pub fn diff(&self, new: &Asset) -> AccountDiff {
+        AccountDiff::between(AccountSnapshot::from(self), AccountSnapshot::from(new))
+    }
+}
+
+impl Collection {
+    /// Compute the changes from this version of the collection to a newer one.
+    pub fn diff(&self, new: &Collection) -> AccountDiff {
+        AccountDiff::between(AccountSnapshot::from(self), AccountSnapshot::from(new))
+    }
+}
+
+impl IndexableAsset {
+    /// Compute the changes from this version of the asset or collection to a newer one.
+    pub fn diff(&self, new: &IndexableAsset) -> AccountDiff {
+        AccountDiff::between(AccountSnapshot::from(self), AccountSnapshot::from(new))
+    }
+}
+
+// The comparable parts of an asset or collection, common to all the account representations.
+struct AccountSnapshot {
+    owner: Option<Pubkey>,
+    update_authority: UpdateAuthority,
+    name: String,
+    uri: String,
+    num_minted: Option<u32>,
+    current_size: Option<u32>,
+    plugins: HashMap<PluginType, (PluginAuthority, Plugin)>,
+    external_plugin_adapters: Vec<(ExternalPluginAdapterKey, ExternalPluginAdapterSnapshot)>,
+}
+
+struct ExternalPluginAdapterSnapshot {
+    authority: Option<PluginAuthority>,
+    adapter: ExternalPluginAdapter,
+    lifecycle_checks: Option<LifecycleChecks>,
+    data: Option<String>,
+}
+
+impl From<&Asset> for AccountSnapshot {
+    fn from(asset: &Asset) -> Self {
+        Self {
+            owner: Some(asset.base.owner),
+            update_authority: asset.base.update_authority.clone(),
+            name: asset.base.name.clone(),
+            uri: asset.base.uri.clone(),
+            num_minted: None,
+            current_size: None,
+            plugins: plugin_list_entries(&asset.plugin_list),
+            external_plugin_adapters: external_plugin_adapter_list_entries(
+                &asset.external_plugin_adapter_list,
+            ),
+        }
+    }
+}
+
+impl From<&Collection> for AccountSnapshot {
+    fn from(collection: &Collection) -> Self {
+        Self {
+            owner: None,
+            update_authority: UpdateAuthority::Address(collection.base.update_authority),
+            name: collection.base.name.clone(),
+            uri: collection.base.uri.clone(),
+            num_minted: Some(collection.base.num_minted),
+            current_size: Some(collection.base.current_size),
+            plugins: plugin_list_entries(&collection.plugin_list),
+            external_plugin_adapters: external_plugin_adapter_list_entries(
+                &collection.external_plugin_adapter_list,
+            ),
+        }
+    }
+}
+
+impl From<&IndexableAsset> for AccountSnapshot {
+    fn from(indexable_asset: &IndexableAsset) -> Self {
+        Self {
+            owner: indexable_asset.owner,
+            update_authority: indexable_asset.update_authority.clone(),
+            name: indexable_asset.name.clone(),
+            uri: indexable_asset.uri.clone(),
+            num_minted: indexable_asset.num_minted,
+            current_size: indexable_asset.current_size,
+            plugins: indexable_asset
+                .plugins
+                .iter()
+                .map(|(plugin_type, plugin)| {
+                    (
+                        plugin_type.clone(),
+                        (plugin.authority.clone(), plugin.data.clone()),
+                    )
+                })
+                .collect(),
+            external_plugin_adapters: indexable_asset
+                .external_plugins
+                .iter()
+                .map(|plugin| {
+                    (
+                        ExternalPluginAdapterKey::from(&plugin.adapter_config),
+                        ExternalPluginAdapterSnapshot {
+                            authority: Some(plugin.authority.clone()),
+                            adapter: plugin.adapter_config.clone(),
+                            lifecycle_checks: plugin.lifecycle_checks.clone(),
+                            data: plugin.data.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+// Collect the authority and data of every plugin in a plugin list.
+fn plugin_list_entries(
+    plugin_list: &PluginsList,
+) -> HashMap<PluginType, (PluginAuthority, Plugin)> {
+    macro_rules! entries {
+        ($($field:ident => $variant:ident),* $(,)?) => {
+            [$(plugin_list.$field.as_ref().map(|plugin| {
+                (
+                    PluginType::$variant,
+                    (
+                        PluginAuthority::from(&plugin.base.authority),
+                        Plugin::$variant(plugin.$field.clone()),
+                    ),
+                )
+            })),*]
+        };
+    }
+
+    entries!(
+        royalties => Royalties,
+        freeze_delegate => FreezeDelegate,
+        burn_delegate => BurnDelegate,
+        transfer_delegate => TransferDelegate,
+        update_delegate => UpdateDelegate,
+        permanent_freeze_delegate => PermanentFreezeDelegate,
+        attributes => Attributes,
+        permanent_transfer_delegate => PermanentTransferDelegate,
+        permanent_burn_delegate => PermanentBurnDelegate,
+        edition => Edition,
+        master_edition => MasterEdition,
+        add_blocker => AddBlocker,
+        immutable_metadata => ImmutableMetadata,
+        verified_creators => VerifiedCreators,
+        autograph => Autograph,
+        time_lock => TimeLock,
+        soulbound => Soulbound,
+        use_delegate => UseDelegate,
+        attributes_v2 => AttributesV2,
+    )
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+// Collect the configuration and lifecycle checks of every adapter in an external plugin adapter
+// list.  The list does not hold the registry authority or the data of an adapter.
+fn external_plugin_adapter_list_entries(
+    list: &ExternalPluginAdaptersList,
+) -> Vec<(ExternalPluginAdapterKey, ExternalPluginAdapterSnapshot)> {
+    let adapters = list
+        .lifecycle_hooks
+        .iter()
+        .cloned()
+        .map(ExternalPluginAdapter::LifecycleHook)
+        .chain(
+            list.oracles
+                .iter()
+                .cloned()
+                .map(ExternalPluginAdapter::Oracle),
+        )
+        .chain(
+            list.data_stores
+                .iter()
+                .cloned()
+                .map(ExternalPluginAdapter::DataStore),
+        );
+
+    adapters
+        .map(|adapter| {
+            let key = ExternalPluginAdapterKey::from(&adapter);
+            let lifecycle_checks = list
+                .lifecycle_checks
+                .iter()
+                .find(|checks| checks.key == key)
+                .map(|checks| {
+                    let mut lifecycle_checks = LifecycleChecks::default();
+                    for (event, check_result) in &checks.lifecycle_checks {
+                        lifecycle_checks.set(
+                            event.clone(),
+                            Vec::<IndexableCheckResult>::from(check_result.clone()),
+                        );
+                    }
+                    lifecycle_checks
+                })
+                .filter(|lifecycle_checks| !lifecycle_checks.is_all_empty());
+
+            (
+                key,
+                ExternalPluginAdapterSnapshot {
+                    authority: None,
+                    adapter,
+                    lifecycle_checks,
+                    data: None,
+                },
+            )
+        })
+        .collect()
+}
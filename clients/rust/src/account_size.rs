@@ -0,0 +1,378 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{
+    AnchorDeserialize as CrateDeserialize, AnchorSerialize as CrateSerialize,
+};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize as CrateDeserialize, BorshSerialize as CrateSerialize};
+use num_traits::FromPrimitive;
+use solana_program::rent::Rent;
+
+use crate::{
+    accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1, PluginRegistryV1},
+    errors::MplCoreError,
+    types::{
+        ExternalPluginAdapter, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey,
+        ExternalPluginAdapterType, ExternalRegistryRecord, Key, Plugin, PluginAuthority,
+        PluginAuthorityPair, PluginType, RegistryRecord,
+    },
+    DataBlob, PluginRegistryV1Safe,
+};
+
+/// The lamports the program keeps in every new asset account on top of rent, to be collected
+/// later.
+pub const COLLECT_AMOUNT: u64 = 1_500_000;
+
+/// The estimated size of a new account and the lamports the payer funds it with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountSizeEstimate {
+    /// The size of the account data in bytes.
+    pub size: usize,
+    /// The rent exemption for `size`, plus `COLLECT_AMOUNT` for assets.
+    pub lamports: u64,
+}
+
+/// The estimated change to an existing account.  Positive values are paid by the payer, negative
+/// values are refunded to the payer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountSizeDelta {
+    /// The change of the account data size in bytes.
+    pub size: i64,
+    /// The change of the rent exemption in lamports.
+    pub lamports: i64,
+}
+
+/// Estimate the size and lamports of an asset created with `CreateV2`.
+pub fn estimate_create_asset(
+    name: &str,
+    uri: &str,
+    plugins: &[PluginAuthorityPair],
+    external_plugin_adapters: &[ExternalPluginAdapterInitInfo],
+    rent: &Rent,
+) -> Result<AccountSizeEstimate, MplCoreError> {
+    let size = BaseAssetV1::BASE_LENGTH
+        + name.len()
+        + uri.len()
+        + plugins_size(plugins, external_plugin_adapters)?;
+
+    Ok(AccountSizeEstimate {
+        size,
+        lamports: rent.minimum_balance(size) + COLLECT_AMOUNT,
+    })
+}
+
+/// Estimate the size and lamports of a collection created with `CreateCollectionV2`.
+pub fn estimate_create_collection(
+    name: &str,
+    uri: &str,
+    plugins: &[PluginAuthorityPair],
+    external_plugin_adapters: &[ExternalPluginAdapterInitInfo],
+    rent: &Rent,
+) -> Result<AccountSizeEstimate, MplCoreError> {
+    let size = BaseCollectionV1::BASE_LENGTH
+        + name.len()
+        + uri.len()
+        + plugins_size(plugins, external_plugin_adapters)?;
+
+    Ok(AccountSizeEstimate {
+        size,
+        lamports: rent.minimum_balance(size),
+    })
+}
+
+/// Estimate the change to an existing asset or collection account when adding a plugin.
+pub fn estimate_add_plugin(
+    account_data: &[u8],
+    plugin: &Plugin,
+    authority: Option<&PluginAuthority>,
+    rent: &Rent,
+) -> Result<AccountSizeDelta, MplCoreError> {
+    let plugin_type = PluginType::from(plugin);
+    let (mut size_increase, registry) = match load_registry(account_data)? {
+        Some(registry) => (0, registry),
+        None => (plugin_meta_size()?, empty_registry()),
+    };
+
+    // You cannot add a duplicate plugin.
+    if registry
+        .registry
+        .iter()
+        .any(|record| record.plugin_type == plugin_type.clone() as u8)
+    {
+        return Err(MplCoreError::PluginAlreadyExists);
+    }
+
+    size_increase += plugin_size(
+        plugin,
+        &authority.cloned().unwrap_or_else(|| plugin_type.manager()),
+    )?;
+
+    size_delta(account_data.len(), size_increase as i64, rent)
+}
+
+/// Estimate the change to an existing asset or collection account when removing a plugin.
+pub fn estimate_remove_plugin(
+    account_data: &[u8],
+    plugin_type: &PluginType,
+    rent: &Rent,
+) -> Result<AccountSizeDelta, MplCoreError> {
+    let registry = load_registry(account_data)?.ok_or(MplCoreError::PluginNotFound)?;
+    let record = registry
+        .registry
+        .iter()
+        .find(|record| record.plugin_type == plugin_type.clone() as u8)
+        .ok_or(MplCoreError::PluginNotFound)?;
+
+    let mut plugin_data = account_data
+        .get(record.offset as usize..)
+        .ok_or(MplCoreError::DeserializationError)?;
+    let plugin =
+        Plugin::deserialize(&mut plugin_data).map_err(|_| MplCoreError::DeserializationError)?;
+
+    let size_decrease = plugin_size(&plugin, &record.authority)?;
+
+    size_delta(account_data.len(), -(size_decrease as i64), rent)
+}
+
+/// Estimate the change to an existing asset or collection account when adding an external
+/// plugin adapter.
+pub fn estimate_add_external_plugin_adapter(
+    account_data: &[u8],
+    init_info: &ExternalPluginAdapterInitInfo,
+    rent: &Rent,
+) -> Result<AccountSizeDelta, MplCoreError> {
+    let (mut size_increase, registry) = match load_registry(account_data)? {
+        Some(registry) => (0, registry),
+        None => (plugin_meta_size()?, empty_registry()),
+    };
+
+    // You cannot add a duplicate external plugin adapter.
+    let key = ExternalPluginAdapterKey::from(&ExternalPluginAdapter::from(init_info));
+    for record in &registry.external_registry {
+        if load_external_plugin_adapter(account_data, record.offset)?.0 == key {
+            return Err(MplCoreError::ExternalPluginAdapterAlreadyExists);
+        }
+    }
+
+    size_increase += external_plugin_adapter_size(init_info)?;
+
+    size_delta(account_data.len(), size_increase as i64, rent)
+}
+
+/// Estimate the change to an existing asset or collection account when removing an external
+/// plugin adapter, including its data.
+pub fn estimate_remove_external_plugin_adapter(
+    account_data: &[u8],
+    key: &ExternalPluginAdapterKey,
+    rent: &Rent,
+) -> Result<AccountSizeDelta, MplCoreError> {
+    let registry =
+        load_registry(account_data)?.ok_or(MplCoreError::ExternalPluginAdapterNotFound)?;
+
+    for record in &registry.external_registry {
+        let (record_key, plugin_size) = load_external_plugin_adapter(account_data, record.offset)?;
+        if record_key != *key {
+            continue;
+        }
+
+        let record_size = 1
+            + serialized_len(&record.authority)?
+            + serialized_len(&record.lifecycle_checks)?
+            + 8
+            + serialized_len(&record.data_offset)?
+            + serialized_len(&record.data_len)?;
+        let size_decrease = plugin_size + record.data_len.unwrap_or(0) as usize + record_size;
+
+        return size_delta(account_data.len(), -(size_decrease as i64), rent);
+    }
+
+    Err(MplCoreError::ExternalPluginAdapterNotFound)
+}
+
+// The size of the plugin header, registry and plugins written when creating an account.
+fn plugins_size(
+    plugins: &[PluginAuthorityPair],
+    external_plugin_adapters: &[ExternalPluginAdapterInitInfo],
+) -> Result<usize, MplCoreError> {
+    if plugins.is_empty() && external_plugin_adapters.is_empty() {
+        return Ok(0);
+    }
+
+    let mut size = plugin_meta_size()?;
+
+    let mut plugin_types = Vec::<PluginType>::new();
+    for pair in plugins {
+        let plugin_type = PluginType::from(&pair.plugin);
+        if plugin_types.contains(&plugin_type) {
+            return Err(MplCoreError::PluginAlreadyExists);
+        }
+
+        size += plugin_size(
+            &pair.plugin,
+            &pair
+                .authority
+                .clone()
+                .unwrap_or_else(|| plugin_type.manager()),
+        )?;
+        plugin_types.push(plugin_type);
+    }
+
+    let mut keys = Vec::<ExternalPluginAdapterKey>::new();
+    for init_info in external_plugin_adapters {
+        let key = ExternalPluginAdapterKey::from(&ExternalPluginAdapter::from(init_info));
+        if keys.contains(&key) {
+            return Err(MplCoreError::ExternalPluginAdapterAlreadyExists);
+        }
+
+        size += external_plugin_adapter_size(init_info)?;
+        keys.push(key);
+    }
+
+    Ok(size)
+}
+
+// The size of an empty plugin header and registry.
+fn plugin_meta_size() -> Result<usize, MplCoreError> {
+    let header = PluginHeaderV1 {
+        key: Key::PluginHeaderV1,
+        plugin_registry_offset: 0,
+    };
+    let registry = PluginRegistryV1 {
+        key: Key::PluginRegistryV1,
+        registry: vec![],
+        external_registry: vec![],
+    };
+
+    Ok(serialized_len(&header)? + serialized_len(&registry)?)
+}
+
+// The size of a plugin and its registry record.
+fn plugin_size(plugin: &Plugin, authority: &PluginAuthority) -> Result<usize, MplCoreError> {
+    let registry_record = RegistryRecord {
+        plugin_type: PluginType::from(plugin),
+        authority: authority.clone(),
+        offset: 0,
+    };
+
+    Ok(serialized_len(plugin)? + serialized_len(&registry_record)?)
+}
+
+// The size of a new external plugin adapter and its registry record.
+fn external_plugin_adapter_size(
+    init_info: &ExternalPluginAdapterInitInfo,
+) -> Result<usize, MplCoreError> {
+    let (plugin_type, authority, lifecycle_checks) = match init_info {
+        ExternalPluginAdapterInitInfo::LifecycleHook(init_info) => (
+            ExternalPluginAdapterType::LifecycleHook,
+            init_info.init_plugin_authority.clone(),
+            Some(init_info.lifecycle_checks.clone()),
+        ),
+        ExternalPluginAdapterInitInfo::Oracle(init_info) => (
+            ExternalPluginAdapterType::Oracle,
+            init_info.init_plugin_authority.clone(),
+            Some(init_info.lifecycle_checks.clone()),
+        ),
+        ExternalPluginAdapterInitInfo::DataStore(init_info) => (
+            ExternalPluginAdapterType::DataStore,
+            init_info.init_plugin_authority.clone(),
+            None,
+        ),
+    };
+
+    // Lifecycle hooks and data stores start with an empty data section after the plugin.
+    let (data_offset, data_len) = match plugin_type {
+        ExternalPluginAdapterType::LifecycleHook | ExternalPluginAdapterType::DataStore => {
+            (Some(0), Some(0))
+        }
+        ExternalPluginAdapterType::Oracle => (None, None),
+    };
+
+    let registry_record = ExternalRegistryRecord {
+        plugin_type,
+        authority: authority.unwrap_or(PluginAuthority::UpdateAuthority),
+        lifecycle_checks,
+        offset: 0,
+        data_offset,
+        data_len,
+    };
+
+    Ok(
+        serialized_len(&ExternalPluginAdapter::from(init_info))?
+            + serialized_len(&registry_record)?,
+    )
+}
+
+// Load the plugin registry of an asset or collection account, if it has one.
+fn load_registry(account_data: &[u8]) -> Result<Option<PluginRegistryV1Safe>, MplCoreError> {
+    let header_offset = match account_data.first().copied().and_then(Key::from_u8) {
+        Some(Key::AssetV1) => BaseAssetV1::from_bytes(account_data)
+            .map_err(|_| MplCoreError::DeserializationError)?
+            .get_size(),
+        Some(Key::CollectionV1) => BaseCollectionV1::from_bytes(account_data)
+            .map_err(|_| MplCoreError::DeserializationError)?
+            .get_size(),
+        _ => return Err(MplCoreError::IncorrectAccount),
+    };
+
+    if header_offset >= account_data.len() {
+        return Ok(None);
+    }
+
+    let header = PluginHeaderV1::from_bytes(&account_data[header_offset..])
+        .map_err(|_| MplCoreError::DeserializationError)?;
+    let registry_data = account_data
+        .get(header.plugin_registry_offset as usize..)
+        .ok_or(MplCoreError::DeserializationError)?;
+    let registry = PluginRegistryV1Safe::from_bytes(registry_data)
+        .map_err(|_| MplCoreError::DeserializationError)?;
+
+    Ok(Some(registry))
+}
+
+fn empty_registry() -> PluginRegistryV1Safe {
+    PluginRegistryV1Safe {
+        _key: Key::PluginRegistryV1,
+        registry: vec![],
+        external_registry: vec![],
+    }
+}
+
+// Load the key and serialized size of the external plugin adapter at the offset.
+fn load_external_plugin_adapter(
+    account_data: &[u8],
+    offset: u64,
+) -> Result<(ExternalPluginAdapterKey, usize), MplCoreError> {
+    let mut plugin_data = account_data
+        .get(offset as usize..)
+        .ok_or(MplCoreError::DeserializationError)?;
+    let plugin = ExternalPluginAdapter::deserialize(&mut plugin_data)
+        .map_err(|_| MplCoreError::DeserializationError)?;
+
+    Ok((
+        ExternalPluginAdapterKey::from(&plugin),
+        serialized_len(&plugin)?,
+    ))
+}
+
+fn serialized_len<T: CrateSerialize>(value: &T) -> Result<usize, MplCoreError> {
+    value
+        .try_to_vec()
+        .map(|data| data.len())
+        .map_err(|_| MplCoreError::SerializationError)
+}
+
+fn size_delta(
+    current_size: usize,
+    size_change: i64,
+    rent: &Rent,
+) -> Result<AccountSizeDelta, MplCoreError> {
+    let new_size = (current_size as i64)
+        .checked_add(size_change)
+        .filter(|size| *size >= 0)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    Ok(AccountSizeDelta {
+        size: size_change,
+        lamports: rent.minimum_balance(new_size as usize) as i64
+            - rent.minimum_balance(current_size) as i64,
+    })
+}
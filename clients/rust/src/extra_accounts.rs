@@ -0,0 +1,479 @@
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+
+use crate::{
+    errors::MplCoreError,
+    instructions::{
+        AddCollectionPluginV1Builder, AddPluginV1Builder,
+        ApproveCollectionPluginAuthorityV1Builder, ApprovePluginAuthorityV1Builder,
+        BurnCollectionV1Builder, BurnV1Builder, CompressV1Builder, CreateV2Builder,
+        DecompressV1Builder, RemoveAttributeV1Builder, RemoveCollectionAttributeV1Builder,
+        RemoveCollectionPluginV1Builder, RemovePluginV1Builder,
+        RevokeCollectionPluginAuthorityV1Builder, RevokePluginAuthorityV1Builder,
+        TransferV1Builder, UpdateCollectionPluginV1Builder, UpdateCollectionV1Builder,
+        UpdatePluginV1Builder, UpdateV1Builder, UpdateV2Builder, UpsertAttributeV1Builder,
+        UpsertCollectionAttributeV1Builder,
+    },
+    types::{
+        ExternalCheckResult, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey, ExtraAccount,
+        HookableLifecycleEvent, Seed,
+    },
+    Asset, Collection, ExternalPluginAdaptersList,
+};
+
+/// The prefix used by the program for preconfigured extra account seeds.
+pub const MPL_CORE_PREFIX: &str = "mpl-core";
+
+/// The accounts of a lifecycle event that extra accounts are derived from.
+#[derive(Clone, Copy, Default)]
+pub struct ExtraAccountInputs<'a> {
+    /// The asset address and its deserialized account, if the event targets an asset.
+    pub asset: Option<(Pubkey, &'a Asset)>,
+    /// The collection address and its deserialized account, if the event involves a collection.
+    pub collection: Option<(Pubkey, &'a Collection)>,
+    /// The new owner of the asset, for transfers.
+    pub recipient: Option<Pubkey>,
+}
+
+/// The accounts of a `CreateV2` instruction that extra accounts are derived from.  The asset
+/// does not exist yet, so its adapters are taken from the init infos passed to the instruction.
+#[derive(Clone, Copy)]
+pub struct CreateExtraAccountInputs<'a> {
+    /// The address of the asset being created.
+    pub asset: Pubkey,
+    /// The owner of the new asset.
+    pub owner: Pubkey,
+    /// The collection the asset is created in, if any.
+    pub collection: Option<Pubkey>,
+    /// The external plugin adapters the asset is created with.
+    pub external_plugin_adapters: &'a [ExternalPluginAdapterInitInfo],
+}
+
+// The addresses the seeds of extra accounts are derived from.
+struct SeedAddresses {
+    asset: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    collection: Option<Pubkey>,
+    recipient: Option<Pubkey>,
+}
+
+impl From<&ExtraAccountInputs<'_>> for SeedAddresses {
+    fn from(inputs: &ExtraAccountInputs) -> Self {
+        Self {
+            asset: inputs.asset.map(|(address, _)| address),
+            owner: inputs.asset.map(|(_, asset)| asset.base.owner),
+            collection: inputs.collection.map(|(address, _)| address),
+            recipient: inputs.recipient,
+        }
+    }
+}
+
+impl From<&CreateExtraAccountInputs<'_>> for SeedAddresses {
+    fn from(inputs: &CreateExtraAccountInputs) -> Self {
+        Self {
+            asset: Some(inputs.asset),
+            owner: Some(inputs.owner),
+            collection: inputs.collection,
+            recipient: None,
+        }
+    }
+}
+
+impl ExtraAccount {
+    /// Derive the address of the extra account the same way the program does, using `program_id`
+    /// for preconfigured and custom PDAs.
+    pub fn derive(
+        &self,
+        program_id: &Pubkey,
+        inputs: &ExtraAccountInputs,
+    ) -> Result<Pubkey, MplCoreError> {
+        self.derive_from_seeds(program_id, &SeedAddresses::from(inputs))
+    }
+
+    /// Derive the extra account and pair it with its signer and writable flags.
+    pub fn to_account_meta(
+        &self,
+        program_id: &Pubkey,
+        inputs: &ExtraAccountInputs,
+    ) -> Result<AccountMeta, MplCoreError> {
+        self.to_account_meta_from_seeds(program_id, &SeedAddresses::from(inputs))
+    }
+
+    fn derive_from_seeds(
+        &self,
+        program_id: &Pubkey,
+        inputs: &SeedAddresses,
+    ) -> Result<Pubkey, MplCoreError> {
+        match self {
+            ExtraAccount::PreconfiguredProgram { .. } => {
+                let seeds = &[MPL_CORE_PREFIX.as_bytes()];
+                let (pubkey, _bump) = Pubkey::find_program_address(seeds, program_id);
+                Ok(pubkey)
+            }
+            ExtraAccount::PreconfiguredCollection { .. } => {
+                let collection = inputs.collection.ok_or(MplCoreError::MissingCollection)?;
+                let seeds = &[MPL_CORE_PREFIX.as_bytes(), collection.as_ref()];
+                let (pubkey, _bump) = Pubkey::find_program_address(seeds, program_id);
+                Ok(pubkey)
+            }
+            ExtraAccount::PreconfiguredOwner { .. } => {
+                let owner = inputs.owner.ok_or(MplCoreError::MissingAsset)?;
+                let seeds = &[MPL_CORE_PREFIX.as_bytes(), owner.as_ref()];
+                let (pubkey, _bump) = Pubkey::find_program_address(seeds, program_id);
+                Ok(pubkey)
+            }
+            ExtraAccount::PreconfiguredRecipient { .. } => {
+                let recipient = inputs.recipient.ok_or(MplCoreError::MissingNewOwner)?;
+                let seeds = &[MPL_CORE_PREFIX.as_bytes(), recipient.as_ref()];
+                let (pubkey, _bump) = Pubkey::find_program_address(seeds, program_id);
+                Ok(pubkey)
+            }
+            ExtraAccount::PreconfiguredAsset { .. } => {
+                let asset = inputs.asset.ok_or(MplCoreError::MissingAsset)?;
+                let seeds = &[MPL_CORE_PREFIX.as_bytes(), asset.as_ref()];
+                let (pubkey, _bump) = Pubkey::find_program_address(seeds, program_id);
+                Ok(pubkey)
+            }
+            ExtraAccount::CustomPda {
+                seeds,
+                custom_program_id,
+                ..
+            } => {
+                let seeds = transform_seeds(seeds, inputs)?;
+
+                // Convert the Vec of Vec into Vec of u8 slices.
+                let vec_of_slices: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+
+                let (pubkey, _bump) = Pubkey::find_program_address(
+                    &vec_of_slices,
+                    custom_program_id.as_ref().unwrap_or(program_id),
+                );
+                Ok(pubkey)
+            }
+            ExtraAccount::Address { address, .. } => Ok(*address),
+        }
+    }
+
+    fn to_account_meta_from_seeds(
+        &self,
+        program_id: &Pubkey,
+        inputs: &SeedAddresses,
+    ) -> Result<AccountMeta, MplCoreError> {
+        let pubkey = self.derive_from_seeds(program_id, inputs)?;
+
+        let (is_signer, is_writable) = match self {
+            ExtraAccount::PreconfiguredProgram {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredCollection {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredOwner {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredRecipient {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredAsset {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::CustomPda {
+                is_signer,
+                is_writable,
+                ..
+            }
+            | ExtraAccount::Address {
+                is_signer,
+                is_writable,
+                ..
+            } => (*is_signer, *is_writable),
+        };
+
+        Ok(AccountMeta {
+            pubkey,
+            is_signer,
+            is_writable,
+        })
+    }
+}
+
+// Transform seeds from their tokens into actual seeds based on the passed-in inputs.
+fn transform_seeds(seeds: &[Seed], inputs: &SeedAddresses) -> Result<Vec<Vec<u8>>, MplCoreError> {
+    seeds
+        .iter()
+        .map(|seed| match seed {
+            Seed::Collection => inputs
+                .collection
+                .map(|collection| collection.to_bytes().to_vec())
+                .ok_or(MplCoreError::MissingCollection),
+            Seed::Owner => inputs
+                .owner
+                .map(|owner| owner.to_bytes().to_vec())
+                .ok_or(MplCoreError::MissingAsset),
+            Seed::Recipient => inputs
+                .recipient
+                .map(|recipient| recipient.to_bytes().to_vec())
+                .ok_or(MplCoreError::MissingNewOwner),
+            Seed::Asset => inputs
+                .asset
+                .map(|asset| asset.to_bytes().to_vec())
+                .ok_or(MplCoreError::MissingAsset),
+            Seed::Address(pubkey) => Ok(pubkey.to_bytes().to_vec()),
+            Seed::Bytes(val) => Ok(val.clone()),
+        })
+        .collect()
+}
+
+/// Find the accounts the external plugin adapters of an asset and its collection need for a
+/// lifecycle event, which must be passed to the instruction as remaining accounts.
+///
+/// Only adapters registered for the event are included.  As in the program, an asset adapter
+/// replaces a collection adapter with the same key.  Each account is listed once.
+pub fn find_extra_accounts(
+    event: &HookableLifecycleEvent,
+    inputs: &ExtraAccountInputs,
+) -> Result<Vec<AccountMeta>, MplCoreError> {
+    let asset_adapters = inputs
+        .asset
+        .map(|(_, asset)| &asset.external_plugin_adapter_list);
+    let collection_adapters = inputs
+        .collection
+        .map(|(_, collection)| &collection.external_plugin_adapter_list);
+
+    let asset_keys = asset_adapters
+        .map(|adapters| adapters_for_event(adapters, event))
+        .unwrap_or_default();
+    let collection_keys = collection_adapters
+        .map(|adapters| adapters_for_event(adapters, event))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|key| !asset_keys.contains(key))
+        .collect::<Vec<_>>();
+
+    let seeds = SeedAddresses::from(inputs);
+    let mut account_metas = Vec::<AccountMeta>::new();
+    for (adapters, keys) in [
+        (collection_adapters, collection_keys),
+        (asset_adapters, asset_keys),
+    ] {
+        let adapters = match adapters {
+            Some(adapters) => adapters,
+            None => continue,
+        };
+
+        for key in keys {
+            for account_meta in adapter_account_metas(adapters, &key, &seeds)? {
+                push_account_meta(&mut account_metas, account_meta);
+            }
+        }
+    }
+
+    Ok(account_metas)
+}
+
+/// Find the accounts the external plugin adapters of a new asset need when it is created, which
+/// must be passed to `CreateV2` as remaining accounts.
+///
+/// Only adapters registered for `Create` are included.  As in the program, the adapters of the
+/// collection are not validated on create.  Each account is listed once.
+pub fn find_create_extra_accounts(
+    inputs: &CreateExtraAccountInputs,
+) -> Result<Vec<AccountMeta>, MplCoreError> {
+    let seeds = SeedAddresses::from(inputs);
+    let mut account_metas = Vec::<AccountMeta>::new();
+    for init_info in inputs.external_plugin_adapters {
+        let adapter_account_metas = match init_info {
+            ExternalPluginAdapterInitInfo::LifecycleHook(init_info)
+                if is_registered_for_create(&init_info.lifecycle_checks) =>
+            {
+                lifecycle_hook_account_metas(
+                    &init_info.hooked_program,
+                    &init_info.extra_accounts,
+                    &seeds,
+                )?
+            }
+            ExternalPluginAdapterInitInfo::Oracle(init_info)
+                if is_registered_for_create(&init_info.lifecycle_checks) =>
+            {
+                vec![oracle_account_meta(
+                    &init_info.base_address,
+                    &init_info.base_address_config,
+                    &seeds,
+                )?]
+            }
+            _ => vec![],
+        };
+        for account_meta in adapter_account_metas {
+            push_account_meta(&mut account_metas, account_meta);
+        }
+    }
+
+    Ok(account_metas)
+}
+
+// Add an account to the list, merging its flags into an existing entry for the same address.
+fn push_account_meta(account_metas: &mut Vec<AccountMeta>, account_meta: AccountMeta) {
+    match account_metas
+        .iter_mut()
+        .find(|existing| existing.pubkey == account_meta.pubkey)
+    {
+        Some(existing) => {
+            existing.is_signer |= account_meta.is_signer;
+            existing.is_writable |= account_meta.is_writable;
+        }
+        None => account_metas.push(account_meta),
+    }
+}
+
+// Whether an adapter being created is registered for the create event.
+fn is_registered_for_create(
+    lifecycle_checks: &[(HookableLifecycleEvent, ExternalCheckResult)],
+) -> bool {
+    lifecycle_checks
+        .iter()
+        .any(|(event, _)| *event == HookableLifecycleEvent::Create)
+}
+
+// The keys of the adapters registered for the lifecycle event.
+fn adapters_for_event(
+    adapters: &ExternalPluginAdaptersList,
+    event: &HookableLifecycleEvent,
+) -> Vec<ExternalPluginAdapterKey> {
+    adapters
+        .lifecycle_checks
+        .iter()
+        .filter(|checks| {
+            checks
+                .lifecycle_checks
+                .iter()
+                .any(|(check_event, _)| check_event == event)
+        })
+        .map(|checks| checks.key.clone())
+        .collect()
+}
+
+// The accounts a single adapter reads when it is validated.
+fn adapter_account_metas(
+    adapters: &ExternalPluginAdaptersList,
+    key: &ExternalPluginAdapterKey,
+    inputs: &SeedAddresses,
+) -> Result<Vec<AccountMeta>, MplCoreError> {
+    match key {
+        ExternalPluginAdapterKey::LifecycleHook(hooked_program) => {
+            let hook = match adapters
+                .lifecycle_hooks
+                .iter()
+                .find(|hook| hook.hooked_program == *hooked_program)
+            {
+                Some(hook) => hook,
+                None => return Ok(vec![]),
+            };
+
+            lifecycle_hook_account_metas(&hook.hooked_program, &hook.extra_accounts, inputs)
+        }
+        ExternalPluginAdapterKey::Oracle(base_address) => {
+            let oracle = match adapters
+                .oracles
+                .iter()
+                .find(|oracle| oracle.base_address == *base_address)
+            {
+                Some(oracle) => oracle,
+                None => return Ok(vec![]),
+            };
+
+            Ok(vec![oracle_account_meta(
+                &oracle.base_address,
+                &oracle.base_address_config,
+                inputs,
+            )?])
+        }
+        // Data stores do not take part in lifecycle events.
+        ExternalPluginAdapterKey::DataStore(_) => Ok(vec![]),
+    }
+}
+
+// The hooked program and the extra accounts a lifecycle hook reads when it is validated.
+fn lifecycle_hook_account_metas(
+    hooked_program: &Pubkey,
+    extra_accounts: &Option<Vec<ExtraAccount>>,
+    inputs: &SeedAddresses,
+) -> Result<Vec<AccountMeta>, MplCoreError> {
+    let mut account_metas = vec![AccountMeta::new_readonly(*hooked_program, false)];
+    for extra_account in extra_accounts.iter().flatten() {
+        account_metas.push(extra_account.to_account_meta_from_seeds(hooked_program, inputs)?);
+    }
+    Ok(account_metas)
+}
+
+// The account an oracle reads its validation results from.
+fn oracle_account_meta(
+    base_address: &Pubkey,
+    base_address_config: &Option<ExtraAccount>,
+    inputs: &SeedAddresses,
+) -> Result<AccountMeta, MplCoreError> {
+    let oracle_account = match base_address_config {
+        None => *base_address,
+        Some(extra_account) => extra_account.derive_from_seeds(base_address, inputs)?,
+    };
+    Ok(AccountMeta::new_readonly(oracle_account, false))
+}
+
+macro_rules! impl_add_extra_accounts {
+    ($($builder:ident => $event:ident),* $(,)?) => {
+        $(
+            impl $builder {
+                /// Append the accounts required by the external plugin adapters of the asset
+                /// and collection as remaining accounts.
+                pub fn add_extra_accounts(
+                    &mut self,
+                    inputs: &ExtraAccountInputs,
+                ) -> Result<&mut Self, MplCoreError> {
+                    let accounts =
+                        find_extra_accounts(&HookableLifecycleEvent::$event, inputs)?;
+                    Ok(self.add_remaining_accounts(&accounts))
+                }
+            }
+        )*
+    };
+}
+
+impl_add_extra_accounts!(
+    AddPluginV1Builder => AddPlugin,
+    AddCollectionPluginV1Builder => AddPlugin,
+    ApprovePluginAuthorityV1Builder => ApprovePluginAuthority,
+    ApproveCollectionPluginAuthorityV1Builder => ApprovePluginAuthority,
+    BurnV1Builder => Burn,
+    BurnCollectionV1Builder => Burn,
+    CompressV1Builder => Compress,
+    DecompressV1Builder => Decompress,
+    RemovePluginV1Builder => RemovePlugin,
+    RemoveCollectionPluginV1Builder => RemovePlugin,
+    RevokePluginAuthorityV1Builder => RevokePluginAuthority,
+    RevokeCollectionPluginAuthorityV1Builder => RevokePluginAuthority,
+    TransferV1Builder => Transfer,
+    UpdateV1Builder => Update,
+    UpdateV2Builder => Update,
+    UpdateCollectionV1Builder => Update,
+    UpdatePluginV1Builder => UpdatePlugin,
+    UpdateCollectionPluginV1Builder => UpdatePlugin,
+    UpsertAttributeV1Builder => UpdatePlugin,
+    UpsertCollectionAttributeV1Builder => UpdatePlugin,
+    RemoveAttributeV1Builder => UpdatePlugin,
+    RemoveCollectionAttributeV1Builder => UpdatePlugin,
+);
+
+impl CreateV2Builder {
+    /// Append the accounts required by the external plugin adapters the asset is created with as
+    /// remaining accounts.
+    pub fn add_extra_accounts(
+        &mut self,
+        inputs: &CreateExtraAccountInputs,
+    ) -> Result<&mut Self, MplCoreError> {
+        let accounts = find_create_extra_accounts(inputs)?;
+        Ok(self.add_remaining_accounts(&accounts))
+    }
+}
@@ -136,6 +136,38 @@ pub enum MplCoreError {
     /// 41 (0x29) - Invalid plugin operation
     #[error("Invalid plugin operation")]
     InvalidPluginOperation,
+    /// 42 (0x2A) - Could not read validation result returned by lifecycle hook
+    #[error("Could not read validation result returned by lifecycle hook")]
+    InvalidLifecycleHookResult,
+    /// 43 (0x2B) - Cannot compress an asset with external plugin adapters
+    #[error("Cannot compress an asset with external plugin adapters")]
+    CannotCompressExternalPluginAdapters,
+    /// 44 (0x2C) - Cannot move asset to collection with permanent delegates
+    #[error("Cannot move asset to collection with permanent delegates")]
+    PermanentDelegatesPreventMove,
+    /// 45 (0x2D) - Master edition max supply reached
+    #[error("Master edition max supply reached")]
+    MaxSupplyReached,
+    /// 46 (0x2E) - Multisig authority must have a threshold between one and the number of unique signers
+    #[error(
+        "Multisig authority must have a threshold between one and the number of unique signers"
+    )]
+    InvalidMultisigAuthority,
+    /// 47 (0x2F) - Invalid asset signer PDA, it must be derived from the asset
+    #[error("Invalid asset signer PDA, it must be derived from the asset")]
+    InvalidExecutePda,
+    /// 48 (0x30) - Invalid or missing creator account for royalty payment
+    #[error("Invalid or missing creator account for royalty payment")]
+    InvalidRoyaltyCreator,
+    /// 49 (0x31) - Missing instructions sysvar needed to identify the invoking program
+    #[error("Missing instructions sysvar needed to identify the invoking program")]
+    MissingInstructionsSysvar,
+    /// 50 (0x32) - Attribute not found
+    #[error("Attribute not found")]
+    AttributeNotFound,
+    /// 51 (0x33) - Sale price is too low to pay every creator their royalties
+    #[error("Sale price is too low to pay every creator their royalties")]
+    RoyaltyUnderpaid,
 }
 
 impl solana_program::program_error::PrintProgramError for MplCoreError {
@@ -22,6 +22,8 @@ pub struct CreateCollectionV2 {
     pub payer: solana_program::pubkey::Pubkey,
     /// The system program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The SPL Noop Program
+    pub log_wrapper: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl CreateCollectionV2 {
@@ -37,7 +39,7 @@ impl CreateCollectionV2 {
         args: CreateCollectionV2InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.collection,
             true,
@@ -60,6 +62,17 @@ impl CreateCollectionV2 {
             self.system_program,
             false,
         ));
+        if let Some(log_wrapper) = self.log_wrapper {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                log_wrapper,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_CORE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = CreateCollectionV2InstructionData::new()
             .try_to_vec()
@@ -106,12 +119,14 @@ pub struct CreateCollectionV2InstructionArgs {
 ///   1. `[optional]` update_authority
 ///   2. `[writable, signer]` payer
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   4. `[optional]` log_wrapper
 #[derive(Default)]
 pub struct CreateCollectionV2Builder {
     collection: Option<solana_program::pubkey::Pubkey>,
     update_authority: Option<solana_program::pubkey::Pubkey>,
     payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    log_wrapper: Option<solana_program::pubkey::Pubkey>,
     name: Option<String>,
     uri: Option<String>,
     plugins: Option<Vec<PluginAuthorityPair>>,
@@ -152,6 +167,16 @@ impl CreateCollectionV2Builder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL Noop Program
+    #[inline(always)]
+    pub fn log_wrapper(
+        &mut self,
+        log_wrapper: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.log_wrapper = log_wrapper;
+        self
+    }
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
@@ -204,6 +229,7 @@ impl CreateCollectionV2Builder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            log_wrapper: self.log_wrapper,
         };
         let args = CreateCollectionV2InstructionArgs {
             name: self.name.clone().expect("name is not set"),
@@ -226,6 +252,8 @@ pub struct CreateCollectionV2CpiAccounts<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL Noop Program
+    pub log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `create_collection_v2` CPI instruction.
@@ -240,6 +268,8 @@ pub struct CreateCollectionV2Cpi<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL Noop Program
+    pub log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateCollectionV2InstructionArgs,
 }
@@ -256,6 +286,7 @@ impl<'a, 'b> CreateCollectionV2Cpi<'a, 'b> {
             update_authority: accounts.update_authority,
             payer: accounts.payer,
             system_program: accounts.system_program,
+            log_wrapper: accounts.log_wrapper,
             __args: args,
         }
     }
@@ -292,7 +323,7 @@ impl<'a, 'b> CreateCollectionV2Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.collection.key,
             true,
@@ -316,6 +347,17 @@ impl<'a, 'b> CreateCollectionV2Cpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(log_wrapper) = self.log_wrapper {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *log_wrapper.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_CORE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -334,7 +376,7 @@ impl<'a, 'b> CreateCollectionV2Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.collection.clone());
         if let Some(update_authority) = self.update_authority {
@@ -342,6 +384,9 @@ impl<'a, 'b> CreateCollectionV2Cpi<'a, 'b> {
         }
         account_infos.push(self.payer.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(log_wrapper) = self.log_wrapper {
+            account_infos.push(log_wrapper.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -362,6 +407,7 @@ impl<'a, 'b> CreateCollectionV2Cpi<'a, 'b> {
 ///   1. `[optional]` update_authority
 ///   2. `[writable, signer]` payer
 ///   3. `[]` system_program
+///   4. `[optional]` log_wrapper
 pub struct CreateCollectionV2CpiBuilder<'a, 'b> {
     instruction: Box<CreateCollectionV2CpiBuilderInstruction<'a, 'b>>,
 }
@@ -374,6 +420,7 @@ impl<'a, 'b> CreateCollectionV2CpiBuilder<'a, 'b> {
             update_authority: None,
             payer: None,
             system_program: None,
+            log_wrapper: None,
             name: None,
             uri: None,
             plugins: None,
@@ -416,6 +463,16 @@ impl<'a, 'b> CreateCollectionV2CpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The SPL Noop Program
+    #[inline(always)]
+    pub fn log_wrapper(
+        &mut self,
+        log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.log_wrapper = log_wrapper;
+        self
+    }
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
@@ -501,6 +558,8 @@ impl<'a, 'b> CreateCollectionV2CpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            log_wrapper: self.instruction.log_wrapper,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -516,6 +575,7 @@ struct CreateCollectionV2CpiBuilderInstruction<'a, 'b> {
     update_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     name: Option<String>,
     uri: Option<String>,
     plugins: Option<Vec<PluginAuthorityPair>>,
@@ -144,6 +144,7 @@ pub struct CreateV2InstructionArgs {
     pub uri: String,
     pub plugins: Option<Vec<PluginAuthorityPair>>,
     pub external_plugin_adapters: Option<Vec<ExternalPluginAdapterInitInfo>>,
+    pub auto_number_edition: Option<bool>,
 }
 
 /// Instruction builder for `CreateV2`.
@@ -173,6 +174,7 @@ pub struct CreateV2Builder {
     uri: Option<String>,
     plugins: Option<Vec<PluginAuthorityPair>>,
     external_plugin_adapters: Option<Vec<ExternalPluginAdapterInitInfo>>,
+    auto_number_edition: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -271,6 +273,12 @@ impl CreateV2Builder {
         self.external_plugin_adapters = Some(external_plugin_adapters);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn auto_number_edition(&mut self, auto_number_edition: bool) -> &mut Self {
+        self.auto_number_edition = Some(auto_number_edition);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -309,6 +317,7 @@ impl CreateV2Builder {
             uri: self.uri.clone().expect("uri is not set"),
             plugins: self.plugins.clone(),
             external_plugin_adapters: self.external_plugin_adapters.clone(),
+            auto_number_edition: self.auto_number_edition.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -559,6 +568,7 @@ impl<'a, 'b> CreateV2CpiBuilder<'a, 'b> {
             uri: None,
             plugins: None,
             external_plugin_adapters: None,
+            auto_number_edition: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -665,6 +675,12 @@ impl<'a, 'b> CreateV2CpiBuilder<'a, 'b> {
         self.instruction.external_plugin_adapters = Some(external_plugin_adapters);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn auto_number_edition(&mut self, auto_number_edition: bool) -> &mut Self {
+        self.instruction.auto_number_edition = Some(auto_number_edition);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -716,6 +732,7 @@ impl<'a, 'b> CreateV2CpiBuilder<'a, 'b> {
             uri: self.instruction.uri.clone().expect("uri is not set"),
             plugins: self.instruction.plugins.clone(),
             external_plugin_adapters: self.instruction.external_plugin_adapters.clone(),
+            auto_number_edition: self.instruction.auto_number_edition.clone(),
         };
         let instruction = CreateV2Cpi {
             __program: self.instruction.__program,
@@ -762,6 +779,7 @@ struct CreateV2CpiBuilderInstruction<'a, 'b> {
     uri: Option<String>,
     plugins: Option<Vec<PluginAuthorityPair>>,
     external_plugin_adapters: Option<Vec<ExternalPluginAdapterInitInfo>>,
+    auto_number_edition: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -20,6 +20,9 @@ pub(crate) mod r#create_collection_v2;
 pub(crate) mod r#create_v1;
 pub(crate) mod r#create_v2;
 pub(crate) mod r#decompress_v1;
+pub(crate) mod r#execute_v1;
+pub(crate) mod r#remove_attribute_v1;
+pub(crate) mod r#remove_collection_attribute_v1;
 pub(crate) mod r#remove_collection_external_plugin_adapter_v1;
 pub(crate) mod r#remove_collection_plugin_v1;
 pub(crate) mod r#remove_external_plugin_adapter_v1;
@@ -33,6 +36,9 @@ pub(crate) mod r#update_collection_v1;
 pub(crate) mod r#update_external_plugin_adapter_v1;
 pub(crate) mod r#update_plugin_v1;
 pub(crate) mod r#update_v1;
+pub(crate) mod r#update_v2;
+pub(crate) mod r#upsert_attribute_v1;
+pub(crate) mod r#upsert_collection_attribute_v1;
 pub(crate) mod r#write_collection_external_plugin_adapter_data_v1;
 pub(crate) mod r#write_external_plugin_adapter_data_v1;
 
@@ -51,6 +57,9 @@ pub use self::r#create_collection_v2::*;
 pub use self::r#create_v1::*;
 pub use self::r#create_v2::*;
 pub use self::r#decompress_v1::*;
+pub use self::r#execute_v1::*;
+pub use self::r#remove_attribute_v1::*;
+pub use self::r#remove_collection_attribute_v1::*;
 pub use self::r#remove_collection_external_plugin_adapter_v1::*;
 pub use self::r#remove_collection_plugin_v1::*;
 pub use self::r#remove_external_plugin_adapter_v1::*;
@@ -64,5 +73,8 @@ pub use self::r#update_collection_v1::*;
 pub use self::r#update_external_plugin_adapter_v1::*;
 pub use self::r#update_plugin_v1::*;
 pub use self::r#update_v1::*;
+pub use self::r#update_v2::*;
+pub use self::r#upsert_attribute_v1::*;
+pub use self::r#upsert_collection_attribute_v1::*;
 pub use self::r#write_collection_external_plugin_adapter_data_v1::*;
 pub use self::r#write_external_plugin_adapter_data_v1::*;
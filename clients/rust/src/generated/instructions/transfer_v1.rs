@@ -126,6 +126,7 @@ impl TransferV1InstructionData {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransferV1InstructionArgs {
     pub compression_proof: Option<CompressionProof>,
+    pub sale_price: Option<u64>,
 }
 
 /// Instruction builder for `TransferV1`.
@@ -149,6 +150,7 @@ pub struct TransferV1Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     log_wrapper: Option<solana_program::pubkey::Pubkey>,
     compression_proof: Option<CompressionProof>,
+    sale_price: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -214,6 +216,12 @@ impl TransferV1Builder {
         self.compression_proof = Some(compression_proof);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_price(&mut self, sale_price: u64) -> &mut Self {
+        self.sale_price = Some(sale_price);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -245,6 +253,7 @@ impl TransferV1Builder {
         };
         let args = TransferV1InstructionArgs {
             compression_proof: self.compression_proof.clone(),
+            sale_price: self.sale_price.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -471,6 +480,7 @@ impl<'a, 'b> TransferV1CpiBuilder<'a, 'b> {
             system_program: None,
             log_wrapper: None,
             compression_proof: None,
+            sale_price: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -542,6 +552,12 @@ impl<'a, 'b> TransferV1CpiBuilder<'a, 'b> {
         self.instruction.compression_proof = Some(compression_proof);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sale_price(&mut self, sale_price: u64) -> &mut Self {
+        self.instruction.sale_price = Some(sale_price);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -585,6 +601,7 @@ impl<'a, 'b> TransferV1CpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = TransferV1InstructionArgs {
             compression_proof: self.instruction.compression_proof.clone(),
+            sale_price: self.instruction.sale_price.clone(),
         };
         let instruction = TransferV1Cpi {
             __program: self.instruction.__program,
@@ -621,6 +638,7 @@ struct TransferV1CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     compression_proof: Option<CompressionProof>,
+    sale_price: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -20,4 +20,11 @@ pub enum HookableLifecycleEvent {
     Transfer,
     Burn,
     Update,
+    AddPlugin,
+    RemovePlugin,
+    UpdatePlugin,
+    ApprovePluginAuthority,
+    RevokePluginAuthority,
+    Compress,
+    Decompress,
 }
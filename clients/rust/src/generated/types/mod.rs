@@ -7,7 +7,9 @@
 
 pub(crate) mod r#add_blocker;
 pub(crate) mod r#attribute;
+pub(crate) mod r#attribute_key_authority;
 pub(crate) mod r#attributes;
+pub(crate) mod r#attributes_v2;
 pub(crate) mod r#autograph;
 pub(crate) mod r#autograph_signature;
 pub(crate) mod r#burn_delegate;
@@ -36,10 +38,16 @@ pub(crate) mod r#immutable_metadata;
 pub(crate) mod r#key;
 pub(crate) mod r#lifecycle_hook;
 pub(crate) mod r#lifecycle_hook_init_info;
+pub(crate) mod r#lifecycle_hook_instruction;
 pub(crate) mod r#lifecycle_hook_update_info;
+pub(crate) mod r#lock_expiry;
 pub(crate) mod r#master_edition;
+pub(crate) mod r#mpl_core_event;
+pub(crate) mod r#mpl_core_event_payload_v1;
+pub(crate) mod r#mpl_core_event_v1;
 pub(crate) mod r#oracle;
 pub(crate) mod r#oracle_init_info;
+pub(crate) mod r#oracle_plugin_validation;
 pub(crate) mod r#oracle_update_info;
 pub(crate) mod r#oracle_validation;
 pub(crate) mod r#permanent_burn_delegate;
@@ -53,9 +61,12 @@ pub(crate) mod r#registry_record;
 pub(crate) mod r#royalties;
 pub(crate) mod r#rule_set;
 pub(crate) mod r#seed;
+pub(crate) mod r#soulbound;
+pub(crate) mod r#time_lock;
 pub(crate) mod r#transfer_delegate;
 pub(crate) mod r#update_authority;
 pub(crate) mod r#update_delegate;
+pub(crate) mod r#use_delegate;
 pub(crate) mod r#validation_result;
 pub(crate) mod r#validation_results_offset;
 pub(crate) mod r#verified_creators;
@@ -63,7 +74,9 @@ pub(crate) mod r#verified_creators_signature;
 
 pub use self::r#add_blocker::*;
 pub use self::r#attribute::*;
+pub use self::r#attribute_key_authority::*;
 pub use self::r#attributes::*;
+pub use self::r#attributes_v2::*;
 pub use self::r#autograph::*;
 pub use self::r#autograph_signature::*;
 pub use self::r#burn_delegate::*;
@@ -92,10 +105,16 @@ pub use self::r#immutable_metadata::*;
 pub use self::r#key::*;
 pub use self::r#lifecycle_hook::*;
 pub use self::r#lifecycle_hook_init_info::*;
+pub use self::r#lifecycle_hook_instruction::*;
 pub use self::r#lifecycle_hook_update_info::*;
+pub use self::r#lock_expiry::*;
 pub use self::r#master_edition::*;
+pub use self::r#mpl_core_event::*;
+pub use self::r#mpl_core_event_payload_v1::*;
+pub use self::r#mpl_core_event_v1::*;
 pub use self::r#oracle::*;
 pub use self::r#oracle_init_info::*;
+pub use self::r#oracle_plugin_validation::*;
 pub use self::r#oracle_update_info::*;
 pub use self::r#oracle_validation::*;
 pub use self::r#permanent_burn_delegate::*;
@@ -109,9 +128,12 @@ pub use self::r#registry_record::*;
 pub use self::r#royalties::*;
 pub use self::r#rule_set::*;
 pub use self::r#seed::*;
+pub use self::r#soulbound::*;
+pub use self::r#time_lock::*;
 pub use self::r#transfer_delegate::*;
 pub use self::r#update_authority::*;
 pub use self::r#update_delegate::*;
+pub use self::r#use_delegate::*;
 pub use self::r#validation_result::*;
 pub use self::r#validation_results_offset::*;
 pub use self::r#verified_creators::*;
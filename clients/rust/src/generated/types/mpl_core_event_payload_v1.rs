@@ -0,0 +1,94 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::ExternalPluginAdapterKey;
+use crate::generated::types::PluginAuthority;
+use crate::generated::types::PluginType;
+use crate::generated::types::UpdateAuthority;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MplCoreEventPayloadV1 {
+    Create {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        owner: Pubkey,
+        update_authority: UpdateAuthority,
+    },
+    Transfer {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        new_owner: Pubkey,
+    },
+    Update {
+        new_name: Option<String>,
+        new_uri: Option<String>,
+        new_update_authority: Option<UpdateAuthority>,
+    },
+    Burn,
+    AddPlugin {
+        plugin_type: PluginType,
+        authority: PluginAuthority,
+    },
+    RemovePlugin {
+        plugin_type: PluginType,
+    },
+    UpdatePlugin {
+        plugin_type: PluginType,
+    },
+    ApprovePluginAuthority {
+        plugin_type: PluginType,
+        new_authority: PluginAuthority,
+    },
+    RevokePluginAuthority {
+        plugin_type: PluginType,
+    },
+    AddExternalPluginAdapter {
+        key: ExternalPluginAdapterKey,
+    },
+    RemoveExternalPluginAdapter {
+        key: ExternalPluginAdapterKey,
+    },
+    UpdateExternalPluginAdapter {
+        key: ExternalPluginAdapterKey,
+    },
+    WriteExternalPluginAdapterData {
+        key: ExternalPluginAdapterKey,
+    },
+    CreateCollection {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        update_authority: Pubkey,
+    },
+    Decompress {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        owner: Pubkey,
+    },
+    Execute {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        program_id: Pubkey,
+    },
+}
@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::ExternalValidationResult;
+use crate::generated::types::OraclePluginValidation;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -23,4 +24,17 @@ pub enum OracleValidation {
         burn: ExternalValidationResult,
         update: ExternalValidationResult,
     },
+    V2 {
+        create: ExternalValidationResult,
+        transfer: ExternalValidationResult,
+        burn: ExternalValidationResult,
+        update: ExternalValidationResult,
+        add_plugin: OraclePluginValidation,
+        remove_plugin: OraclePluginValidation,
+        update_plugin: OraclePluginValidation,
+        approve_plugin_authority: OraclePluginValidation,
+        revoke_plugin_authority: OraclePluginValidation,
+        compress: ExternalValidationResult,
+        decompress: ExternalValidationResult,
+    },
 }
@@ -7,6 +7,7 @@
 
 use crate::generated::types::AddBlocker;
 use crate::generated::types::Attributes;
+use crate::generated::types::AttributesV2;
 use crate::generated::types::Autograph;
 use crate::generated::types::BurnDelegate;
 use crate::generated::types::Edition;
@@ -17,8 +18,11 @@ use crate::generated::types::PermanentBurnDelegate;
 use crate::generated::types::PermanentFreezeDelegate;
 use crate::generated::types::PermanentTransferDelegate;
 use crate::generated::types::Royalties;
+use crate::generated::types::Soulbound;
+use crate::generated::types::TimeLock;
 use crate::generated::types::TransferDelegate;
 use crate::generated::types::UpdateDelegate;
+use crate::generated::types::UseDelegate;
 use crate::generated::types::VerifiedCreators;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
@@ -45,4 +49,8 @@ pub enum Plugin {
     ImmutableMetadata(ImmutableMetadata),
     VerifiedCreators(VerifiedCreators),
     Autograph(Autograph),
+    TimeLock(TimeLock),
+    Soulbound(Soulbound),
+    UseDelegate(UseDelegate),
+    AttributesV2(AttributesV2),
 }
@@ -26,4 +26,12 @@ pub enum PluginAuthority {
         )]
         address: Pubkey,
     },
+    Multisig {
+        threshold: u8,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        )]
+        signers: Vec<Pubkey>,
+    },
 }
@@ -31,4 +31,8 @@ pub enum PluginType {
     ImmutableMetadata,
     VerifiedCreators,
     Autograph,
+    TimeLock,
+    Soulbound,
+    UseDelegate,
+    AttributesV2,
 }
@@ -20,4 +20,5 @@ pub struct Royalties {
     pub basis_points: u16,
     pub creators: Vec<Creator>,
     pub rule_set: RuleSet,
+    pub royalty_payments: bool,
 }
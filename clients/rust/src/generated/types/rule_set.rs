@@ -27,4 +27,14 @@ pub enum RuleSet {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     ProgramDenyList(Vec<Pubkey>),
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    InvokingProgramAllowList(Vec<Pubkey>),
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    InvokingProgramDenyList(Vec<Pubkey>),
 }
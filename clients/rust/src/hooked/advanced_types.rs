@@ -1,18 +1,20 @@
 #[cfg(feature = "anchor")]
-use anchor_lang::prelude::AnchorDeserialize;
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 use std::{cmp::Ordering, io::ErrorKind};
 
 use crate::{
     accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
     types::{
-        AddBlocker, Attributes, Autograph, BurnDelegate, DataStore, Edition, ExternalCheckResult,
-        ExternalPluginAdapter, ExternalPluginAdapterKey, FreezeDelegate, ImmutableMetadata, Key,
-        LifecycleHook, MasterEdition, Oracle, PermanentBurnDelegate, PermanentFreezeDelegate,
-        PermanentTransferDelegate, PluginAuthority, Royalties, TransferDelegate, UpdateDelegate,
-        VerifiedCreators,
+        AddBlocker, Attributes, AttributesV2, Autograph, BurnDelegate, DataStore, Edition,
+        ExternalCheckResult, ExternalPluginAdapter, ExternalPluginAdapterInitInfo,
+        ExternalPluginAdapterKey, ExternalPluginAdapterSchema, FreezeDelegate,
+        HookableLifecycleEvent, ImmutableMetadata, Key, LifecycleHook, MasterEdition, Oracle,
+        PermanentBurnDelegate, PermanentFreezeDelegate, PermanentTransferDelegate, PluginAuthority,
+        Royalties, Soulbound, TimeLock, TransferDelegate, UpdateDelegate, UseDelegate,
+        ValidationResultsOffset, VerifiedCreators,
     },
 };
 
@@ -22,6 +24,7 @@ pub enum AuthorityType {
     Owner,
     UpdateAuthority,
     Address,
+    Multisig,
 }
 
 impl From<PluginAuthority> for AuthorityType {
@@ -31,14 +34,17 @@ impl From<PluginAuthority> for AuthorityType {
             PluginAuthority::Owner => AuthorityType::Owner,
             PluginAuthority::UpdateAuthority => AuthorityType::UpdateAuthority,
             PluginAuthority::Address { address: _ } => AuthorityType::Address,
+            PluginAuthority::Multisig { .. } => AuthorityType::Multisig,
         }
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct BaseAuthority {
     pub authority_type: AuthorityType,
     pub address: Option<Pubkey>,
+    pub threshold: Option<u8>,
+    pub signers: Option<Vec<Pubkey>>,
 }
 
 impl From<PluginAuthority> for BaseAuthority {
@@ -47,24 +53,55 @@ impl From<PluginAuthority> for BaseAuthority {
             PluginAuthority::None => BaseAuthority {
                 authority_type: AuthorityType::None,
                 address: None,
+                threshold: None,
+                signers: None,
             },
             PluginAuthority::Owner => BaseAuthority {
                 authority_type: AuthorityType::Owner,
                 address: None,
+                threshold: None,
+                signers: None,
             },
             PluginAuthority::UpdateAuthority => BaseAuthority {
                 authority_type: AuthorityType::UpdateAuthority,
                 address: None,
+                threshold: None,
+                signers: None,
             },
             PluginAuthority::Address { address } => BaseAuthority {
                 authority_type: AuthorityType::Address,
                 address: Some(address),
+                threshold: None,
+                signers: None,
+            },
+            PluginAuthority::Multisig { threshold, signers } => BaseAuthority {
+                authority_type: AuthorityType::Multisig,
+                address: None,
+                threshold: Some(threshold),
+                signers: Some(signers),
             },
         }
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+impl From<&BaseAuthority> for PluginAuthority {
+    fn from(authority: &BaseAuthority) -> Self {
+        match authority.authority_type {
+            AuthorityType::None => PluginAuthority::None,
+            AuthorityType::Owner => PluginAuthority::Owner,
+            AuthorityType::UpdateAuthority => PluginAuthority::UpdateAuthority,
+            AuthorityType::Address => PluginAuthority::Address {
+                address: authority.address.unwrap_or_default(),
+            },
+            AuthorityType::Multisig => PluginAuthority::Multisig {
+                threshold: authority.threshold.unwrap_or_default(),
+                signers: authority.signers.clone().unwrap_or_default(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct BasePlugin {
     pub authority: BaseAuthority,
     pub offset: Option<u64>,
@@ -160,6 +197,30 @@ pub struct AutographPlugin {
     pub autograph: Autograph,
 }
 
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TimeLockPlugin {
+    pub base: BasePlugin,
+    pub time_lock: TimeLock,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SoulboundPlugin {
+    pub base: BasePlugin,
+    pub soulbound: Soulbound,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UseDelegatePlugin {
+    pub base: BasePlugin,
+    pub use_delegate: UseDelegate,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AttributesV2Plugin {
+    pub base: BasePlugin,
+    pub attributes_v2: AttributesV2,
+}
+
 #[derive(Debug, Default)]
 pub struct PluginsList {
     pub royalties: Option<RoyaltiesPlugin>,
@@ -177,6 +238,18 @@ pub struct PluginsList {
     pub immutable_metadata: Option<ImmutableMetadataPlugin>,
     pub verified_creators: Option<VerifiedCreatorsPlugin>,
     pub autograph: Option<AutographPlugin>,
+    pub time_lock: Option<TimeLockPlugin>,
+    pub soulbound: Option<SoulboundPlugin>,
+    pub use_delegate: Option<UseDelegatePlugin>,
+    pub attributes_v2: Option<AttributesV2Plugin>,
+}
+
+/// The lifecycle events an external plugin adapter is registered for, taken from its registry
+/// record.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ExternalPluginAdapterLifecycleChecks {
+    pub key: ExternalPluginAdapterKey,
+    pub lifecycle_checks: Vec<(HookableLifecycleEvent, ExternalCheckResult)>,
 }
 
 #[derive(Debug, Default)]
@@ -184,6 +257,7 @@ pub struct ExternalPluginAdaptersList {
     pub lifecycle_hooks: Vec<LifecycleHook>,
     pub oracles: Vec<Oracle>,
     pub data_stores: Vec<DataStore>,
+    pub lifecycle_checks: Vec<ExternalPluginAdapterLifecycleChecks>,
 }
 
 #[derive(Debug)]
@@ -198,11 +272,14 @@ pub struct Asset {
 pub struct Collection {
     pub base: BaseCollectionV1,
     pub plugin_list: PluginsList,
+    pub external_plugin_adapter_list: ExternalPluginAdaptersList,
     pub plugin_header: Option<PluginHeaderV1>,
 }
 
 /// Registry record that can be used when the plugin type is not known (i.e. a `PluginType` that
 /// is too new for this client to know about).
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize))]
 pub struct RegistryRecordSafe {
     pub plugin_type: u8,
     pub authority: PluginAuthority,
@@ -218,6 +295,8 @@ impl RegistryRecordSafe {
 
 ///ExternalPluginAdapter Registry record that can be used when the plugin type is not known (i.e. a `ExternalPluginAdapterType` that
 /// is too new for this client to know about).
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize))]
 pub struct ExternalRegistryRecordSafe {
     pub plugin_type: u8,
     pub authority: PluginAuthority,
@@ -240,6 +319,8 @@ impl ExternalRegistryRecordSafe {
 /// Plugin registry that an account can safely be deserialized into even if some plugins are
 /// not known.  Note this skips over external plugin adapters for now, and will be updated when those
 /// are defined.
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize))]
 pub struct PluginRegistryV1Safe {
     pub _key: Key,
     pub registry: Vec<RegistryRecordSafe>,
@@ -315,3 +396,40 @@ impl From<&ExternalPluginAdapter> for ExternalPluginAdapterKey {
         }
     }
 }
+
+impl From<&ExternalPluginAdapterInitInfo> for ExternalPluginAdapter {
+    fn from(init_info: &ExternalPluginAdapterInitInfo) -> Self {
+        match init_info {
+            ExternalPluginAdapterInitInfo::LifecycleHook(init_info) => {
+                ExternalPluginAdapter::LifecycleHook(LifecycleHook {
+                    hooked_program: init_info.hooked_program,
+                    extra_accounts: init_info.extra_accounts.clone(),
+                    data_authority: init_info.data_authority.clone(),
+                    schema: init_info
+                        .schema
+                        .clone()
+                        .unwrap_or(ExternalPluginAdapterSchema::Binary),
+                })
+            }
+            ExternalPluginAdapterInitInfo::Oracle(init_info) => {
+                ExternalPluginAdapter::Oracle(Oracle {
+                    base_address: init_info.base_address,
+                    base_address_config: init_info.base_address_config.clone(),
+                    results_offset: init_info
+                        .results_offset
+                        .clone()
+                        .unwrap_or(ValidationResultsOffset::NoOffset),
+                })
+            }
+            ExternalPluginAdapterInitInfo::DataStore(init_info) => {
+                ExternalPluginAdapter::DataStore(DataStore {
+                    data_authority: init_info.data_authority.clone(),
+                    schema: init_info
+                        .schema
+                        .clone()
+                        .unwrap_or(ExternalPluginAdapterSchema::Binary),
+                })
+            }
+        }
+    }
+}
@@ -9,7 +9,20 @@ use crate::{
     PluginRegistryV1Safe,
 };
 
+/// The seed prefix for the asset signer PDA, which is derived from the asset address.
+pub const ASSET_SIGNER_PREFIX: &str = "mpl-core-execute";
+
 impl Asset {
+    /// Find the signer PDA for an asset, which the program signs for in `ExecuteV1`.
+    pub fn find_signer_address(
+        asset: &solana_program::pubkey::Pubkey,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[ASSET_SIGNER_PREFIX.as_bytes(), asset.as_ref()],
+            &crate::MPL_CORE_ID,
+        )
+    }
+
     pub fn deserialize(data: &[u8]) -> Result<Self, std::io::Error> {
         let base = BaseAssetV1::from_bytes(data)?;
         let base_data = base.try_to_vec()?;
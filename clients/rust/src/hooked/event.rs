@@ -0,0 +1,46 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::AnchorDeserialize as CrateDeserialize;
+#[cfg(not(feature = "anchor"))]
+use borsh::BorshDeserialize as CrateDeserialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::types::{MplCoreEvent, MplCoreEventPayloadV1};
+
+/// The tag the program writes before every event in the noop instruction data, so events can be
+/// told apart from the `CompressionProof` that compressed assets write through the same program.
+pub const MPL_CORE_EVENT_TAG: [u8; 8] = *b"mplcevt1";
+
+impl MplCoreEvent {
+    /// Decode an event from the data of the noop instruction the program emitted it in.  Data
+    /// that does not start with `MPL_CORE_EVENT_TAG`, such as a `CompressionProof`, is rejected.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let event_data = data.strip_prefix(&MPL_CORE_EVENT_TAG).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "data is not tagged as an mpl-core event",
+            )
+        })?;
+        Self::try_from_slice(event_data)
+    }
+
+    /// The address of the asset or collection that changed.
+    pub fn asset(&self) -> &Pubkey {
+        match self {
+            MplCoreEvent::V1(event) => &event.asset,
+        }
+    }
+
+    /// The sequence number of the asset after the change, if it is tracked.
+    pub fn seq(&self) -> Option<u64> {
+        match self {
+            MplCoreEvent::V1(event) => event.seq,
+        }
+    }
+
+    /// The change described by a version 1 event.
+    pub fn payload_v1(&self) -> Option<&MplCoreEventPayloadV1> {
+        match self {
+            MplCoreEvent::V1(event) => Some(&event.payload),
+        }
+    }
+}
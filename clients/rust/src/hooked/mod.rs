@@ -10,6 +10,9 @@ pub use asset::*;
 pub mod collection;
 pub use collection::*;
 
+pub mod event;
+pub use event::*;
+
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{
     AnchorDeserialize as CrateDeserialize, AnchorSerialize as CrateSerialize,
@@ -23,7 +26,7 @@ use std::{cmp::Ordering, mem::size_of};
 use crate::{
     accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1, PluginRegistryV1},
     errors::MplCoreError,
-    types::{ExternalCheckResult, Key, Plugin, PluginType, RegistryRecord},
+    types::{ExternalCheckResult, Key, Plugin, PluginAuthority, PluginType, RegistryRecord},
 };
 use solana_program::account_info::AccountInfo;
 
@@ -45,6 +48,37 @@ impl From<&Plugin> for PluginType {
             Plugin::ImmutableMetadata(_) => PluginType::ImmutableMetadata,
             Plugin::VerifiedCreators(_) => PluginType::VerifiedCreators,
             Plugin::Autograph(_) => PluginType::Autograph,
+            Plugin::TimeLock(_) => PluginType::TimeLock,
+            Plugin::Soulbound(_) => PluginType::Soulbound,
+            Plugin::UseDelegate(_) => PluginType::UseDelegate,
+            Plugin::AttributesV2(_) => PluginType::AttributesV2,
+        }
+    }
+}
+
+impl PluginType {
+    /// Get the default authority for a plugin which defines who must allow the plugin to be created.
+    pub fn manager(&self) -> PluginAuthority {
+        match self {
+            PluginType::AddBlocker => PluginAuthority::UpdateAuthority,
+            PluginType::ImmutableMetadata => PluginAuthority::UpdateAuthority,
+            PluginType::Royalties => PluginAuthority::UpdateAuthority,
+            PluginType::FreezeDelegate => PluginAuthority::Owner,
+            PluginType::BurnDelegate => PluginAuthority::Owner,
+            PluginType::TransferDelegate => PluginAuthority::Owner,
+            PluginType::UpdateDelegate => PluginAuthority::UpdateAuthority,
+            PluginType::PermanentFreezeDelegate => PluginAuthority::UpdateAuthority,
+            PluginType::Attributes => PluginAuthority::UpdateAuthority,
+            PluginType::PermanentTransferDelegate => PluginAuthority::UpdateAuthority,
+            PluginType::PermanentBurnDelegate => PluginAuthority::UpdateAuthority,
+            PluginType::Edition => PluginAuthority::UpdateAuthority,
+            PluginType::MasterEdition => PluginAuthority::UpdateAuthority,
+            PluginType::VerifiedCreators => PluginAuthority::UpdateAuthority,
+            PluginType::Autograph => PluginAuthority::Owner,
+            PluginType::TimeLock => PluginAuthority::UpdateAuthority,
+            PluginType::Soulbound => PluginAuthority::None,
+            PluginType::UseDelegate => PluginAuthority::Owner,
+            PluginType::AttributesV2 => PluginAuthority::UpdateAuthority,
         }
     }
 }
@@ -9,15 +9,17 @@ use crate::{
     accounts::{BaseAssetV1, PluginHeaderV1},
     errors::MplCoreError,
     types::{
-        ExternalPluginAdapter, ExternalPluginAdapterType, Plugin, PluginAuthority, PluginType,
-        RegistryRecord,
+        ExternalPluginAdapter, ExternalPluginAdapterKey, ExternalPluginAdapterType,
+        HookableLifecycleEvent, Plugin, PluginAuthority, PluginType, RegistryRecord,
     },
-    AddBlockerPlugin, AttributesPlugin, AutographPlugin, BaseAuthority, BasePlugin,
-    BurnDelegatePlugin, DataBlob, EditionPlugin, ExternalPluginAdaptersList,
-    ExternalRegistryRecordSafe, FreezeDelegatePlugin, ImmutableMetadataPlugin, MasterEditionPlugin,
-    PermanentBurnDelegatePlugin, PermanentFreezeDelegatePlugin, PermanentTransferDelegatePlugin,
-    PluginRegistryV1Safe, PluginsList, RegistryRecordSafe, RoyaltiesPlugin, SolanaAccount,
-    TransferDelegatePlugin, UpdateDelegatePlugin, VerifiedCreatorsPlugin,
+    AddBlockerPlugin, AttributesPlugin, AttributesV2Plugin, AutographPlugin, BaseAuthority,
+    BasePlugin, BurnDelegatePlugin, DataBlob, EditionPlugin, ExternalPluginAdapterLifecycleChecks,
+    ExternalPluginAdaptersList, ExternalRegistryRecordSafe, FreezeDelegatePlugin,
+    ImmutableMetadataPlugin, MasterEditionPlugin, PermanentBurnDelegatePlugin,
+    PermanentFreezeDelegatePlugin, PermanentTransferDelegatePlugin, PluginRegistryV1Safe,
+    PluginsList, RegistryRecordSafe, RoyaltiesPlugin, SolanaAccount, SoulboundPlugin,
+    TimeLockPlugin, TransferDelegatePlugin, UpdateDelegatePlugin, UseDelegatePlugin,
+    VerifiedCreatorsPlugin,
 };
 
 /// Fetch the plugin from the registry.
@@ -216,6 +218,21 @@ pub(crate) fn registry_records_to_plugin_list(
                     Plugin::Autograph(autograph) => {
                         acc.autograph = Some(AutographPlugin { base, autograph })
                     }
+                    Plugin::TimeLock(time_lock) => {
+                        acc.time_lock = Some(TimeLockPlugin { base, time_lock })
+                    }
+                    Plugin::Soulbound(soulbound) => {
+                        acc.soulbound = Some(SoulboundPlugin { base, soulbound })
+                    }
+                    Plugin::UseDelegate(use_delegate) => {
+                        acc.use_delegate = Some(UseDelegatePlugin { base, use_delegate })
+                    }
+                    Plugin::AttributesV2(attributes_v2) => {
+                        acc.attributes_v2 = Some(AttributesV2Plugin {
+                            base,
+                            attributes_v2,
+                        })
+                    }
                 }
             }
             Ok(acc)
@@ -238,6 +255,21 @@ pub(crate) fn registry_records_to_external_plugin_adapter_list(
                     &mut &account_data[record.offset as usize..],
                 )?;
 
+                if let Some(lifecycle_checks) = &record.lifecycle_checks {
+                    acc.lifecycle_checks
+                        .push(ExternalPluginAdapterLifecycleChecks {
+                            key: ExternalPluginAdapterKey::from(&plugin),
+                            // Events too new for this client to know about are dropped.
+                            lifecycle_checks: lifecycle_checks
+                                .iter()
+                                .filter_map(|(event, check_result)| {
+                                    HookableLifecycleEvent::from_u8(*event)
+                                        .map(|event| (event, check_result.clone()))
+                                })
+                                .collect(),
+                        });
+                }
+
                 match plugin {
                     ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
                         acc.lifecycle_hooks.push(lifecycle_hook)
@@ -1,8 +1,8 @@
 #[cfg(feature = "anchor")]
-use anchor_lang::prelude::AnchorDeserialize;
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 use base64::prelude::*;
 #[cfg(not(feature = "anchor"))]
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use num_traits::FromPrimitive;
 use solana_program::pubkey::Pubkey;
 use std::{cmp::Ordering, collections::HashMap, io::ErrorKind};
@@ -140,6 +140,20 @@ pub struct LifecycleChecks {
     pub transfer: Vec<IndexableCheckResult>,
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub burn: Vec<IndexableCheckResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub add_plugin: Vec<IndexableCheckResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub remove_plugin: Vec<IndexableCheckResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub update_plugin: Vec<IndexableCheckResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub approve_plugin_authority: Vec<IndexableCheckResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub revoke_plugin_authority: Vec<IndexableCheckResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub compress: Vec<IndexableCheckResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub decompress: Vec<IndexableCheckResult>,
 }
 
 impl LifecycleChecks {
@@ -148,6 +162,79 @@ impl LifecycleChecks {
             && self.update.is_empty()
             && self.transfer.is_empty()
             && self.burn.is_empty()
+            && self.add_plugin.is_empty()
+            && self.remove_plugin.is_empty()
+            && self.update_plugin.is_empty()
+            && self.approve_plugin_authority.is_empty()
+            && self.revoke_plugin_authority.is_empty()
+            && self.compress.is_empty()
+            && self.decompress.is_empty()
+    }
+
+    // Set the checks for a single lifecycle event.
+    pub(crate) fn set(&mut self, event: HookableLifecycleEvent, checks: Vec<IndexableCheckResult>) {
+        match event {
+            HookableLifecycleEvent::Create => self.create = checks,
+            HookableLifecycleEvent::Update => self.update = checks,
+            HookableLifecycleEvent::Transfer => self.transfer = checks,
+            HookableLifecycleEvent::Burn => self.burn = checks,
+            HookableLifecycleEvent::AddPlugin => self.add_plugin = checks,
+            HookableLifecycleEvent::RemovePlugin => self.remove_plugin = checks,
+            HookableLifecycleEvent::UpdatePlugin => self.update_plugin = checks,
+            HookableLifecycleEvent::ApprovePluginAuthority => {
+                self.approve_plugin_authority = checks
+            }
+            HookableLifecycleEvent::RevokePluginAuthority => self.revoke_plugin_authority = checks,
+            HookableLifecycleEvent::Compress => self.compress = checks,
+            HookableLifecycleEvent::Decompress => self.decompress = checks,
+        }
+    }
+
+    // Convert the known and unknown lifecycle checks back into registry lifecycle checks, with
+    // the known events in `HookableLifecycleEvent` order.
+    fn to_registry_checks(
+        lifecycle_checks: Option<&LifecycleChecks>,
+        unknown_lifecycle_checks: Option<&Vec<(u8, Vec<IndexableCheckResult>)>>,
+    ) -> Option<Vec<(u8, ExternalCheckResult)>> {
+        if lifecycle_checks.is_none() && unknown_lifecycle_checks.is_none() {
+            return None;
+        }
+
+        let mut registry_checks = vec![];
+        if let Some(checks) = lifecycle_checks {
+            for (event, check_results) in [
+                (HookableLifecycleEvent::Create, &checks.create),
+                (HookableLifecycleEvent::Transfer, &checks.transfer),
+                (HookableLifecycleEvent::Burn, &checks.burn),
+                (HookableLifecycleEvent::Update, &checks.update),
+                (HookableLifecycleEvent::AddPlugin, &checks.add_plugin),
+                (HookableLifecycleEvent::RemovePlugin, &checks.remove_plugin),
+                (HookableLifecycleEvent::UpdatePlugin, &checks.update_plugin),
+                (
+                    HookableLifecycleEvent::ApprovePluginAuthority,
+                    &checks.approve_plugin_authority,
+                ),
+                (
+                    HookableLifecycleEvent::RevokePluginAuthority,
+                    &checks.revoke_plugin_authority,
+                ),
+                (HookableLifecycleEvent::Compress, &checks.compress),
+                (HookableLifecycleEvent::Decompress, &checks.decompress),
+            ] {
+                if !check_results.is_empty() {
+                    registry_checks.push((
+                        event as u8,
+                        ExternalCheckResult::from(check_results.as_slice()),
+                    ));
+                }
+            }
+        }
+
+        for (event, check_results) in unknown_lifecycle_checks.into_iter().flatten() {
+            registry_checks.push((*event, ExternalCheckResult::from(check_results.as_slice())));
+        }
+
+        Some(registry_checks)
     }
 }
 
@@ -176,6 +263,20 @@ impl From<ExternalCheckResult> for Vec<IndexableCheckResult> {
     }
 }
 
+impl From<&[IndexableCheckResult]> for ExternalCheckResult {
+    fn from(check_results: &[IndexableCheckResult]) -> Self {
+        let mut check_result_bits = ExternalCheckResultBits::new();
+        for check_result in check_results {
+            match check_result {
+                IndexableCheckResult::CanListen => check_result_bits.set_can_listen(true),
+                IndexableCheckResult::CanApprove => check_result_bits.set_can_approve(true),
+                IndexableCheckResult::CanReject => check_result_bits.set_can_reject(true),
+            }
+        }
+        ExternalCheckResult::from(check_result_bits)
+    }
+}
+
 struct ExternalPluginDataInfo<'a> {
     data_offset: u64,
     data_len: u64,
@@ -200,14 +301,7 @@ impl ProcessedExternalPlugin {
             for (event, check) in checks {
                 let checks = Vec::<IndexableCheckResult>::from(check);
                 match HookableLifecycleEvent::from_u8(event) {
-                    Some(val) => match val {
-                        HookableLifecycleEvent::Create => known_lifecycle_checks.create = checks,
-                        HookableLifecycleEvent::Update => known_lifecycle_checks.update = checks,
-                        HookableLifecycleEvent::Transfer => {
-                            known_lifecycle_checks.transfer = checks
-                        }
-                        HookableLifecycleEvent::Burn => known_lifecycle_checks.burn = checks,
-                    },
+                    Some(event) => known_lifecycle_checks.set(event, checks),
                     None => unknown_lifecycle_checks.push((event, checks)),
                 }
             }
@@ -309,6 +403,29 @@ impl ProcessedExternalPlugin {
             }
         }
     }
+
+    // The inverse of `convert_data_to_string`, checked against the length of the original data.
+    // `MsgPack` data is re-encoded from its JSON form unless it fell back to base64.
+    fn convert_string_to_data(
+        schema: &ExternalPluginAdapterSchema,
+        data: &str,
+        data_len: u64,
+    ) -> Result<Vec<u8>, std::io::Error> {
+        let decode_base64 = || BASE64_STANDARD.decode(data).ok();
+        let data_slice = match schema {
+            ExternalPluginAdapterSchema::Binary => decode_base64(),
+            ExternalPluginAdapterSchema::Json => Some(data.as_bytes().to_vec()),
+            ExternalPluginAdapterSchema::MsgPack => serde_json::from_str::<serde_json::Value>(data)
+                .ok()
+                .and_then(|json_val| rmp_serde::encode::to_vec(&json_val).ok())
+                .filter(|data_slice| data_slice.len() as u64 == data_len)
+                .or_else(decode_base64),
+        };
+
+        data_slice
+            .filter(|data_slice| data_slice.len() as u64 == data_len)
+            .ok_or_else(|| ErrorKind::InvalidData.into())
+    }
 }
 
 /// A type used to store both Core Assets and Core Collections for indexing.
@@ -385,6 +502,18 @@ impl IndexableAsset {
         }
     }
 
+    /// Get the user holding the usage rights granted by a `UseDelegate` plugin, if the rental is
+    /// still active at the given unix timestamp.
+    pub fn current_user(&self, unix_timestamp: i64) -> Option<Pubkey> {
+        match self.plugins.get(&PluginType::UseDelegate) {
+            Some(IndexablePluginSchemaV1 {
+                data: Plugin::UseDelegate(use_delegate),
+                ..
+            }) if unix_timestamp < use_delegate.expiry => Some(use_delegate.user),
+            _ => None,
+        }
+    }
+
     // Add a processed plugin to the correct `IndexableAsset` struct member.
     fn add_processed_plugin(&mut self, plugin: ProcessedPlugin) {
         match plugin {
@@ -473,6 +602,178 @@ impl IndexableAsset {
         Ok(())
     }
 
+    /// Rebuild the raw account data of the `Asset` or `Collection`, the inverse of `fetch`.  The
+    /// account is an `Asset` if `owner` is set, and a non-zero `seq` is written as its sequence
+    /// number.
+    ///
+    /// Lifecycle checks are written in `HookableLifecycleEvent` order followed by unknown events.
+    /// An account whose plugins were all removed keeps an empty plugin registry, which is not
+    /// represented in an `IndexableAsset` and so is not rebuilt.
+    pub fn to_account_data(&self) -> Result<Vec<u8>, std::io::Error> {
+        let (base_data, base_size) = match self.owner {
+            Some(owner) => {
+                let asset = BaseAssetV1 {
+                    key: Key::AssetV1,
+                    owner,
+                    update_authority: self.update_authority.clone(),
+                    name: self.name.clone(),
+                    uri: self.uri.clone(),
+                    seq: (self.seq != 0).then_some(self.seq),
+                };
+                (asset.try_to_vec()?, asset.get_size())
+            }
+            None => {
+                let update_authority = match self.update_authority {
+                    UpdateAuthority::Address(address) => address,
+                    _ => return Err(ErrorKind::InvalidInput.into()),
+                };
+                let collection = BaseCollectionV1 {
+                    key: Key::CollectionV1,
+                    update_authority,
+                    name: self.name.clone(),
+                    uri: self.uri.clone(),
+                    num_minted: self.num_minted.ok_or(ErrorKind::InvalidInput)?,
+                    current_size: self.current_size.ok_or(ErrorKind::InvalidInput)?,
+                };
+                (collection.try_to_vec()?, collection.get_size())
+            }
+        };
+
+        if self.plugins.is_empty()
+            && self.unknown_plugins.is_empty()
+            && self.external_plugins.is_empty()
+            && self.unknown_external_plugins.is_empty()
+        {
+            return Ok(base_data);
+        }
+
+        // Collect the registry records and the sections of data they point to.
+        let mut registry = vec![];
+        let mut external_registry = vec![];
+        let mut sections = vec![];
+
+        for plugin in self.plugins.values() {
+            registry.push(RegistryRecordSafe {
+                plugin_type: PluginType::from(&plugin.data) as u8,
+                authority: plugin.authority.clone(),
+                offset: plugin.offset,
+            });
+            sections.push((plugin.offset, plugin.data.try_to_vec()?));
+        }
+
+        for plugin in &self.unknown_plugins {
+            registry.push(RegistryRecordSafe {
+                plugin_type: plugin.r#type,
+                authority: plugin.authority.clone(),
+                offset: plugin.offset,
+            });
+            sections.push((
+                plugin.offset,
+                BASE64_STANDARD
+                    .decode(&plugin.data)
+                    .map_err(|_| ErrorKind::InvalidData)?,
+            ));
+        }
+
+        for plugin in &self.external_plugins {
+            external_registry.push(ExternalRegistryRecordSafe {
+                plugin_type: plugin.r#type.clone() as u8,
+                authority: plugin.authority.clone(),
+                lifecycle_checks: LifecycleChecks::to_registry_checks(
+                    plugin.lifecycle_checks.as_ref(),
+                    plugin.unknown_lifecycle_checks.as_ref(),
+                ),
+                offset: plugin.offset,
+                data_offset: plugin.data_offset,
+                data_len: plugin.data_len,
+            });
+            sections.push((plugin.offset, plugin.adapter_config.try_to_vec()?));
+
+            if let (Some(data_offset), Some(data_len)) = (plugin.data_offset, plugin.data_len) {
+                let schema = match &plugin.adapter_config {
+                    ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => &lifecycle_hook.schema,
+                    ExternalPluginAdapter::DataStore(data_store) => &data_store.schema,
+                    _ => &ExternalPluginAdapterSchema::Binary,
+                };
+                let data = ProcessedExternalPlugin::convert_string_to_data(
+                    schema,
+                    plugin.data.as_deref().unwrap_or_default(),
+                    data_len,
+                )?;
+                sections.push((data_offset, data));
+            }
+        }
+
+        for plugin in &self.unknown_external_plugins {
+            external_registry.push(ExternalRegistryRecordSafe {
+                plugin_type: plugin.r#type,
+                authority: plugin.authority.clone(),
+                lifecycle_checks: LifecycleChecks::to_registry_checks(
+                    plugin.lifecycle_checks.as_ref(),
+                    plugin.unknown_lifecycle_checks.as_ref(),
+                ),
+                offset: plugin.offset,
+                data_offset: plugin.data_offset,
+                data_len: plugin.data_len,
+            });
+            sections.push((
+                plugin.offset,
+                BASE64_STANDARD
+                    .decode(&plugin.unknown_adapter_config)
+                    .map_err(|_| ErrorKind::InvalidData)?,
+            ));
+
+            if let (Some(data_offset), Some(data_len)) = (plugin.data_offset, plugin.data_len) {
+                // We don't know the schema so the data is base64.
+                let data = ProcessedExternalPlugin::convert_string_to_data(
+                    &ExternalPluginAdapterSchema::Binary,
+                    plugin.data.as_deref().unwrap_or_default(),
+                    data_len,
+                )?;
+                sections.push((data_offset, data));
+            }
+        }
+
+        // Lay out the plugin header followed by the sections, which must not overlap.  The plugin
+        // registry starts where the last section ends.
+        let mut header = PluginHeaderV1 {
+            key: Key::PluginHeaderV1,
+            plugin_registry_offset: 0,
+        };
+        let mut plugin_registry_offset = (base_size + header.try_to_vec()?.len()) as u64;
+
+        // Empty data sections take no space and may share an offset with the next section.
+        sections.retain(|(_, data)| !data.is_empty());
+        sections.sort_by_key(|(offset, _)| *offset);
+        for (offset, data) in &sections {
+            if *offset < plugin_registry_offset {
+                return Err(ErrorKind::InvalidData.into());
+            }
+            plugin_registry_offset = offset + data.len() as u64;
+        }
+        header.plugin_registry_offset = plugin_registry_offset;
+
+        registry.sort_by(RegistryRecordSafe::compare_offsets);
+        external_registry.sort_by(ExternalRegistryRecordSafe::compare_offsets);
+        let plugin_registry = PluginRegistryV1Safe {
+            _key: Key::PluginRegistryV1,
+            registry,
+            external_registry,
+        };
+
+        let mut account = vec![0; plugin_registry_offset as usize];
+        account[..base_data.len()].copy_from_slice(&base_data);
+        let header_data = header.try_to_vec()?;
+        account[base_size..base_size + header_data.len()].copy_from_slice(&header_data);
+        for (offset, data) in sections {
+            let offset = offset as usize;
+            account[offset..offset + data.len()].copy_from_slice(&data);
+        }
+        account.extend_from_slice(&plugin_registry.try_to_vec()?);
+
+        Ok(account)
+    }
+
     /// Fetch the base `Asset`` or `Collection`` and all the plugins and store in an
     /// `IndexableAsset`.
     pub fn fetch(key: Key, account: &[u8]) -> Result<Self, std::io::Error> {
@@ -1,10 +1,18 @@
+mod account_diff;
+mod account_size;
+mod extra_accounts;
 mod generated;
 mod hooked;
 mod indexable_asset;
+mod permissions;
 
+pub use account_diff::*;
+pub use account_size::*;
+pub use extra_accounts::*;
 pub use generated::programs::MPL_CORE_ID as ID;
 pub use generated::*;
 pub use hooked::*;
 pub use indexable_asset::*;
+pub use permissions::*;
 
 impl Copy for generated::types::Key {}
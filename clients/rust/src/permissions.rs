@@ -0,0 +1,581 @@
+use solana_program::{pubkey::Pubkey, system_program};
+
+use crate::{
+    errors::MplCoreError,
+    types::{
+        ExternalPluginAdapterKey, LockExpiry, PluginAuthority, PluginType, RuleSet, UpdateAuthority,
+    },
+    Asset, BasePlugin, Collection, ExternalPluginAdaptersList, PluginsList, MPL_CORE_ID,
+};
+
+/// A lifecycle action whose permissions can be simulated.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LifecycleAction {
+    /// Transfer the asset.
+    Transfer,
+    /// Burn the asset.
+    Burn,
+    /// Update the asset.
+    Update,
+}
+
+/// The outcome of a simulated lifecycle action, matching the program's final decision.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PermissionOutcome {
+    /// The action is approved.
+    Approved,
+    /// The action is rejected, which the program reports as `InvalidAuthority`.
+    Rejected,
+    /// Nothing approved the action, which the program reports as `NoApprovals`.
+    NoApprovals,
+}
+
+/// The part of the asset or collection that decided a simulated lifecycle action.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PermissionSource {
+    /// The asset itself, e.g. the owner approving a transfer.
+    Asset,
+    /// The collection itself, e.g. the update authority approving an update.
+    Collection,
+    /// A plugin on the asset.
+    AssetPlugin(PluginType),
+    /// A plugin on the collection.
+    CollectionPlugin(PluginType),
+}
+
+/// The result of simulating a lifecycle action.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PermissionSimulation {
+    pub outcome: PermissionOutcome,
+    /// The source responsible for the outcome: the first rejection, the plugin that force
+    /// approved, or the first approval.  `None` when nothing approved the action.
+    pub responsible: Option<PermissionSource>,
+    /// External plugin adapters on the asset and its collection that may also approve or reject
+    /// the action.  Their results depend on accounts outside the asset so they are not simulated.
+    pub unsimulated_adapters: Vec<ExternalPluginAdapterKey>,
+}
+
+/// The result of a single validation, mirroring the program's `ValidationResult`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Validation {
+    Approved,
+    Rejected,
+    Pass,
+    ForceApproved,
+}
+
+/// Off-chain evaluator of the lifecycle checks the program runs in `validate_asset_permissions`.
+/// The collection must be the collection of the asset, as it is not deserialized with its address.
+///
+/// The simulator needs the cluster clock for time based plugins.  It defaults to a zero clock,
+/// which treats every time lock and rental as still active.
+pub struct PermissionSimulator<'a> {
+    asset: &'a Asset,
+    collection: Option<&'a Collection>,
+    authority: Pubkey,
+    additional_signers: Vec<Pubkey>,
+    authority_program: Pubkey,
+    new_owner_program: Pubkey,
+    invoking_program: Pubkey,
+    unix_timestamp: i64,
+    slot: u64,
+}
+
+impl<'a> PermissionSimulator<'a> {
+    pub fn new(asset: &'a Asset, collection: Option<&'a Collection>, authority: Pubkey) -> Self {
+        Self {
+            asset,
+            collection,
+            authority,
+            additional_signers: vec![],
+            authority_program: system_program::ID,
+            new_owner_program: system_program::ID,
+            invoking_program: MPL_CORE_ID,
+            unix_timestamp: 0,
+            slot: 0,
+        }
+    }
+
+    /// Other signers of the transaction, used to satisfy multisig authorities.
+    #[inline(always)]
+    pub fn additional_signers(&mut self, additional_signers: Vec<Pubkey>) -> &mut Self {
+        self.additional_signers = additional_signers;
+        self
+    }
+
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The program owning the authority account, checked by program royalties rule sets.
+    #[inline(always)]
+    pub fn authority_program(&mut self, authority_program: Pubkey) -> &mut Self {
+        self.authority_program = authority_program;
+        self
+    }
+
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The program owning the new owner account, checked by program royalties rule sets.
+    #[inline(always)]
+    pub fn new_owner_program(&mut self, new_owner_program: Pubkey) -> &mut Self {
+        self.new_owner_program = new_owner_program;
+        self
+    }
+
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The program of the top-level instruction, checked by invoking program royalties rule sets.
+    #[inline(always)]
+    pub fn invoking_program(&mut self, invoking_program: Pubkey) -> &mut Self {
+        self.invoking_program = invoking_program;
+        self
+    }
+
+    /// The cluster clock the action is expected to execute at.
+    #[inline(always)]
+    pub fn clock(&mut self, unix_timestamp: i64, slot: u64) -> &mut Self {
+        self.unix_timestamp = unix_timestamp;
+        self.slot = slot;
+        self
+    }
+
+    /// Simulate the lifecycle checks for `action`.  Errors are the program errors the action
+    /// fails with before any plugin is evaluated.
+    pub fn simulate(&self, action: LifecycleAction) -> Result<PermissionSimulation, MplCoreError> {
+        // If the asset is part of a collection, the collection must be passed in.
+        match (&self.asset.base.update_authority, self.collection) {
+            (UpdateAuthority::Collection(_), None) => return Err(MplCoreError::MissingCollection),
+            (UpdateAuthority::Collection(_), Some(_)) | (_, None) => (),
+            (_, Some(_)) => return Err(MplCoreError::InvalidCollection),
+        }
+
+        let resolved_authorities = self.resolve_authorities();
+
+        let mut approved: Option<PermissionSource> = None;
+        let mut rejected: Option<PermissionSource> = None;
+        let mut record = |source: PermissionSource, validation: Validation| match validation {
+            Validation::Approved => {
+                approved.get_or_insert(source);
+                None
+            }
+            Validation::Rejected => {
+                rejected.get_or_insert(source);
+                None
+            }
+            Validation::Pass => None,
+            Validation::ForceApproved => Some(source),
+        };
+
+        // Do the core validation.
+        if let Some(forced) = record(PermissionSource::Asset, self.validate_asset(action)) {
+            return Ok(self.finish(PermissionOutcome::Approved, Some(forced)));
+        }
+        if let Some(collection) = self.collection {
+            if action == LifecycleAction::Update {
+                let validation = if self.authority == collection.base.update_authority {
+                    Validation::Approved
+                } else {
+                    Validation::Pass
+                };
+                record(PermissionSource::Collection, validation);
+            }
+        }
+
+        // Plugins on the asset override the collection plugins of the same type, and the
+        // collection plugins are validated first.
+        let checks = self.plugin_checks();
+        for (is_asset, plugin_type, authority, plugin_list) in checks
+            .iter()
+            .filter(|(is_asset, ..)| !is_asset)
+            .chain(checks.iter().filter(|(is_asset, ..)| *is_asset))
+        {
+            let source = if *is_asset {
+                PermissionSource::AssetPlugin(plugin_type.clone())
+            } else {
+                PermissionSource::CollectionPlugin(plugin_type.clone())
+            };
+            let validation = self.validate_plugin(
+                action,
+                plugin_type,
+                authority,
+                plugin_list,
+                &resolved_authorities,
+            );
+            if let Some(forced) = record(source, validation) {
+                return Ok(self.finish(PermissionOutcome::Approved, Some(forced)));
+            }
+        }
+
+        Ok(match (rejected, approved) {
+            (Some(source), _) => self.finish(PermissionOutcome::Rejected, Some(source)),
+            (None, Some(source)) => self.finish(PermissionOutcome::Approved, Some(source)),
+            (None, None) => self.finish(PermissionOutcome::NoApprovals, None),
+        })
+    }
+
+    fn finish(
+        &self,
+        outcome: PermissionOutcome,
+        responsible: Option<PermissionSource>,
+    ) -> PermissionSimulation {
+        // An adapter on the asset replaces the collection adapter with the same key, so each key
+        // is listed once.
+        let mut unsimulated_adapters: Vec<ExternalPluginAdapterKey> = vec![];
+        let collection_keys = self
+            .collection
+            .map(|collection| validated_adapter_keys(&collection.external_plugin_adapter_list))
+            .unwrap_or_default();
+        let asset_keys = validated_adapter_keys(&self.asset.external_plugin_adapter_list);
+        for key in collection_keys.into_iter().chain(asset_keys) {
+            if !unsimulated_adapters.contains(&key) {
+                unsimulated_adapters.push(key);
+            }
+        }
+
+        PermissionSimulation {
+            outcome,
+            responsible,
+            unsimulated_adapters,
+        }
+    }
+
+    /// Resolve the authority to the plugin authorities it holds, the same way the program does
+    /// in `resolve_pubkey_to_authorities` and `resolve_multisig_authorities`.
+    fn resolve_authorities(&self) -> Vec<PluginAuthority> {
+        let mut authorities = Vec::with_capacity(3);
+        if self.authority == self.asset.base.owner {
+            authorities.push(PluginAuthority::Owner);
+        }
+
+        match (&self.asset.base.update_authority, self.collection) {
+            (UpdateAuthority::Address(address), _) if *address == self.authority => {
+                authorities.push(PluginAuthority::UpdateAuthority)
+            }
+            (UpdateAuthority::Collection(_), Some(collection))
+                if collection.base.update_authority == self.authority =>
+            {
+                authorities.push(PluginAuthority::UpdateAuthority)
+            }
+            _ => (),
+        }
+
+        authorities.push(PluginAuthority::Address {
+            address: self.authority,
+        });
+
+        for (_, _, authority, _) in self.plugin_records() {
+            if self.is_multisig_satisfied(&authority) && !authorities.contains(&authority) {
+                authorities.push(authority);
+            }
+        }
+
+        authorities
+    }
+
+    /// All plugin records of the collection and the asset, collection first.
+    fn plugin_records(&self) -> Vec<(bool, PluginType, PluginAuthority, &PluginsList)> {
+        let collection_records = self
+            .collection
+            .map(|collection| plugin_authorities(&collection.plugin_list))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(plugin_type, authority)| {
+                (
+                    false,
+                    plugin_type,
+                    authority,
+                    &self.collection.unwrap().plugin_list,
+                )
+            });
+        let asset_records = plugin_authorities(&self.asset.plugin_list).into_iter().map(
+            |(plugin_type, authority)| (true, plugin_type, authority, &self.asset.plugin_list),
+        );
+
+        collection_records.chain(asset_records).collect()
+    }
+
+    /// The plugin records to validate, ordered by plugin type, with asset plugins replacing
+    /// collection plugins of the same type.
+    fn plugin_checks(&self) -> Vec<(bool, PluginType, PluginAuthority, &PluginsList)> {
+        let mut checks: Vec<(bool, PluginType, PluginAuthority, &PluginsList)> = vec![];
+        for record in self.plugin_records() {
+            match checks.iter_mut().find(|check| check.1 == record.1) {
+                Some(check) => *check = record,
+                None => checks.push(record),
+            }
+        }
+        checks.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        checks
+    }
+
+    fn validate_asset(&self, action: LifecycleAction) -> Validation {
+        let approver = match action {
+            LifecycleAction::Transfer | LifecycleAction::Burn => self.asset.base.owner,
+            LifecycleAction::Update => match self.asset.base.update_authority {
+                UpdateAuthority::None => Pubkey::default(),
+                UpdateAuthority::Address(address) | UpdateAuthority::Collection(address) => address,
+            },
+        };
+
+        if self.authority == approver {
+            Validation::Approved
+        } else {
+            Validation::Pass
+        }
+    }
+
+    /// Validate a plugin for the action.  Plugins that the program does not check for the
+    /// action always pass.
+    fn validate_plugin(
+        &self,
+        action: LifecycleAction,
+        plugin_type: &PluginType,
+        authority: &PluginAuthority,
+        plugin_list: &PluginsList,
+        resolved_authorities: &[PluginAuthority],
+    ) -> Validation {
+        let reject_if = |condition: bool| {
+            if condition {
+                Validation::Rejected
+            } else {
+                Validation::Pass
+            }
+        };
+        let approve_if = |condition: bool| {
+            if condition {
+                Validation::Approved
+            } else {
+                Validation::Pass
+            }
+        };
+        let force_approve_if = |condition: bool| {
+            if condition {
+                Validation::ForceApproved
+            } else {
+                Validation::Pass
+            }
+        };
+        let is_transfer = action == LifecycleAction::Transfer;
+        let is_burn = action == LifecycleAction::Burn;
+
+        match plugin_type {
+            PluginType::Royalties if is_transfer => {
+                let royalties = &plugin_list.royalties.as_ref().unwrap().royalties;
+                match &royalties.rule_set {
+                    RuleSet::None => Validation::Pass,
+                    RuleSet::ProgramAllowList(allow_list) => reject_if(
+                        !allow_list.contains(&self.authority_program)
+                            || !allow_list.contains(&self.new_owner_program),
+                    ),
+                    RuleSet::ProgramDenyList(deny_list) => reject_if(
+                        deny_list.contains(&self.authority_program)
+                            || deny_list.contains(&self.new_owner_program),
+                    ),
+                    RuleSet::InvokingProgramAllowList(allow_list) => {
+                        reject_if(!allow_list.contains(&self.invoking_program))
+                    }
+                    RuleSet::InvokingProgramDenyList(deny_list) => {
+                        reject_if(deny_list.contains(&self.invoking_program))
+                    }
+                }
+            }
+            PluginType::FreezeDelegate if is_transfer || is_burn => reject_if(
+                plugin_list
+                    .freeze_delegate
+                    .as_ref()
+                    .unwrap()
+                    .freeze_delegate
+                    .frozen,
+            ),
+            PluginType::PermanentFreezeDelegate if is_transfer || is_burn => reject_if(
+                plugin_list
+                    .permanent_freeze_delegate
+                    .as_ref()
+                    .unwrap()
+                    .permanent_freeze_delegate
+                    .frozen,
+            ),
+            PluginType::TransferDelegate if is_transfer => {
+                approve_if(self.is_self_authority_signer(authority))
+            }
+            PluginType::BurnDelegate if is_burn => {
+                approve_if(self.is_self_authority_signer(authority))
+            }
+            PluginType::PermanentTransferDelegate if is_transfer => {
+                force_approve_if(resolved_authorities.contains(authority))
+            }
+            PluginType::PermanentBurnDelegate if is_burn => {
+                force_approve_if(resolved_authorities.contains(authority))
+            }
+            PluginType::TimeLock if is_transfer || is_burn => {
+                let time_lock = &plugin_list.time_lock.as_ref().unwrap().time_lock;
+                reject_if(match time_lock.expiry {
+                    LockExpiry::UnixTimestamp(timestamp) => self.unix_timestamp < timestamp,
+                    LockExpiry::Slot(slot) => self.slot < slot,
+                })
+            }
+            PluginType::Soulbound if is_transfer => {
+                // The owner can never act as the recovery authority.
+                if *authority != PluginAuthority::Owner && resolved_authorities.contains(authority)
+                {
+                    Validation::Approved
+                } else {
+                    Validation::Rejected
+                }
+            }
+            PluginType::UseDelegate if is_transfer || is_burn => {
+                let use_delegate = &plugin_list.use_delegate.as_ref().unwrap().use_delegate;
+                reject_if(self.unix_timestamp < use_delegate.expiry)
+            }
+            PluginType::ImmutableMetadata if action == LifecycleAction::Update => {
+                Validation::Rejected
+            }
+            PluginType::UpdateDelegate if action == LifecycleAction::Update => {
+                let update_delegate = &plugin_list
+                    .update_delegate
+                    .as_ref()
+                    .unwrap()
+                    .update_delegate;
+                approve_if(
+                    self.is_self_authority_signer(authority)
+                        || update_delegate
+                            .additional_delegates
+                            .contains(&self.authority),
+                )
+            }
+            _ => Validation::Pass,
+        }
+    }
+
+    fn is_self_authority_signer(&self, authority: &PluginAuthority) -> bool {
+        match authority {
+            PluginAuthority::Address { address } => *address == self.authority,
+            PluginAuthority::Multisig { .. } => self.is_multisig_satisfied(authority),
+            _ => false,
+        }
+    }
+
+    fn is_multisig_satisfied(&self, authority: &PluginAuthority) -> bool {
+        match authority {
+            PluginAuthority::Multisig { threshold, signers } => {
+                let signed = signers
+                    .iter()
+                    .filter(|signer| {
+                        **signer == self.authority || self.additional_signers.contains(signer)
+                    })
+                    .count();
+                *threshold > 0 && signed >= usize::from(*threshold)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// List the keys of the external plugin adapters that can validate lifecycle actions.
+fn validated_adapter_keys(adapters: &ExternalPluginAdaptersList) -> Vec<ExternalPluginAdapterKey> {
+    adapters
+        .lifecycle_hooks
+        .iter()
+        .map(|hook| ExternalPluginAdapterKey::LifecycleHook(hook.hooked_program))
+        .chain(
+            adapters
+                .oracles
+                .iter()
+                .map(|oracle| ExternalPluginAdapterKey::Oracle(oracle.base_address)),
+        )
+        .collect()
+}
+
+/// List the type and authority of every plugin in a plugin list, in plugin type order.
+fn plugin_authorities(plugin_list: &PluginsList) -> Vec<(PluginType, PluginAuthority)> {
+    let bases: [(PluginType, Option<&BasePlugin>); 19] = [
+        (
+            PluginType::Royalties,
+            plugin_list.royalties.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::FreezeDelegate,
+            plugin_list.freeze_delegate.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::BurnDelegate,
+            plugin_list.burn_delegate.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::TransferDelegate,
+            plugin_list.transfer_delegate.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::UpdateDelegate,
+            plugin_list.update_delegate.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::PermanentFreezeDelegate,
+            plugin_list
+                .permanent_freeze_delegate
+                .as_ref()
+                .map(|p| &p.base),
+        ),
+        (
+            PluginType::Attributes,
+            plugin_list.attributes.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::PermanentTransferDelegate,
+            plugin_list
+                .permanent_transfer_delegate
+                .as_ref()
+                .map(|p| &p.base),
+        ),
+        (
+            PluginType::PermanentBurnDelegate,
+            plugin_list
+                .permanent_burn_delegate
+                .as_ref()
+                .map(|p| &p.base),
+        ),
+        (
+            PluginType::Edition,
+            plugin_list.edition.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::MasterEdition,
+            plugin_list.master_edition.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::AddBlocker,
+            plugin_list.add_blocker.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::ImmutableMetadata,
+            plugin_list.immutable_metadata.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::VerifiedCreators,
+            plugin_list.verified_creators.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::Autograph,
+            plugin_list.autograph.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::TimeLock,
+            plugin_list.time_lock.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::Soulbound,
+            plugin_list.soulbound.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::UseDelegate,
+            plugin_list.use_delegate.as_ref().map(|p| &p.base),
+        ),
+        (
+            PluginType::AttributesV2,
+            plugin_list.attributes_v2.as_ref().map(|p| &p.base),
+        ),
+    ];
+
+    bases
+        .into_iter()
+        .filter_map(|(plugin_type, base)| {
+            base.map(|base| (plugin_type, PluginAuthority::from(&base.authority)))
+        })
+        .collect()
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{AddPluginV1Builder, WriteExternalPluginAdapterDataV1Builder},
+    types::{
+        DataStoreInitInfo, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey,
+        ExternalPluginAdapterSchema, FreezeDelegate, Key, Plugin, PluginAuthority, PluginType,
+    },
+    Asset, ExternalPluginAdapterChange, IndexableAsset, PluginChange, ValueChange,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn fetch_account(context: &mut ProgramTestContext, address: Pubkey) -> Account {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found")
+}
+
+#[tokio::test]
+async fn diff_asset_versions() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::DataStore(
+                DataStoreInitInfo {
+                    init_plugin_authority: None,
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    schema: Some(ExternalPluginAdapterSchema::Json),
+                },
+            )],
+        },
+    )
+    .await
+    .unwrap();
+
+    let old_account = fetch_account(&mut context, asset.pubkey()).await;
+
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+        .instruction();
+    let write_ix = WriteExternalPluginAdapterDataV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .key(ExternalPluginAdapterKey::DataStore(
+            PluginAuthority::UpdateAuthority,
+        ))
+        .data(br#"{"level":2}"#.to_vec())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix, write_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let new_account = fetch_account(&mut context, asset.pubkey()).await;
+
+    let expected_plugin_change = PluginChange::Added {
+        authority: PluginAuthority::Owner,
+        plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+    };
+
+    // The indexable asset also carries the data of the data store.
+    let old_indexable_asset = IndexableAsset::fetch(Key::AssetV1, &old_account.data).unwrap();
+    let new_indexable_asset = IndexableAsset::fetch(Key::AssetV1, &new_account.data).unwrap();
+    let diff = old_indexable_asset.diff(&new_indexable_asset);
+
+    assert_eq!(diff.owner, None);
+    assert_eq!(diff.plugins.len(), 1);
+    assert_eq!(
+        diff.plugins.get(&PluginType::FreezeDelegate),
+        Some(&expected_plugin_change)
+    );
+    assert_eq!(diff.external_plugin_adapters.len(), 1);
+    let (key, change) = &diff.external_plugin_adapters[0];
+    assert_eq!(
+        *key,
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority)
+    );
+    match change {
+        ExternalPluginAdapterChange::Updated(update) => {
+            assert_eq!(
+                update.data,
+                Some(ValueChange {
+                    old: Some(String::new()),
+                    new: Some(r#"{"level":2}"#.to_string()),
+                })
+            );
+            assert_eq!(update.adapter, None);
+            assert_eq!(update.authority, None);
+            assert_eq!(update.lifecycle_checks, None);
+        }
+        _ => panic!("expected the data store to be updated"),
+    }
+    assert!(new_indexable_asset.diff(&new_indexable_asset).is_empty());
+
+    // The asset does not carry adapter data, so only the plugin change is reported.
+    let old_asset = Asset::from_bytes(&old_account.data).unwrap();
+    let new_asset = Asset::from_bytes(&new_account.data).unwrap();
+    let diff = old_asset.diff(&new_asset);
+
+    assert_eq!(diff.plugins.len(), 1);
+    assert_eq!(
+        diff.plugins.get(&PluginType::FreezeDelegate),
+        Some(&expected_plugin_change)
+    );
+    assert!(diff.external_plugin_adapters.is_empty());
+
+    let diff = new_asset.diff(&old_asset);
+    assert!(matches!(
+        diff.plugins.get(&PluginType::FreezeDelegate),
+        Some(PluginChange::Removed { .. })
+    ));
+}
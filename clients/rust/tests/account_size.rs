@@ -0,0 +1,231 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshSerialize;
+use mpl_core::{
+    accounts::{BaseAssetV1, PluginHeaderV1, PluginRegistryV1},
+    errors::MplCoreError,
+    estimate_add_plugin, estimate_create_asset, estimate_remove_external_plugin_adapter,
+    estimate_remove_plugin,
+    instructions::{AddPluginV1Builder, RemovePluginV1Builder},
+    types::{
+        Attribute, Attributes, BurnDelegate, DataStoreInitInfo, ExternalPluginAdapterInitInfo,
+        ExternalPluginAdapterKey, FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair,
+        PluginType,
+    },
+    DataBlob, COLLECT_AMOUNT,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn fetch_account(context: &mut ProgramTestContext, address: Pubkey) -> Account {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found")
+}
+
+#[tokio::test]
+async fn estimate_asset_size_and_rent() {
+    let mut context = program_test().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let name = "Estimated Asset".to_string();
+    let uri = "https://example.com/estimated".to_string();
+    let plugins = vec![
+        PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes {
+                attribute_list: vec![Attribute {
+                    key: "color".to_string(),
+                    value: "blue".to_string(),
+                }],
+            }),
+            authority: None,
+        },
+        PluginAuthorityPair {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+            authority: Some(PluginAuthority::Address {
+                address: Pubkey::new_unique(),
+            }),
+        },
+    ];
+    let external_plugin_adapters = vec![ExternalPluginAdapterInitInfo::DataStore(
+        DataStoreInitInfo {
+            data_authority: PluginAuthority::UpdateAuthority,
+            init_plugin_authority: None,
+            schema: None,
+        },
+    )];
+
+    let estimate =
+        estimate_create_asset(&name, &uri, &plugins, &external_plugin_adapters, &rent).unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: Some(name),
+            uri: Some(uri),
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins,
+            external_plugin_adapters,
+        },
+    )
+    .await
+    .unwrap();
+
+    let account = fetch_account(&mut context, asset.pubkey()).await;
+    assert_eq!(account.data.len(), estimate.size);
+    assert_eq!(account.lamports, estimate.lamports);
+    assert_eq!(
+        account.lamports,
+        rent.minimum_balance(account.data.len()) + COLLECT_AMOUNT
+    );
+
+    // Adding a plugin.
+    let delta = estimate_add_plugin(
+        &account.data,
+        &Plugin::BurnDelegate(BurnDelegate {}),
+        None,
+        &rent,
+    )
+    .unwrap();
+
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::BurnDelegate(BurnDelegate {}))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let added = fetch_account(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        added.data.len() as i64 - account.data.len() as i64,
+        delta.size
+    );
+    assert_eq!(
+        added.lamports as i64 - account.lamports as i64,
+        delta.lamports
+    );
+
+    // Removing a plugin.
+    let delta = estimate_remove_plugin(&added.data, &PluginType::Attributes, &rent).unwrap();
+
+    let remove_plugin_ix = RemovePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::Attributes)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[remove_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let removed = fetch_account(&mut context, asset.pubkey()).await;
+    assert_eq!(
+        removed.data.len() as i64 - added.data.len() as i64,
+        delta.size
+    );
+    assert_eq!(
+        removed.lamports as i64 - added.lamports as i64,
+        delta.lamports
+    );
+}
+
+#[tokio::test]
+async fn estimate_with_out_of_range_offsets() {
+    let mut context = program_test().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::DataStore(
+                DataStoreInitInfo {
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    init_plugin_authority: None,
+                    schema: None,
+                },
+            )],
+        },
+    )
+    .await
+    .unwrap();
+
+    let account = fetch_account(&mut context, asset.pubkey()).await;
+    let asset_size = BaseAssetV1::from_bytes(&account.data).unwrap().get_size();
+    let header = PluginHeaderV1::from_bytes(&account.data[asset_size..]).unwrap();
+    let registry_offset = header.plugin_registry_offset as usize;
+
+    // Point every plugin past the end of the account.
+    let mut registry = PluginRegistryV1::from_bytes(&account.data[registry_offset..]).unwrap();
+    let out_of_range = account.data.len() as u64 + 1;
+    for record in registry.registry.iter_mut() {
+        record.offset = out_of_range;
+    }
+    for record in registry.external_registry.iter_mut() {
+        record.offset = out_of_range;
+    }
+    let mut data = account.data[..registry_offset].to_vec();
+    data.extend(registry.try_to_vec().unwrap());
+
+    assert_eq!(
+        estimate_remove_plugin(&data, &PluginType::FreezeDelegate, &rent).unwrap_err(),
+        MplCoreError::DeserializationError
+    );
+    assert_eq!(
+        estimate_remove_external_plugin_adapter(
+            &data,
+            &ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority),
+            &rent,
+        )
+        .unwrap_err(),
+        MplCoreError::DeserializationError
+    );
+
+    // Truncate the account before the plugin registry.
+    assert_eq!(
+        estimate_remove_plugin(
+            &account.data[..registry_offset],
+            &PluginType::FreezeDelegate,
+            &rent
+        )
+        .unwrap_err(),
+        MplCoreError::DeserializationError
+    );
+}
@@ -2,14 +2,12 @@
 pub mod setup;
 use mpl_core::{
     errors::MplCoreError,
-    instructions::{
-        AddCollectionExternalPluginAdapterV1Builder, AddExternalPluginAdapterV1Builder,
-    },
+    instructions::AddExternalPluginAdapterV1Builder,
     types::{
         DataStore, DataStoreInitInfo, ExternalCheckResult, ExternalPluginAdapter,
-        ExternalPluginAdapterInitInfo, ExternalPluginAdapterSchema, HookableLifecycleEvent,
-        LifecycleHook, LifecycleHookInitInfo, Oracle, OracleInitInfo, PluginAuthority,
-        UpdateAuthority, ValidationResultsOffset,
+        ExternalPluginAdapterInitInfo, ExternalPluginAdapterSchema, ExtraAccount,
+        HookableLifecycleEvent, LifecycleHook, LifecycleHookInitInfo, Oracle, OracleInitInfo,
+        PluginAuthority, UpdateAuthority, ValidationResultsOffset,
     },
 };
 pub use setup::*;
@@ -19,7 +17,6 @@ use solana_program_test::tokio;
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
 
 #[tokio::test]
-#[ignore]
 async fn test_add_lifecycle_hook() {
     let mut context = program_test().start_with_context().await;
 
@@ -107,7 +104,6 @@ async fn test_add_lifecycle_hook() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_cannot_add_lifecycle_hook_with_duplicate_lifecycle_checks() {
     let mut context = program_test().start_with_context().await;
 
@@ -202,7 +198,7 @@ async fn test_cannot_add_lifecycle_hook_with_duplicate_lifecycle_checks() {
 }
 
 #[tokio::test]
-async fn test_temporarily_cannot_add_lifecycle_hook() {
+async fn test_cannot_add_lifecycle_hook_with_signer_extra_account() {
     let mut context = program_test().start_with_context().await;
 
     let asset = Keypair::new();
@@ -252,7 +248,11 @@ async fn test_temporarily_cannot_add_lifecycle_hook() {
                     HookableLifecycleEvent::Transfer,
                     ExternalCheckResult { flags: 1 },
                 )],
-                extra_accounts: None,
+                extra_accounts: Some(vec![ExtraAccount::Address {
+                    address: owner,
+                    is_signer: true,
+                    is_writable: true,
+                }]),
                 data_authority: Some(PluginAuthority::UpdateAuthority),
                 schema: None,
             },
@@ -272,7 +272,7 @@ async fn test_temporarily_cannot_add_lifecycle_hook() {
         .await
         .unwrap_err();
 
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidPluginSetting);
 
     assert_asset(
         &mut context,
@@ -289,77 +289,6 @@ async fn test_temporarily_cannot_add_lifecycle_hook() {
     .await;
 }
 
-#[tokio::test]
-async fn test_temporarily_cannot_add_lifecycle_hook_on_collection() {
-    let mut context = program_test().start_with_context().await;
-
-    let collection = Keypair::new();
-    create_collection(
-        &mut context,
-        CreateCollectionHelperArgs {
-            collection: &collection,
-            update_authority: None,
-            payer: None,
-            name: None,
-            uri: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![],
-        },
-    )
-    .await
-    .unwrap();
-
-    let update_authority = context.payer.pubkey();
-    assert_collection(
-        &mut context,
-        AssertCollectionHelperArgs {
-            collection: collection.pubkey(),
-            update_authority,
-            name: None,
-            uri: None,
-            num_minted: 0,
-            current_size: 0,
-            plugins: vec![],
-        },
-    )
-    .await;
-
-    let add_external_plugin_adapter_ix = AddCollectionExternalPluginAdapterV1Builder::new()
-        .collection(collection.pubkey())
-        .payer(context.payer.pubkey())
-        .init_info(ExternalPluginAdapterInitInfo::LifecycleHook(
-            LifecycleHookInitInfo {
-                hooked_program: pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
-                init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                lifecycle_checks: vec![(
-                    HookableLifecycleEvent::Transfer,
-                    ExternalCheckResult { flags: 1 },
-                )],
-                extra_accounts: None,
-                data_authority: Some(PluginAuthority::UpdateAuthority),
-                schema: None,
-            },
-        ))
-        .instruction();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[add_external_plugin_adapter_ix],
-        Some(&context.payer.pubkey()),
-        &[&context.payer],
-        context.last_blockhash,
-    );
-
-    let error = context
-        .banks_client
-        .process_transaction(tx)
-        .await
-        .unwrap_err();
-
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
-
-    // TODO add collection assert.
-}
-
 #[tokio::test]
 async fn test_add_oracle() {
     let mut context = program_test().start_with_context().await;
@@ -535,7 +464,6 @@ async fn test_cannot_add_oracle_with_duplicate_lifecycle_checks() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_add_data_store() {
     let mut context = program_test().start_with_context().await;
 
@@ -614,153 +542,6 @@ async fn test_add_data_store() {
     .await;
 }
 
-#[tokio::test]
-async fn test_temporarily_cannot_add_data_store() {
-    let mut context = program_test().start_with_context().await;
-
-    let asset = Keypair::new();
-    create_asset(
-        &mut context,
-        CreateAssetHelperArgs {
-            owner: None,
-            payer: None,
-            asset: &asset,
-            data_state: None,
-            name: None,
-            uri: None,
-            authority: None,
-            update_authority: None,
-            collection: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![],
-        },
-    )
-    .await
-    .unwrap();
-
-    let owner = context.payer.pubkey();
-    let update_authority = context.payer.pubkey();
-    assert_asset(
-        &mut context,
-        AssertAssetHelperArgs {
-            asset: asset.pubkey(),
-            owner,
-            update_authority: Some(UpdateAuthority::Address(update_authority)),
-            name: None,
-            uri: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![],
-        },
-    )
-    .await;
-
-    let add_external_plugin_adapter_ix = AddExternalPluginAdapterV1Builder::new()
-        .asset(asset.pubkey())
-        .payer(context.payer.pubkey())
-        .init_info(ExternalPluginAdapterInitInfo::DataStore(
-            DataStoreInitInfo {
-                init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                data_authority: PluginAuthority::UpdateAuthority,
-                schema: None,
-            },
-        ))
-        .instruction();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[add_external_plugin_adapter_ix],
-        Some(&context.payer.pubkey()),
-        &[&context.payer],
-        context.last_blockhash,
-    );
-
-    let error = context
-        .banks_client
-        .process_transaction(tx)
-        .await
-        .unwrap_err();
-
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
-
-    assert_asset(
-        &mut context,
-        AssertAssetHelperArgs {
-            asset: asset.pubkey(),
-            owner,
-            update_authority: Some(UpdateAuthority::Address(update_authority)),
-            name: None,
-            uri: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![],
-        },
-    )
-    .await;
-}
-
-#[tokio::test]
-async fn test_temporarily_cannot_add_data_store_on_collection() {
-    let mut context = program_test().start_with_context().await;
-
-    let collection = Keypair::new();
-    create_collection(
-        &mut context,
-        CreateCollectionHelperArgs {
-            collection: &collection,
-            update_authority: None,
-            payer: None,
-            name: None,
-            uri: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![],
-        },
-    )
-    .await
-    .unwrap();
-
-    let update_authority = context.payer.pubkey();
-    assert_collection(
-        &mut context,
-        AssertCollectionHelperArgs {
-            collection: collection.pubkey(),
-            update_authority,
-            name: None,
-            uri: None,
-            num_minted: 0,
-            current_size: 0,
-            plugins: vec![],
-        },
-    )
-    .await;
-
-    let add_external_plugin_adapter_ix = AddCollectionExternalPluginAdapterV1Builder::new()
-        .collection(collection.pubkey())
-        .payer(context.payer.pubkey())
-        .init_info(ExternalPluginAdapterInitInfo::DataStore(
-            DataStoreInitInfo {
-                init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                data_authority: PluginAuthority::UpdateAuthority,
-                schema: None,
-            },
-        ))
-        .instruction();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[add_external_plugin_adapter_ix],
-        Some(&context.payer.pubkey()),
-        &[&context.payer],
-        context.last_blockhash,
-    );
-
-    let error = context
-        .banks_client
-        .process_transaction(tx)
-        .await
-        .unwrap_err();
-
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
-
-    // TODO: add collection assert.
-}
-
 #[tokio::test]
 async fn test_cannot_add_duplicate_external_plugin_adapter() {
     let mut context = program_test().start_with_context().await;
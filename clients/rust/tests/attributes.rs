@@ -0,0 +1,220 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use borsh::BorshSerialize;
+use mpl_core::{
+    errors::MplCoreError,
+    fetch_plugins,
+    instructions::UpsertAttributeV1Builder,
+    types::{
+        Attribute, AttributeKeyAuthority, Attributes, AttributesV2, FreezeDelegate, Plugin,
+        PluginAuthority, PluginAuthorityPair, PluginType,
+    },
+    Asset,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_asset_with_attributes(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    attributes: Plugin,
+) -> Result<(), BanksClientError> {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: attributes,
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: None,
+                },
+            ],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+}
+
+async fn upsert_attribute(context: &mut ProgramTestContext, asset: Pubkey, key: &str, value: &str) {
+    let ix = UpsertAttributeV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .key(key.to_string())
+        .value(value.to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn get_asset_data(context: &mut ProgramTestContext, asset: Pubkey) -> Vec<u8> {
+    context
+        .banks_client
+        .get_account(asset)
+        .await
+        .expect("get_account")
+        .expect("asset account not found")
+        .data
+}
+
+#[tokio::test]
+async fn attributes_use_original_layout() {
+    let mut context = program_test().start_with_context().await;
+
+    let attribute_list = vec![Attribute {
+        key: "level".to_string(),
+        value: "1".to_string(),
+    }];
+    let asset = Keypair::new();
+    create_asset_with_attributes(
+        &mut context,
+        &asset,
+        Plugin::Attributes(Attributes {
+            attribute_list: attribute_list.clone(),
+        }),
+    )
+    .await
+    .unwrap();
+
+    // The plugin data holds only the attribute list.
+    let data = get_asset_data(&mut context, asset.pubkey()).await;
+    let records = fetch_plugins(&data).unwrap();
+    let offset_of = |plugin_type: PluginType| {
+        records
+            .iter()
+            .find(|record| record.plugin_type == plugin_type)
+            .unwrap()
+            .offset as usize
+    };
+    let original_layout = attribute_list.try_to_vec().unwrap();
+    let plugin_data_offset = offset_of(PluginType::Attributes) + 1;
+    assert_eq!(
+        offset_of(PluginType::FreezeDelegate),
+        plugin_data_offset + original_layout.len()
+    );
+    assert_eq!(
+        &data[plugin_data_offset..plugin_data_offset + original_layout.len()],
+        original_layout.as_slice()
+    );
+
+    // The program and client read and update plugins in that layout.
+    upsert_attribute(&mut context, asset.pubkey(), "name", "hero").await;
+
+    let data = get_asset_data(&mut context, asset.pubkey()).await;
+    let asset = Asset::from_bytes(&data).unwrap();
+    assert_eq!(
+        asset.plugin_list.attributes.unwrap().attributes,
+        Attributes {
+            attribute_list: vec![
+                Attribute {
+                    key: "level".to_string(),
+                    value: "1".to_string(),
+                },
+                Attribute {
+                    key: "name".to_string(),
+                    value: "hero".to_string(),
+                },
+            ],
+        }
+    );
+    assert_eq!(
+        asset.plugin_list.freeze_delegate.unwrap().freeze_delegate,
+        FreezeDelegate { frozen: false }
+    );
+}
+
+#[tokio::test]
+async fn attributes_v2_round_trip() {
+    let mut context = program_test().start_with_context().await;
+
+    let key_authorities = vec![AttributeKeyAuthority {
+        key: "name".to_string(),
+        authority: PluginAuthority::UpdateAuthority,
+    }];
+    let asset = Keypair::new();
+    create_asset_with_attributes(
+        &mut context,
+        &asset,
+        Plugin::AttributesV2(AttributesV2 {
+            attribute_list: vec![Attribute {
+                key: "level".to_string(),
+                value: "1".to_string(),
+            }],
+            key_authorities: key_authorities.clone(),
+        }),
+    )
+    .await
+    .unwrap();
+
+    upsert_attribute(&mut context, asset.pubkey(), "name", "hero").await;
+
+    let data = get_asset_data(&mut context, asset.pubkey()).await;
+    let asset = Asset::from_bytes(&data).unwrap();
+    assert_eq!(
+        asset.plugin_list.attributes_v2.unwrap().attributes_v2,
+        AttributesV2 {
+            attribute_list: vec![
+                Attribute {
+                    key: "level".to_string(),
+                    value: "1".to_string(),
+                },
+                Attribute {
+                    key: "name".to_string(),
+                    value: "hero".to_string(),
+                },
+            ],
+            key_authorities,
+        }
+    );
+    assert_eq!(
+        asset.plugin_list.freeze_delegate.unwrap().freeze_delegate,
+        FreezeDelegate { frozen: false }
+    );
+}
+
+#[tokio::test]
+async fn attributes_v2_rejects_multisig_key_authority_with_duplicate_signers() {
+    let mut context = program_test().start_with_context().await;
+
+    let signer = Pubkey::new_unique();
+    let asset = Keypair::new();
+    let error = create_asset_with_attributes(
+        &mut context,
+        &asset,
+        Plugin::AttributesV2(AttributesV2 {
+            attribute_list: vec![],
+            key_authorities: vec![AttributeKeyAuthority {
+                key: "name".to_string(),
+                authority: PluginAuthority::Multisig {
+                    threshold: 2,
+                    signers: vec![signer, signer],
+                },
+            }],
+        }),
+    )
+    .await
+    .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidMultisigAuthority);
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::CollectBuilder,
+    types::{
+        Attribute, AttributeKeyAuthority, AttributesV2, Creator, FreezeDelegate, Plugin,
+        PluginAuthority, PluginAuthorityPair, Royalties, RuleSet,
+    },
+};
+pub use setup::*;
+
+use solana_program::{pubkey, pubkey::Pubkey, rent::Rent};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::AccountMeta, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+const RECIPIENT1: Pubkey = pubkey!("8AT6o8Qk5T9QnZvPThMrF9bcCQLTGkyGvVZZzHgCw11v");
+const RECIPIENT2: Pubkey = pubkey!("MmHsqX4LxTfifxoH8BVRLUKrwDn1LPCac6YcCZTHhwt");
+const COLLECT_AMOUNT: u64 = 1_500_000;
+
+async fn collect(context: &mut ProgramTestContext, account: Pubkey) {
+    let ix = CollectBuilder::new()
+        .recipient1(RECIPIENT1)
+        .recipient2(RECIPIENT2)
+        .add_remaining_account(AccountMeta::new(account, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn get_lamports(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context
+        .banks_client
+        .get_balance(address)
+        .await
+        .expect("get_balance")
+}
+
+#[tokio::test]
+async fn collect_twice_from_asset_with_plugins() {
+    let mut context = program_test().start_with_context().await;
+    airdrop(&mut context, &RECIPIENT1, 100_000_000)
+        .await
+        .unwrap();
+    airdrop(&mut context, &RECIPIENT2, 100_000_000)
+        .await
+        .unwrap();
+
+    let asset = Keypair::new();
+    let creator = context.payer.pubkey();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Royalties(Royalties {
+                        basis_points: 500,
+                        creators: vec![Creator {
+                            address: creator,
+                            percentage: 100,
+                        }],
+                        rule_set: RuleSet::None,
+                        royalty_payments: false,
+                    }),
+                    authority: Some(PluginAuthority::Address { address: creator }),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::AttributesV2(AttributesV2 {
+                        attribute_list: vec![Attribute {
+                            key: "level".to_string(),
+                            value: "1".to_string(),
+                        }],
+                        key_authorities: vec![AttributeKeyAuthority {
+                            key: "level".to_string(),
+                            authority: PluginAuthority::Owner,
+                        }],
+                    }),
+                    authority: None,
+                },
+            ],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let data_len = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found")
+        .data
+        .len();
+    let asset_rent = Rent::minimum_balance(&rent, data_len);
+    assert_eq!(
+        get_lamports(&mut context, asset.pubkey()).await,
+        asset_rent + COLLECT_AMOUNT
+    );
+
+    let recipients_before =
+        get_lamports(&mut context, RECIPIENT1).await + get_lamports(&mut context, RECIPIENT2).await;
+
+    // The first collect takes only the fee and leaves the asset rent exempt.
+    collect(&mut context, asset.pubkey()).await;
+    assert_eq!(get_lamports(&mut context, asset.pubkey()).await, asset_rent);
+    let recipients_after =
+        get_lamports(&mut context, RECIPIENT1).await + get_lamports(&mut context, RECIPIENT2).await;
+    assert_eq!(recipients_after, recipients_before + COLLECT_AMOUNT);
+
+    // Collecting again has nothing left to take.
+    context.warp_to_slot(2).unwrap();
+    collect(&mut context, asset.pubkey()).await;
+    assert_eq!(get_lamports(&mut context, asset.pubkey()).await, asset_rent);
+    assert_eq!(
+        get_lamports(&mut context, RECIPIENT1).await + get_lamports(&mut context, RECIPIENT2).await,
+        recipients_after
+    );
+}
@@ -1,10 +1,15 @@
 #![cfg(feature = "test-sbf")]
 pub mod setup;
-use mpl_core::types::{Creator, Plugin, PluginAuthority, PluginAuthorityPair, Royalties, RuleSet};
+use mpl_core::{
+    instructions::CreateCollectionV2Builder,
+    types::{Creator, Plugin, PluginAuthority, PluginAuthorityPair, Royalties, RuleSet},
+};
 pub use setup::*;
 
 use solana_program_test::tokio;
-use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer, transaction::Transaction,
+};
 
 #[tokio::test]
 async fn test_create_collection() {
@@ -104,6 +109,7 @@ async fn create_collection_with_plugins() {
                         percentage: 100,
                     }],
                     rule_set: RuleSet::ProgramDenyList(vec![]),
+                    royalty_payments: false,
                 }),
             }],
             external_plugin_adapters: vec![],
@@ -130,6 +136,7 @@ async fn create_collection_with_plugins() {
                         percentage: 100,
                     }],
                     rule_set: RuleSet::ProgramDenyList(vec![]),
+                    royalty_payments: false,
                 }),
             }],
         },
@@ -203,6 +210,7 @@ async fn create_collection_with_plugins_with_different_plugin_authority() {
                         percentage: 100,
                     }],
                     rule_set: RuleSet::ProgramDenyList(vec![]),
+                    royalty_payments: false,
                 }),
             }],
             external_plugin_adapters: vec![],
@@ -231,9 +239,48 @@ async fn create_collection_with_plugins_with_different_plugin_authority() {
                         percentage: 100,
                     }],
                     rule_set: RuleSet::ProgramDenyList(vec![]),
+                    royalty_payments: false,
                 }),
             }],
         },
     )
     .await;
 }
+
+#[tokio::test]
+async fn create_collection_without_log_wrapper_account() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let mut create_ix = CreateCollectionV2Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Collection".to_owned())
+        .uri("https://example.com/collection".to_owned())
+        .instruction();
+    // Callers built before the log wrapper account was added only pass the first four accounts.
+    create_ix.accounts.truncate(4);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &collection],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let update_authority = context.payer.pubkey();
+    assert_collection(
+        &mut context,
+        AssertCollectionHelperArgs {
+            collection: collection.pubkey(),
+            update_authority,
+            name: None,
+            uri: None,
+            num_minted: 0,
+            current_size: 0,
+            plugins: vec![],
+        },
+    )
+    .await;
+}
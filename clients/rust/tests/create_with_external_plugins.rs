@@ -1,22 +1,27 @@
 #![cfg(feature = "test-sbf")]
 pub mod setup;
+use borsh::BorshSerialize;
 use mpl_core::{
     errors::MplCoreError,
+    instructions::CreateV2Builder,
     types::{
         DataStore, DataStoreInitInfo, ExternalCheckResult, ExternalPluginAdapter,
-        ExternalPluginAdapterInitInfo, ExternalPluginAdapterSchema, HookableLifecycleEvent,
-        LifecycleHook, LifecycleHookInitInfo, Oracle, OracleInitInfo, PluginAuthority,
-        UpdateAuthority, ValidationResultsOffset,
+        ExternalPluginAdapterInitInfo, ExternalPluginAdapterSchema, ExternalValidationResult,
+        ExtraAccount, HookableLifecycleEvent, LifecycleHook, LifecycleHookInitInfo, Oracle,
+        OracleInitInfo, OracleValidation, PluginAuthority, UpdateAuthority,
+        ValidationResultsOffset,
     },
+    CreateExtraAccountInputs, MPL_CORE_PREFIX,
 };
 pub use setup::*;
 
-use solana_program::pubkey;
+use solana_program::{instruction::AccountMeta, pubkey};
 use solana_program_test::tokio;
-use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_sdk::{
+    account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction,
+};
 
 #[tokio::test]
-#[ignore]
 async fn test_create_lifecycle_hook() {
     let mut context = program_test().start_with_context().await;
 
@@ -75,7 +80,6 @@ async fn test_create_lifecycle_hook() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_cannot_create_lifecycle_hook_with_duplicate_lifecycle_checks() {
     let mut context = program_test().start_with_context().await;
 
@@ -120,80 +124,6 @@ async fn test_cannot_create_lifecycle_hook_with_duplicate_lifecycle_checks() {
     assert_custom_instruction_error!(0, error, MplCoreError::DuplicateLifecycleChecks);
 }
 
-#[tokio::test]
-async fn test_temporarily_cannot_create_lifecycle_hook() {
-    let mut context = program_test().start_with_context().await;
-
-    let asset = Keypair::new();
-    let error = create_asset(
-        &mut context,
-        CreateAssetHelperArgs {
-            owner: None,
-            payer: None,
-            asset: &asset,
-            data_state: None,
-            name: None,
-            uri: None,
-            authority: None,
-            update_authority: None,
-            collection: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::LifecycleHook(
-                LifecycleHookInitInfo {
-                    hooked_program: pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
-                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                    lifecycle_checks: vec![(
-                        HookableLifecycleEvent::Transfer,
-                        ExternalCheckResult { flags: 1 },
-                    )],
-                    extra_accounts: None,
-                    data_authority: Some(PluginAuthority::UpdateAuthority),
-                    schema: None,
-                },
-            )],
-        },
-    )
-    .await
-    .unwrap_err();
-
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
-}
-
-#[tokio::test]
-async fn test_temporarily_cannot_create_lifecycle_hook_on_collection() {
-    let mut context = program_test().start_with_context().await;
-
-    let collection = Keypair::new();
-    let error = create_collection(
-        &mut context,
-        CreateCollectionHelperArgs {
-            collection: &collection,
-            update_authority: None,
-            payer: None,
-            name: None,
-            uri: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::LifecycleHook(
-                LifecycleHookInitInfo {
-                    hooked_program: pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
-                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                    lifecycle_checks: vec![(
-                        HookableLifecycleEvent::Transfer,
-                        ExternalCheckResult { flags: 1 },
-                    )],
-                    extra_accounts: None,
-                    data_authority: Some(PluginAuthority::UpdateAuthority),
-                    schema: None,
-                },
-            )],
-        },
-    )
-    .await
-    .unwrap_err();
-
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
-}
-
 #[tokio::test]
 async fn test_create_oracle() {
     let mut context = program_test().start_with_context().await;
@@ -291,7 +221,6 @@ async fn test_cannot_create_oracle_with_duplicate_lifecycle_checks() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_create_data_store() {
     let mut context = program_test().start_with_context().await;
 
@@ -342,63 +271,102 @@ async fn test_create_data_store() {
 }
 
 #[tokio::test]
-async fn test_temporarily_cannot_create_data_store() {
+async fn test_create_with_oracle_pda_extra_account() {
     let mut context = program_test().start_with_context().await;
 
+    let oracle_program = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    // Set up the oracle account at the PDA derived from the owner of the new asset.
+    let (oracle_account, _) = Pubkey::find_program_address(
+        &[MPL_CORE_PREFIX.as_bytes(), owner.as_ref()],
+        &oracle_program,
+    );
+    let oracle_data = OracleValidation::V1 {
+        create: ExternalValidationResult::Approved,
+        transfer: ExternalValidationResult::Pass,
+        burn: ExternalValidationResult::Pass,
+        update: ExternalValidationResult::Pass,
+    }
+    .try_to_vec()
+    .unwrap();
+    context.set_account(
+        &oracle_account,
+        &Account {
+            lamports: 1_000_000_000,
+            data: oracle_data,
+            owner: oracle_program,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let external_plugin_adapters = vec![ExternalPluginAdapterInitInfo::Oracle(OracleInitInfo {
+        base_address: oracle_program,
+        init_plugin_authority: None,
+        lifecycle_checks: vec![(
+            HookableLifecycleEvent::Create,
+            ExternalCheckResult { flags: 4 },
+        )],
+        base_address_config: Some(ExtraAccount::PreconfiguredOwner {
+            is_signer: false,
+            is_writable: false,
+        }),
+        results_offset: None,
+    })];
+
     let asset = Keypair::new();
-    let error = create_asset(
-        &mut context,
-        CreateAssetHelperArgs {
-            owner: None,
-            payer: None,
-            asset: &asset,
-            data_state: None,
-            name: None,
-            uri: None,
-            authority: None,
-            update_authority: None,
-            collection: None,
-            plugins: vec![],
-            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::DataStore(
-                DataStoreInitInfo {
-                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                    data_authority: PluginAuthority::UpdateAuthority,
-                    schema: None,
-                },
-            )],
-        },
-    )
-    .await
-    .unwrap_err();
+    let inputs = CreateExtraAccountInputs {
+        asset: asset.pubkey(),
+        owner,
+        collection: None,
+        external_plugin_adapters: &external_plugin_adapters,
+    };
+    assert_eq!(
+        mpl_core::find_create_extra_accounts(&inputs).unwrap(),
+        vec![AccountMeta::new_readonly(oracle_account, false)]
+    );
 
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
-}
+    let create_ix = CreateV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .owner(Some(owner))
+        .name("Oracle Asset".to_owned())
+        .uri("https://example.com/oracle-asset".to_owned())
+        .external_plugin_adapters(external_plugin_adapters.clone())
+        .add_extra_accounts(&inputs)
+        .unwrap()
+        .instruction();
 
-#[tokio::test]
-async fn test_temporarily_cannot_create_data_store_on_collection() {
-    let mut context = program_test().start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
 
-    let collection = Keypair::new();
-    let error = create_collection(
+    let update_authority = context.payer.pubkey();
+    assert_asset(
         &mut context,
-        CreateCollectionHelperArgs {
-            collection: &collection,
-            update_authority: None,
-            payer: None,
-            name: None,
-            uri: None,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner,
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: Some("Oracle Asset".to_owned()),
+            uri: Some("https://example.com/oracle-asset".to_owned()),
             plugins: vec![],
-            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::DataStore(
-                DataStoreInitInfo {
-                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
-                    data_authority: PluginAuthority::UpdateAuthority,
-                    schema: None,
-                },
-            )],
+            external_plugin_adapters: vec![ExternalPluginAdapter::Oracle(Oracle {
+                base_address: oracle_program,
+                base_address_config: Some(ExtraAccount::PreconfiguredOwner {
+                    is_signer: false,
+                    is_writable: false,
+                }),
+                results_offset: ValidationResultsOffset::NoOffset,
+            })],
         },
     )
-    .await
-    .unwrap_err();
-
-    assert_custom_instruction_error!(0, error, MplCoreError::NotAvailable);
+    .await;
 }
@@ -0,0 +1,275 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use borsh::BorshSerialize;
+use mpl_core::{
+    instructions::{
+        AddPluginV1Builder, CreateCollectionV2Builder, ExecuteV1Builder, TransferV1Builder,
+    },
+    types::{
+        FreezeDelegate, MplCoreEvent, MplCoreEventPayloadV1, MplCoreEventV1, Plugin,
+        PluginAuthority, PluginType, UpdateAuthority,
+    },
+    Asset, MPL_CORE_EVENT_TAG,
+};
+pub use setup::*;
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg, pubkey,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
+use solana_program_test::{processor, tokio, ProgramTestContext};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+
+const SPL_NOOP_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+const EVENT_LOG_PREFIX: &str = "Program log: event: ";
+
+// Stands in for the log wrapper, logging the instruction data it is invoked with so the test can
+// read the events back from the transaction logs.
+fn log_noop(_program_id: &Pubkey, _accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    msg!("event: {}", BASE64.encode(data));
+    Ok(())
+}
+
+async fn start_with_log_wrapper() -> ProgramTestContext {
+    let mut program_test = program_test();
+    // Use the logging processor even when the real noop program binary is available.
+    program_test.prefer_bpf(false);
+    program_test.add_program("spl_noop", SPL_NOOP_ID, processor!(log_noop));
+    program_test.start_with_context().await
+}
+
+// Send the instruction and decode the events it emitted through the log wrapper.
+async fn process_and_decode_events(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Vec<MplCoreEvent> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    result
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .filter_map(|log| log.strip_prefix(EVENT_LOG_PREFIX))
+        .map(|data| MplCoreEvent::from_bytes(&BASE64.decode(data).unwrap()).unwrap())
+        .collect()
+}
+
+#[test]
+fn decode_event() {
+    let asset = Pubkey::new_unique();
+    let events = vec![
+        MplCoreEvent::V1(MplCoreEventV1 {
+            asset,
+            seq: None,
+            payload: MplCoreEventPayloadV1::Create {
+                owner: Pubkey::new_unique(),
+                update_authority: UpdateAuthority::Address(Pubkey::new_unique()),
+            },
+        }),
+        MplCoreEvent::V1(MplCoreEventV1 {
+            asset,
+            seq: Some(2),
+            payload: MplCoreEventPayloadV1::AddPlugin {
+                plugin_type: PluginType::FreezeDelegate,
+                authority: PluginAuthority::Owner,
+            },
+        }),
+        MplCoreEvent::V1(MplCoreEventV1 {
+            asset,
+            seq: Some(3),
+            payload: MplCoreEventPayloadV1::Burn,
+        }),
+    ];
+
+    for event in events {
+        let mut data = MPL_CORE_EVENT_TAG.to_vec();
+        event.serialize(&mut data).unwrap();
+        let decoded = MplCoreEvent::from_bytes(&data).unwrap();
+
+        assert_eq!(decoded, event);
+        assert_eq!(*decoded.asset(), asset);
+        assert_eq!(decoded.seq(), event.seq());
+        assert!(decoded.payload_v1().is_some());
+
+        // Untagged data, such as a compression proof, is not decoded as an event.
+        assert!(MplCoreEvent::from_bytes(&event.try_to_vec().unwrap()).is_err());
+    }
+
+    assert!(MplCoreEvent::from_bytes(&[1]).is_err());
+    assert!(MplCoreEvent::from_bytes(&MPL_CORE_EVENT_TAG).is_err());
+}
+
+#[tokio::test]
+async fn decode_emitted_events() {
+    let mut context = start_with_log_wrapper().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+        .log_wrapper(Some(SPL_NOOP_ID))
+        .instruction();
+
+    let events = process_and_decode_events(&mut context, add_plugin_ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![MplCoreEvent::V1(MplCoreEventV1 {
+            asset: asset.pubkey(),
+            seq: None,
+            payload: MplCoreEventPayloadV1::AddPlugin {
+                plugin_type: PluginType::FreezeDelegate,
+                authority: PluginAuthority::Owner,
+            },
+        })]
+    );
+
+    let new_owner = Pubkey::new_unique();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .log_wrapper(Some(SPL_NOOP_ID))
+        .instruction();
+
+    let events = process_and_decode_events(&mut context, transfer_ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![MplCoreEvent::V1(MplCoreEventV1 {
+            asset: asset.pubkey(),
+            seq: None,
+            payload: MplCoreEventPayloadV1::Transfer { new_owner },
+        })]
+    );
+}
+
+#[tokio::test]
+async fn decode_create_collection_event() {
+    let mut context = start_with_log_wrapper().await;
+
+    let collection = Keypair::new();
+    let update_authority = Pubkey::new_unique();
+    let create_collection_ix = CreateCollectionV2Builder::new()
+        .collection(collection.pubkey())
+        .update_authority(Some(update_authority))
+        .payer(context.payer.pubkey())
+        .name("Test Collection".to_owned())
+        .uri("https://example.com/collection".to_owned())
+        .log_wrapper(Some(SPL_NOOP_ID))
+        .instruction();
+
+    let events =
+        process_and_decode_events(&mut context, create_collection_ix, &[&collection]).await;
+    assert_eq!(
+        events,
+        vec![MplCoreEvent::V1(MplCoreEventV1 {
+            asset: collection.pubkey(),
+            seq: None,
+            payload: MplCoreEventPayloadV1::CreateCollection { update_authority },
+        })]
+    );
+}
+
+#[tokio::test]
+async fn decode_execute_event() {
+    let mut context = start_with_log_wrapper().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (asset_signer, _) = Asset::find_signer_address(&asset.pubkey());
+    context.set_account(
+        &asset_signer,
+        &Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let transfer_ix =
+        system_instruction::transfer(&asset_signer, &context.payer.pubkey(), 100_000_000);
+    let execute_ix = ExecuteV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey())
+        .program_id(transfer_ix.program_id)
+        .instruction_data(transfer_ix.data)
+        .log_wrapper(Some(SPL_NOOP_ID))
+        .add_remaining_account(AccountMeta::new(asset_signer, false))
+        .add_remaining_account(AccountMeta::new(context.payer.pubkey(), false))
+        .instruction();
+
+    let events = process_and_decode_events(&mut context, execute_ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![MplCoreEvent::V1(MplCoreEventV1 {
+            asset: asset.pubkey(),
+            seq: None,
+            payload: MplCoreEventPayloadV1::Execute {
+                program_id: system_program::ID,
+            },
+        })]
+    );
+}
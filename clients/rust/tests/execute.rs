@@ -0,0 +1,181 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{errors::MplCoreError, instructions::ExecuteV1Builder, Asset};
+pub use setup::*;
+
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey, system_instruction};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn execute_sol_transfer(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    collection: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let (asset_signer, _) = Asset::find_signer_address(&asset);
+    context.set_account(
+        &asset_signer,
+        &Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let transfer_ix =
+        system_instruction::transfer(&asset_signer, &context.payer.pubkey(), 100_000_000);
+    let execute_ix = ExecuteV1Builder::new()
+        .asset(asset)
+        .collection(collection)
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey())
+        .program_id(transfer_ix.program_id)
+        .instruction_data(transfer_ix.data)
+        .add_remaining_account(AccountMeta::new(asset_signer, false))
+        .add_remaining_account(AccountMeta::new(context.payer.pubkey(), false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+// Copies an account to `address` under a different program owner.
+async fn set_foreign_owned_copy(context: &mut ProgramTestContext, from: Pubkey, address: Pubkey) {
+    let account = context
+        .banks_client
+        .get_account(from)
+        .await
+        .expect("get_account")
+        .expect("account not found");
+
+    context.set_account(
+        &address,
+        &Account {
+            owner: Pubkey::new_unique(),
+            ..account
+        }
+        .into(),
+    );
+}
+
+#[tokio::test]
+async fn execute_as_asset_signer() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    execute_sol_transfer(&mut context, asset.pubkey(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn cannot_execute_with_foreign_owned_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // An account with valid asset data naming the payer as owner, but not owned by the program.
+    let forged_asset = Pubkey::new_unique();
+    set_foreign_owned_copy(&mut context, asset.pubkey(), forged_asset).await;
+
+    let error = execute_sol_transfer(&mut context, forged_asset, None)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::IncorrectAccount);
+}
+
+#[tokio::test]
+async fn cannot_execute_with_foreign_owned_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    set_foreign_owned_copy(&mut context, collection.pubkey(), collection.pubkey()).await;
+
+    let error = execute_sol_transfer(&mut context, asset.pubkey(), Some(collection.pubkey()))
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::IncorrectAccount);
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::WriteExternalPluginAdapterDataV1Builder,
+    types::{
+        Attribute, Attributes, DataStoreInitInfo, ExternalCheckResult,
+        ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey, ExternalPluginAdapterSchema,
+        FreezeDelegate, HookableLifecycleEvent, Key, OracleInitInfo, Plugin, PluginAuthority,
+        PluginAuthorityPair,
+    },
+    IndexableAsset,
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn assert_round_trip(context: &mut ProgramTestContext, address: Pubkey, key: Key) {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found");
+
+    let indexable_asset = IndexableAsset::fetch(key, &account.data).unwrap();
+    assert_eq!(indexable_asset.to_account_data().unwrap(), account.data);
+}
+
+#[tokio::test]
+async fn rebuild_asset_account_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::Attributes(Attributes {
+                        attribute_list: vec![Attribute {
+                            key: "level".to_string(),
+                            value: "1".to_string(),
+                        }],
+                    }),
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+                    authority: Some(PluginAuthority::UpdateAuthority),
+                },
+            ],
+            external_plugin_adapters: vec![
+                ExternalPluginAdapterInitInfo::DataStore(DataStoreInitInfo {
+                    init_plugin_authority: None,
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    schema: Some(ExternalPluginAdapterSchema::Json),
+                }),
+                ExternalPluginAdapterInitInfo::Oracle(OracleInitInfo {
+                    base_address: Pubkey::new_unique(),
+                    init_plugin_authority: None,
+                    lifecycle_checks: vec![
+                        (
+                            HookableLifecycleEvent::Transfer,
+                            ExternalCheckResult { flags: 4 },
+                        ),
+                        (
+                            HookableLifecycleEvent::Burn,
+                            ExternalCheckResult { flags: 4 },
+                        ),
+                    ],
+                    base_address_config: None,
+                    results_offset: None,
+                }),
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_round_trip(&mut context, asset.pubkey(), Key::AssetV1).await;
+
+    // Data written to the data store sits between the data store and the oracle.
+    let write_ix = WriteExternalPluginAdapterDataV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .key(ExternalPluginAdapterKey::DataStore(
+            PluginAuthority::UpdateAuthority,
+        ))
+        .data(br#"{"level":2,"name":"hero"}"#.to_vec())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_round_trip(&mut context, asset.pubkey(), Key::AssetV1).await;
+}
+
+#[tokio::test]
+async fn rebuild_collection_account_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Attributes(Attributes {
+                    attribute_list: vec![Attribute {
+                        key: "series".to_string(),
+                        value: "one".to_string(),
+                    }],
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_round_trip(&mut context, collection.pubkey(), Key::CollectionV1).await;
+}
@@ -0,0 +1,300 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    instructions::{BurnV1Builder, TransferV1Builder},
+    types::{
+        ExternalCheckResult, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey,
+        FreezeDelegate, HookableLifecycleEvent, OracleInitInfo, PermanentBurnDelegate, Plugin,
+        PluginAuthority, PluginAuthorityPair, PluginType,
+    },
+    Asset, Collection, LifecycleAction, PermissionOutcome, PermissionSimulator, PermissionSource,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn fetch_asset(context: &mut ProgramTestContext, address: Pubkey) -> Asset {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    Asset::from_bytes(&account.data).unwrap()
+}
+
+async fn fetch_collection(context: &mut ProgramTestContext, address: Pubkey) -> Collection {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    Collection::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn simulate_transfer_as_owner_and_stranger() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let deserialized_asset = fetch_asset(&mut context, asset.pubkey()).await;
+
+    let simulation = PermissionSimulator::new(&deserialized_asset, None, context.payer.pubkey())
+        .simulate(LifecycleAction::Transfer)
+        .unwrap();
+    assert_eq!(simulation.outcome, PermissionOutcome::Approved);
+    assert_eq!(simulation.responsible, Some(PermissionSource::Asset));
+
+    let simulation = PermissionSimulator::new(&deserialized_asset, None, Pubkey::new_unique())
+        .simulate(LifecycleAction::Transfer)
+        .unwrap();
+    assert_eq!(simulation.outcome, PermissionOutcome::NoApprovals);
+    assert_eq!(simulation.responsible, None);
+}
+
+#[tokio::test]
+async fn simulate_transfer_of_frozen_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let deserialized_asset = fetch_asset(&mut context, asset.pubkey()).await;
+    let simulation = PermissionSimulator::new(&deserialized_asset, None, context.payer.pubkey())
+        .simulate(LifecycleAction::Transfer)
+        .unwrap();
+    assert_eq!(simulation.outcome, PermissionOutcome::Rejected);
+    assert_eq!(
+        simulation.responsible,
+        Some(PermissionSource::AssetPlugin(PluginType::FreezeDelegate))
+    );
+
+    // The program agrees with the simulation.
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(Pubkey::new_unique())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+}
+
+#[tokio::test]
+async fn simulate_burn_by_collection_permanent_burn_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let delegate = Keypair::new();
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let deserialized_asset = fetch_asset(&mut context, asset.pubkey()).await;
+    let deserialized_collection = fetch_collection(&mut context, collection.pubkey()).await;
+
+    assert!(matches!(
+        PermissionSimulator::new(&deserialized_asset, None, delegate.pubkey())
+            .simulate(LifecycleAction::Burn),
+        Err(mpl_core::errors::MplCoreError::MissingCollection)
+    ));
+
+    let simulation = PermissionSimulator::new(
+        &deserialized_asset,
+        Some(&deserialized_collection),
+        delegate.pubkey(),
+    )
+    .simulate(LifecycleAction::Burn)
+    .unwrap();
+    assert_eq!(simulation.outcome, PermissionOutcome::Approved);
+    assert_eq!(
+        simulation.responsible,
+        Some(PermissionSource::CollectionPlugin(
+            PluginType::PermanentBurnDelegate
+        ))
+    );
+
+    // The program agrees with the simulation.
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn transfer_oracle(base_address: Pubkey) -> ExternalPluginAdapterInitInfo {
+    ExternalPluginAdapterInitInfo::Oracle(OracleInitInfo {
+        base_address,
+        init_plugin_authority: None,
+        lifecycle_checks: vec![(
+            HookableLifecycleEvent::Transfer,
+            ExternalCheckResult { flags: 4 },
+        )],
+        base_address_config: None,
+        results_offset: None,
+    })
+}
+
+#[tokio::test]
+async fn simulate_lists_collection_and_asset_adapters() {
+    let mut context = program_test().start_with_context().await;
+
+    let shared_oracle = Pubkey::new_unique();
+    let collection_oracle = Pubkey::new_unique();
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![
+                transfer_oracle(shared_oracle),
+                transfer_oracle(collection_oracle),
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+            external_plugin_adapters: vec![transfer_oracle(shared_oracle)],
+        },
+    )
+    .await
+    .unwrap();
+
+    let deserialized_asset = fetch_asset(&mut context, asset.pubkey()).await;
+    let deserialized_collection = fetch_collection(&mut context, collection.pubkey()).await;
+
+    // The asset oracle replaces the collection oracle with the same base address.
+    let simulation = PermissionSimulator::new(
+        &deserialized_asset,
+        Some(&deserialized_collection),
+        context.payer.pubkey(),
+    )
+    .simulate(LifecycleAction::Transfer)
+    .unwrap();
+    assert_eq!(simulation.outcome, PermissionOutcome::Approved);
+    assert_eq!(
+        simulation.unsimulated_adapters,
+        vec![
+            ExternalPluginAdapterKey::Oracle(shared_oracle),
+            ExternalPluginAdapterKey::Oracle(collection_oracle),
+        ]
+    );
+}
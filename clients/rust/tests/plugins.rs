@@ -48,6 +48,7 @@ async fn test_fetch_plugin() {
                             percentage: 100,
                         }],
                         rule_set: RuleSet::ProgramDenyList(vec![]),
+                        royalty_payments: false,
                     }),
                 },
             ],
@@ -81,6 +82,7 @@ async fn test_fetch_plugin() {
                             percentage: 100,
                         }],
                         rule_set: RuleSet::ProgramDenyList(vec![]),
+                        royalty_payments: false,
                     }),
                 },
             ],
@@ -158,6 +160,7 @@ async fn test_fetch_plugins() {
                             percentage: 100,
                         }],
                         rule_set: RuleSet::ProgramDenyList(vec![]),
+                        royalty_payments: false,
                     }),
                 },
             ],
@@ -191,6 +194,7 @@ async fn test_fetch_plugins() {
                             percentage: 100,
                         }],
                         rule_set: RuleSet::ProgramDenyList(vec![]),
+                        royalty_payments: false,
                     }),
                 },
             ],
@@ -269,6 +273,7 @@ async fn test_list_plugins() {
                             percentage: 100,
                         }],
                         rule_set: RuleSet::ProgramDenyList(vec![]),
+                        royalty_payments: false,
                     }),
                 },
             ],
@@ -302,6 +307,7 @@ async fn test_list_plugins() {
                             percentage: 100,
                         }],
                         rule_set: RuleSet::ProgramDenyList(vec![]),
+                        royalty_payments: false,
                     }),
                 },
             ],
@@ -1,13 +1,25 @@
 #![cfg(feature = "test-sbf")]
 pub mod setup;
+use borsh::BorshSerialize;
 use mpl_core::{
+    errors::MplCoreError,
     instructions::TransferV1Builder,
-    types::{Creator, Plugin, PluginAuthorityPair, Royalties, RuleSet, UpdateAuthority},
+    types::{
+        Creator, ExternalCheckResult, ExternalPluginAdapterInitInfo, ExternalValidationResult,
+        ExtraAccount, HookableLifecycleEvent, LifecycleHookInitInfo, OracleInitInfo,
+        OracleValidation, Plugin, PluginAuthority, PluginAuthorityPair, Royalties, RuleSet,
+        UpdateAuthority,
+    },
+    Asset, ExtraAccountInputs, MPL_CORE_PREFIX,
 };
 pub use setup::*;
 
-use solana_program_test::tokio;
-use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::AccountMeta,
+    program::set_return_data, pubkey::Pubkey,
+};
+use solana_program_test::{processor, tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
 
 #[tokio::test]
 async fn transfer_asset_as_owner() {
@@ -92,6 +104,7 @@ async fn transfer_asset_with_royalties() {
                         percentage: 100,
                     }],
                     rule_set: RuleSet::ProgramDenyList(vec![]),
+                    royalty_payments: false,
                 }),
             }],
             external_plugin_adapters: vec![],
@@ -133,6 +146,7 @@ async fn transfer_asset_with_royalties() {
                         percentage: 100,
                     }],
                     rule_set: RuleSet::ProgramDenyList(vec![]),
+                    royalty_payments: false,
                 }),
             }],
             external_plugin_adapters: vec![],
@@ -140,3 +154,364 @@ async fn transfer_asset_with_royalties() {
     )
     .await;
 }
+
+const APPROVING_HOOK: Pubkey = Pubkey::new_from_array([1; 32]);
+const REJECTING_HOOK: Pubkey = Pubkey::new_from_array([2; 32]);
+const SILENT_HOOK: Pubkey = Pubkey::new_from_array([3; 32]);
+
+fn approving_hook(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    set_return_data(&ExternalValidationResult::Approved.try_to_vec()?);
+    Ok(())
+}
+
+fn rejecting_hook(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    set_return_data(&ExternalValidationResult::Rejected.try_to_vec()?);
+    Ok(())
+}
+
+fn silent_hook(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    Ok(())
+}
+
+fn program_test_with_hooks() -> ProgramTest {
+    let mut program_test = program_test();
+    // The hooks are native fixtures, so they are added as builtins even in SBF runs.
+    program_test.prefer_bpf(false);
+    program_test.add_program("approving_hook", APPROVING_HOOK, processor!(approving_hook));
+    program_test.add_program("rejecting_hook", REJECTING_HOOK, processor!(rejecting_hook));
+    program_test.add_program("silent_hook", SILENT_HOOK, processor!(silent_hook));
+    program_test
+}
+
+async fn create_asset_with_transfer_hook(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    owner: Option<Pubkey>,
+    hooked_program: Pubkey,
+    check_result: ExternalCheckResult,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::LifecycleHook(
+                LifecycleHookInitInfo {
+                    hooked_program,
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    lifecycle_checks: vec![(HookableLifecycleEvent::Transfer, check_result)],
+                    extra_accounts: None,
+                    data_authority: None,
+                    schema: None,
+                },
+            )],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn transfer_asset_approved_by_lifecycle_hook() {
+    let mut context = program_test_with_hooks().start_with_context().await;
+
+    // The payer is not the owner, so the transfer only succeeds because the hook approves it.
+    let owner = Keypair::new();
+    let asset = Keypair::new();
+    create_asset_with_transfer_hook(
+        &mut context,
+        &asset,
+        Some(owner.pubkey()),
+        APPROVING_HOOK,
+        ExternalCheckResult { flags: 2 },
+    )
+    .await;
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .add_remaining_account(AccountMeta::new_readonly(APPROVING_HOOK, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let asset = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset.base.owner, new_owner.pubkey());
+}
+
+#[tokio::test]
+async fn transfer_asset_rejected_by_lifecycle_hook_fails() {
+    let mut context = program_test_with_hooks().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_transfer_hook(
+        &mut context,
+        &asset,
+        None,
+        REJECTING_HOOK,
+        ExternalCheckResult { flags: 4 },
+    )
+    .await;
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .add_remaining_account(AccountMeta::new_readonly(REJECTING_HOOK, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn transfer_asset_with_lifecycle_hook_missing_return_data_fails() {
+    let mut context = program_test_with_hooks().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_transfer_hook(
+        &mut context,
+        &asset,
+        None,
+        SILENT_HOOK,
+        ExternalCheckResult { flags: 4 },
+    )
+    .await;
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .add_remaining_account(AccountMeta::new_readonly(SILENT_HOOK, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidLifecycleHookResult);
+}
+
+#[tokio::test]
+async fn transfer_asset_with_lifecycle_hook_missing_hooked_program_fails() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::LifecycleHook(
+                LifecycleHookInitInfo {
+                    hooked_program: SILENT_HOOK,
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    lifecycle_checks: vec![(
+                        HookableLifecycleEvent::Transfer,
+                        ExternalCheckResult { flags: 4 },
+                    )],
+                    extra_accounts: None,
+                    data_authority: None,
+                    schema: None,
+                },
+            )],
+        },
+    )
+    .await
+    .unwrap();
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(
+        0,
+        error,
+        MplCoreError::MissingExternalPluginAdapterAccount
+    );
+}
+
+#[tokio::test]
+async fn transfer_asset_with_oracle_pda_extra_account() {
+    let mut context = program_test().start_with_context().await;
+
+    let oracle_program = Pubkey::new_unique();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::Oracle(OracleInitInfo {
+                base_address: oracle_program,
+                init_plugin_authority: None,
+                lifecycle_checks: vec![(
+                    HookableLifecycleEvent::Transfer,
+                    ExternalCheckResult { flags: 4 },
+                )],
+                base_address_config: Some(ExtraAccount::PreconfiguredAsset {
+                    is_signer: false,
+                    is_writable: false,
+                }),
+                results_offset: None,
+            })],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Set up the oracle account at the PDA derived from the asset.
+    let (oracle_account, _) = Pubkey::find_program_address(
+        &[MPL_CORE_PREFIX.as_bytes(), asset.pubkey().as_ref()],
+        &oracle_program,
+    );
+    let oracle_data = OracleValidation::V1 {
+        create: ExternalValidationResult::Pass,
+        transfer: ExternalValidationResult::Approved,
+        burn: ExternalValidationResult::Pass,
+        update: ExternalValidationResult::Pass,
+    }
+    .try_to_vec()
+    .unwrap();
+    context.set_account(
+        &oracle_account,
+        &Account {
+            lamports: 1_000_000_000,
+            data: oracle_data,
+            owner: oracle_program,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let deserialized_asset = Asset::from_bytes(&asset_account.data).unwrap();
+
+    let new_owner = Keypair::new();
+    let inputs = ExtraAccountInputs {
+        asset: Some((asset.pubkey(), &deserialized_asset)),
+        collection: None,
+        recipient: Some(new_owner.pubkey()),
+    };
+
+    assert_eq!(
+        mpl_core::find_extra_accounts(&HookableLifecycleEvent::Transfer, &inputs).unwrap(),
+        vec![AccountMeta::new_readonly(oracle_account, false)]
+    );
+    assert!(
+        mpl_core::find_extra_accounts(&HookableLifecycleEvent::Burn, &inputs)
+            .unwrap()
+            .is_empty()
+    );
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .add_extra_accounts(&inputs)
+        .unwrap()
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let deserialized_asset = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(deserialized_asset.base.owner, new_owner.pubkey());
+}
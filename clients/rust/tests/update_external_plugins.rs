@@ -18,7 +18,6 @@ use solana_program_test::tokio;
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
 
 #[tokio::test]
-#[ignore]
 async fn test_update_lifecycle_hook() {
     let mut context = program_test().start_with_context().await;
 
@@ -120,7 +119,6 @@ async fn test_update_lifecycle_hook() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_cannot_update_lifecycle_hook_to_have_duplicate_lifecycle_checks() {
     let mut context = program_test().start_with_context().await;
 
@@ -403,7 +401,6 @@ async fn test_cannot_update_oracle_to_have_duplicate_lifecycle_checks() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_update_data_store() {
     let mut context = program_test().start_with_context().await;
 
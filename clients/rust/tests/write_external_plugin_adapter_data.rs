@@ -0,0 +1,442 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
+    errors::MplCoreError,
+    instructions::{
+        AddCollectionPluginV1Builder, AddPluginV1Builder,
+        WriteCollectionExternalPluginAdapterDataV1Builder, WriteExternalPluginAdapterDataV1Builder,
+    },
+    types::{
+        Attribute, Attributes, DataStoreInitInfo, ExternalPluginAdapterInitInfo,
+        ExternalPluginAdapterKey, ExternalPluginAdapterSchema, FreezeDelegate, Key, Plugin,
+        PluginAuthority, PluginType,
+    },
+    DataBlob, IndexableAsset,
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_asset_with_data_store(context: &mut ProgramTestContext, asset: &Keypair) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::DataStore(
+                DataStoreInitInfo {
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    schema: Some(ExternalPluginAdapterSchema::Json),
+                },
+            )],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn write_data(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    key: ExternalPluginAdapterKey,
+    data: &[u8],
+) -> Result<(), solana_program_test::BanksClientError> {
+    let write_ix = WriteExternalPluginAdapterDataV1Builder::new()
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .key(key)
+        .data(data.to_vec())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn assert_data(context: &mut ProgramTestContext, asset: Pubkey, data: &str) {
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+
+    let indexable_asset = IndexableAsset::fetch(Key::AssetV1, &account.data).unwrap();
+    assert_eq!(indexable_asset.external_plugins.len(), 1);
+    let data_store = &indexable_asset.external_plugins[0];
+    assert_eq!(data_store.data_len, Some(data.len() as u64));
+    assert_eq!(data_store.data, Some(data.to_string()));
+}
+
+#[tokio::test]
+async fn test_write_data_store_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_data_store(&mut context, &asset).await;
+    assert_data(&mut context, asset.pubkey(), "").await;
+
+    // Grow the data.
+    write_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority),
+        br#"{"level":1,"name":"hero"}"#,
+    )
+    .await
+    .unwrap();
+    assert_data(&mut context, asset.pubkey(), r#"{"level":1,"name":"hero"}"#).await;
+
+    // Shrink the data.
+    write_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority),
+        br#"{"level":2}"#,
+    )
+    .await
+    .unwrap();
+    assert_data(&mut context, asset.pubkey(), r#"{"level":2}"#).await;
+}
+
+async fn fetch_layout(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    key: Key,
+) -> (u64, IndexableAsset) {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found");
+
+    let header_offset = match key {
+        Key::CollectionV1 => BaseCollectionV1::from_bytes(&account.data)
+            .unwrap()
+            .get_size(),
+        _ => BaseAssetV1::from_bytes(&account.data).unwrap().get_size(),
+    };
+    let plugin_header = PluginHeaderV1::from_bytes(&account.data[header_offset..]).unwrap();
+    let indexable_asset = IndexableAsset::fetch(key, &account.data).unwrap();
+
+    (plugin_header.plugin_registry_offset, indexable_asset)
+}
+
+fn assert_layout_shifted(
+    (old_registry_offset, old_asset): &(u64, IndexableAsset),
+    (new_registry_offset, new_asset): &(u64, IndexableAsset),
+    size_diff: i64,
+    trailing_plugin_type: PluginType,
+) {
+    let shift = |offset: u64| (offset as i64 + size_diff) as u64;
+
+    assert_eq!(*new_registry_offset, shift(*old_registry_offset));
+
+    // The written data store stays in place, the adapter and plugin after it are moved.
+    let (old_written, new_written) = (
+        &old_asset.external_plugins[0],
+        &new_asset.external_plugins[0],
+    );
+    assert_eq!(new_written.offset, old_written.offset);
+    assert_eq!(new_written.data_offset, old_written.data_offset);
+    assert_eq!(
+        new_written.data_len.unwrap() as i64,
+        old_written.data_len.unwrap() as i64 + size_diff
+    );
+
+    let (old_trailing, new_trailing) = (
+        &old_asset.external_plugins[1],
+        &new_asset.external_plugins[1],
+    );
+    assert_eq!(new_trailing.offset, shift(old_trailing.offset));
+    assert_eq!(
+        new_trailing.data_offset,
+        old_trailing.data_offset.map(shift)
+    );
+    assert_eq!(new_trailing.data_len, old_trailing.data_len);
+    assert_eq!(new_trailing.data, old_trailing.data);
+
+    let old_plugin = &old_asset.plugins[&trailing_plugin_type];
+    let new_plugin = &new_asset.plugins[&trailing_plugin_type];
+    assert_eq!(new_plugin.offset, shift(old_plugin.offset));
+    assert_eq!(new_plugin.data, old_plugin.data);
+}
+
+#[tokio::test]
+async fn test_write_data_store_data_with_trailing_plugins() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![
+                ExternalPluginAdapterInitInfo::DataStore(DataStoreInitInfo {
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    schema: Some(ExternalPluginAdapterSchema::Json),
+                }),
+                ExternalPluginAdapterInitInfo::DataStore(DataStoreInitInfo {
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    data_authority: PluginAuthority::Owner,
+                    schema: Some(ExternalPluginAdapterSchema::Json),
+                }),
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Plugins added after creation are placed after both data stores.
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    write_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::Owner),
+        br#"{"owner":true}"#,
+    )
+    .await
+    .unwrap();
+
+    let initial_layout = fetch_layout(&mut context, asset.pubkey(), Key::AssetV1).await;
+    assert_eq!(
+        initial_layout.1.external_plugins[1].data,
+        Some(r#"{"owner":true}"#.to_string())
+    );
+
+    // Grow the data.
+    let large_data = br#"{"level":1,"name":"hero","class":"wizard"}"#;
+    write_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority),
+        large_data,
+    )
+    .await
+    .unwrap();
+
+    let grown_layout = fetch_layout(&mut context, asset.pubkey(), Key::AssetV1).await;
+    assert_layout_shifted(
+        &initial_layout,
+        &grown_layout,
+        large_data.len() as i64,
+        PluginType::FreezeDelegate,
+    );
+
+    // Shrink the data.
+    let small_data = br#"{"level":2}"#;
+    write_data(
+        &mut context,
+        asset.pubkey(),
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority),
+        small_data,
+    )
+    .await
+    .unwrap();
+
+    let shrunk_layout = fetch_layout(&mut context, asset.pubkey(), Key::AssetV1).await;
+    assert_layout_shifted(
+        &grown_layout,
+        &shrunk_layout,
+        small_data.len() as i64 - large_data.len() as i64,
+        PluginType::FreezeDelegate,
+    );
+    assert_eq!(
+        shrunk_layout.1.external_plugins[0].data,
+        Some(r#"{"level":2}"#.to_string())
+    );
+}
+
+async fn write_collection_data(
+    context: &mut ProgramTestContext,
+    collection: Pubkey,
+    key: ExternalPluginAdapterKey,
+    data: &[u8],
+) -> Result<(), solana_program_test::BanksClientError> {
+    let write_ix = WriteCollectionExternalPluginAdapterDataV1Builder::new()
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .key(key)
+        .data(data.to_vec())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn test_write_collection_data_store_data_with_trailing_plugins() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![
+                ExternalPluginAdapterInitInfo::DataStore(DataStoreInitInfo {
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    schema: Some(ExternalPluginAdapterSchema::Json),
+                }),
+                ExternalPluginAdapterInitInfo::DataStore(DataStoreInitInfo {
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    data_authority: PluginAuthority::Owner,
+                    schema: Some(ExternalPluginAdapterSchema::Json),
+                }),
+            ],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Plugins added after creation are placed after both data stores.
+    let add_plugin_ix = AddCollectionPluginV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Attributes(Attributes {
+            attribute_list: vec![Attribute {
+                key: "level".to_string(),
+                value: "1".to_string(),
+            }],
+        }))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let initial_layout = fetch_layout(&mut context, collection.pubkey(), Key::CollectionV1).await;
+
+    // Grow the data.
+    let large_data = br#"{"level":1,"name":"hero","class":"wizard"}"#;
+    write_collection_data(
+        &mut context,
+        collection.pubkey(),
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority),
+        large_data,
+    )
+    .await
+    .unwrap();
+
+    let grown_layout = fetch_layout(&mut context, collection.pubkey(), Key::CollectionV1).await;
+    assert_layout_shifted(
+        &initial_layout,
+        &grown_layout,
+        large_data.len() as i64,
+        PluginType::Attributes,
+    );
+
+    // Shrink the data.
+    let small_data = br#"{"level":2}"#;
+    write_collection_data(
+        &mut context,
+        collection.pubkey(),
+        ExternalPluginAdapterKey::DataStore(PluginAuthority::UpdateAuthority),
+        small_data,
+    )
+    .await
+    .unwrap();
+
+    let shrunk_layout = fetch_layout(&mut context, collection.pubkey(), Key::CollectionV1).await;
+    assert_layout_shifted(
+        &grown_layout,
+        &shrunk_layout,
+        small_data.len() as i64 - large_data.len() as i64,
+        PluginType::Attributes,
+    );
+    assert_eq!(
+        shrunk_layout.1.external_plugins[0].data,
+        Some(r#"{"level":2}"#.to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_cannot_write_data_store_data_as_non_data_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset_with_data_store(&mut context, &asset).await;
+
+    let fake_authority = Keypair::new();
+    airdrop(&mut context, &fake_authority.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+
+    let write_ix = WriteExternalPluginAdapterDataV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(fake_authority.pubkey())
+        .key(ExternalPluginAdapterKey::DataStore(
+            PluginAuthority::UpdateAuthority,
+        ))
+        .data(b"data".to_vec())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix],
+        Some(&fake_authority.pubkey()),
+        &[&fake_authority],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
@@ -236,6 +236,12 @@ kinobi.update(
     },
     masterEdition: {
       name: "baseMasterEdition"
+    },
+    attributesV2: {
+      name: "baseAttributesV2"
+    },
+    attributeKeyAuthority: {
+      name: "baseAttributeKeyAuthority"
     }
   })
 )
@@ -176,6 +176,48 @@ pub enum MplCoreError {
     /// 41 - Invalid plugin operation
     #[error("Invalid plugin operation")]
     InvalidPluginOperation,
+
+    /// 42 - Could not read lifecycle hook validation result
+    #[error("Could not read validation result returned by lifecycle hook")]
+    InvalidLifecycleHookResult,
+
+    /// 43 - Cannot compress an asset with external plugin adapters
+    #[error("Cannot compress an asset with external plugin adapters")]
+    CannotCompressExternalPluginAdapters,
+
+    /// 44 - Cannot move asset to collection with permanent delegates
+    #[error("Cannot move asset to collection with permanent delegates")]
+    PermanentDelegatesPreventMove,
+
+    /// 45 - Master edition max supply reached
+    #[error("Master edition max supply reached")]
+    MaxSupplyReached,
+
+    /// 46 - Invalid multisig authority
+    #[error(
+        "Multisig authority must have a threshold between one and the number of unique signers"
+    )]
+    InvalidMultisigAuthority,
+
+    /// 47 - Invalid asset signer PDA
+    #[error("Invalid asset signer PDA, it must be derived from the asset")]
+    InvalidExecutePda,
+
+    /// 48 - Invalid or missing creator account for royalty payment
+    #[error("Invalid or missing creator account for royalty payment")]
+    InvalidRoyaltyCreator,
+
+    /// 49 - Missing instructions sysvar
+    #[error("Missing instructions sysvar needed to identify the invoking program")]
+    MissingInstructionsSysvar,
+
+    /// 50 - Attribute not found
+    #[error("Attribute not found")]
+    AttributeNotFound,
+
+    /// 51 - Sale price too low to pay royalties
+    #[error("Sale price is too low to pay every creator their royalties")]
+    RoyaltyUnderpaid,
 }
 
 impl PrintProgramError for MplCoreError {
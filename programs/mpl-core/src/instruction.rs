@@ -7,11 +7,13 @@ use crate::processor::{
     AddExternalPluginAdapterV1Args, AddPluginV1Args, ApproveCollectionPluginAuthorityV1Args,
     ApprovePluginAuthorityV1Args, BurnCollectionV1Args, BurnV1Args, CompressV1Args,
     CreateCollectionV1Args, CreateCollectionV2Args, CreateV1Args, CreateV2Args, DecompressV1Args,
+    ExecuteV1Args, RemoveAttributeV1Args, RemoveCollectionAttributeV1Args,
     RemoveCollectionExternalPluginAdapterV1Args, RemoveCollectionPluginV1Args,
     RemoveExternalPluginAdapterV1Args, RemovePluginV1Args, RevokeCollectionPluginAuthorityV1Args,
     RevokePluginAuthorityV1Args, TransferV1Args, UpdateCollectionExternalPluginAdapterV1Args,
     UpdateCollectionPluginV1Args, UpdateCollectionV1Args, UpdateExternalPluginAdapterV1Args,
-    UpdatePluginV1Args, UpdateV1Args, WriteCollectionExternalPluginAdapterDataV1Args,
+    UpdatePluginV1Args, UpdateV1Args, UpdateV2Args, UpsertAttributeV1Args,
+    UpsertCollectionAttributeV1Args, WriteCollectionExternalPluginAdapterDataV1Args,
     WriteExternalPluginAdapterDataV1Args,
 };
 
@@ -211,6 +213,7 @@ pub(crate) enum MplAssetInstruction {
     #[account(1, optional, name="update_authority", desc = "The authority of the new asset")]
     #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
     #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
     CreateCollectionV2(CreateCollectionV2Args),
 
     /// Add an external plugin adapter to an mpl-core.
@@ -280,4 +283,59 @@ pub(crate) enum MplAssetInstruction {
     #[account(3, name="system_program", desc = "The system program")]
     #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
     WriteCollectionExternalPluginAdapterDataV1(WriteCollectionExternalPluginAdapterDataV1Args),
+
+    /// Update an mpl-core, including moving it into, out of, or between collections.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority or update authority delegate of the asset")]
+    #[account(4, optional, writable, name="new_collection", desc = "The new collection to which to move the asset")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    UpdateV2(UpdateV2Args),
+
+    /// Execute an instruction as the asset signer PDA.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, name="asset_signer", desc = "The signer PDA of the asset")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, name="program_id", desc = "The program to invoke as the asset signer")]
+    #[account(7, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    ExecuteV1(ExecuteV1Args),
+
+    /// Set the value of a single attribute of an mpl-core Asset, adding it if not present.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    UpsertAttributeV1(UpsertAttributeV1Args),
+
+    /// Set the value of a single attribute of an mpl-core Collection, adding it if not present.
+    #[account(0, writable, name="collection", desc = "The address of the asset")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    UpsertCollectionAttributeV1(UpsertCollectionAttributeV1Args),
+
+    /// Remove a single attribute of an mpl-core Asset.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    RemoveAttributeV1(RemoveAttributeV1Args),
+
+    /// Remove a single attribute of an mpl-core Collection.
+    #[account(0, writable, name="collection", desc = "The address of the asset")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(3, name="system_program", desc = "The system program")]
+    #[account(4, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    RemoveCollectionAttributeV1(RemoveCollectionAttributeV1Args),
 }
@@ -1,6 +1,8 @@
-use super::PluginValidation;
-use crate::state::DataBlob;
+use super::{abstain, Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+use crate::{error::MplCoreError, state::DataBlob};
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError};
+use std::collections::HashSet;
 
 /// The Attribute type which represent a Key Value pair.
 #[repr(C)]
@@ -25,6 +27,71 @@ impl Attributes {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Get the value of an attribute by key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        get_attribute(&self.attribute_list, key)
+    }
+
+    /// Set the value of an attribute, adding it if the key is not present.
+    pub fn upsert(&mut self, key: String, value: String) {
+        upsert_attribute(&mut self.attribute_list, key, value)
+    }
+
+    /// Remove an attribute by key, returning it if it was present.
+    pub fn remove(&mut self, key: &str) -> Option<Attribute> {
+        remove_attribute(&mut self.attribute_list, key)
+    }
+}
+
+pub(crate) fn get_attribute<'a>(attribute_list: &'a [Attribute], key: &str) -> Option<&'a str> {
+    attribute_list
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.as_str())
+}
+
+pub(crate) fn upsert_attribute(attribute_list: &mut Vec<Attribute>, key: String, value: String) {
+    match attribute_list
+        .iter_mut()
+        .find(|attribute| attribute.key == key)
+    {
+        Some(attribute) => attribute.value = value,
+        None => attribute_list.push(Attribute { key, value }),
+    }
+}
+
+pub(crate) fn remove_attribute(
+    attribute_list: &mut Vec<Attribute>,
+    key: &str,
+) -> Option<Attribute> {
+    let index = attribute_list
+        .iter()
+        .position(|attribute| attribute.key == key)?;
+    Some(attribute_list.remove(index))
+}
+
+/// Keys must be unique so that every key has a single value.
+pub(crate) fn validate_attribute_list(attribute_list: &[Attribute]) -> ProgramResult {
+    let mut seen_keys = HashSet::new();
+    if !attribute_list
+        .iter()
+        .all(|attribute| seen_keys.insert(attribute.key.as_str()))
+    {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    Ok(())
+}
+
+impl DataBlob for Attribute {
+    fn get_initial_size() -> usize {
+        4 + 4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self.key.len() + 4 + self.value.len()
+    }
 }
 
 impl DataBlob for Attributes {
@@ -33,8 +100,40 @@ impl DataBlob for Attributes {
     }
 
     fn get_size(&self) -> usize {
-        4 // TODO: Implement this.
+        4 + self
+            .attribute_list
+            .iter()
+            .map(Attribute::get_size)
+            .sum::<usize>()
     }
 }
 
-impl PluginValidation for Attributes {}
+impl PluginValidation for Attributes {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_attribute_list(&self.attribute_list)?;
+        abstain!()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let Some(Plugin::Attributes(attributes)) = ctx.target_plugin {
+            validate_attribute_list(&attributes.attribute_list)?;
+        }
+        abstain!()
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let Some(Plugin::Attributes(attributes)) = ctx.target_plugin {
+            validate_attribute_list(&attributes.attribute_list)?;
+        }
+        abstain!()
+    }
+}
@@ -0,0 +1,191 @@
+use super::{
+    abstain, approve, get_attribute, reject, remove_attribute, upsert_attribute,
+    validate_attribute_list, Attribute, Plugin, PluginValidation, PluginValidationContext,
+    ValidationResult,
+};
+use crate::{
+    error::MplCoreError,
+    state::{Authority, DataBlob},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError};
+use std::collections::HashSet;
+
+/// The authority allowed to write the value of an attribute key.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct AttributeKeyAuthority {
+    /// The Key of the attribute.
+    pub key: String, // 4
+    /// The authority that may write the attribute.
+    pub authority: Authority, // 1 + optional 32
+}
+
+/// The AttributesV2 plugin allows the authority to add arbitrary Key-Value pairs to the asset,
+/// like the Attributes plugin.  Keys listed in `key_authorities` can only be written by their
+/// own authority, all other keys are written by the plugin authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, Default)]
+pub struct AttributesV2 {
+    /// A vector of Key-Value pairs.
+    pub attribute_list: Vec<Attribute>, // 4
+    /// A vector of per-key write authorities.
+    pub key_authorities: Vec<AttributeKeyAuthority>, // 4
+}
+
+impl AttributesV2 {
+    /// Get the value of an attribute by key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        get_attribute(&self.attribute_list, key)
+    }
+
+    /// Set the value of an attribute, adding it if the key is not present.
+    pub fn upsert(&mut self, key: String, value: String) {
+        upsert_attribute(&mut self.attribute_list, key, value)
+    }
+
+    /// Remove an attribute by key, returning it if it was present.
+    pub fn remove(&mut self, key: &str) -> Option<Attribute> {
+        remove_attribute(&mut self.attribute_list, key)
+    }
+
+    /// Get the authority allowed to write an attribute key, if the key has its own authority.
+    pub fn key_authority(&self, key: &str) -> Option<&Authority> {
+        self.key_authorities
+            .iter()
+            .find(|key_authority| key_authority.key == key)
+            .map(|key_authority| &key_authority.authority)
+    }
+
+    /// Get every value stored under a key, in order.
+    fn values<'a>(&'a self, key: &str) -> Vec<&'a str> {
+        self.attribute_list
+            .iter()
+            .filter(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+            .collect()
+    }
+
+    /// List the keys whose values differ between these attributes and `other`, including keys
+    /// present in only one of them.
+    fn changed_keys<'a>(&'a self, other: &'a AttributesV2) -> Vec<&'a str> {
+        let mut keys: Vec<&str> = self
+            .attribute_list
+            .iter()
+            .chain(other.attribute_list.iter())
+            .map(|attribute| attribute.key.as_str())
+            .filter(|key| self.values(key) != other.values(key))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        keys
+    }
+}
+
+impl DataBlob for AttributeKeyAuthority {
+    fn get_initial_size() -> usize {
+        4 + Authority::get_initial_size()
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self.key.len() + self.authority.get_size()
+    }
+}
+
+impl DataBlob for AttributesV2 {
+    fn get_initial_size() -> usize {
+        4 + 4
+    }
+
+    fn get_size(&self) -> usize {
+        4 + self
+            .attribute_list
+            .iter()
+            .map(Attribute::get_size)
+            .sum::<usize>()
+            + 4
+            + self
+                .key_authorities
+                .iter()
+                .map(AttributeKeyAuthority::get_size)
+                .sum::<usize>()
+    }
+}
+
+fn validate_attributes(attributes: &AttributesV2) -> ProgramResult {
+    validate_attribute_list(&attributes.attribute_list)?;
+
+    // Every key has a single authority.
+    let mut seen_keys = HashSet::new();
+    if !attributes
+        .key_authorities
+        .iter()
+        .all(|key_authority| seen_keys.insert(key_authority.key.as_str()))
+    {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    for key_authority in &attributes.key_authorities {
+        key_authority.authority.validate()?;
+    }
+
+    Ok(())
+}
+
+impl PluginValidation for AttributesV2 {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_attributes(self)?;
+        abstain!()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let Some(Plugin::AttributesV2(attributes)) = ctx.target_plugin {
+            validate_attributes(attributes)?;
+        }
+        abstain!()
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        let new_attributes = match ctx.target_plugin {
+            Some(Plugin::AttributesV2(attributes)) => attributes,
+            _ => return abstain!(),
+        };
+        validate_attributes(new_attributes)?;
+        let resolved_authorities = ctx
+            .resolved_authorities
+            .ok_or(MplCoreError::InvalidAuthority)?;
+
+        // Only the plugin authority can change who writes which keys.
+        if self.key_authorities != new_attributes.key_authorities
+            && !resolved_authorities.contains(ctx.self_authority)
+        {
+            return reject!();
+        }
+
+        let changed_keys = self.changed_keys(new_attributes);
+        if changed_keys.is_empty() {
+            return abstain!();
+        }
+
+        // Every changed key must be written by its own authority, falling back to the plugin
+        // authority for keys without one.
+        if changed_keys.iter().all(|key| {
+            let authority = self.key_authority(key).unwrap_or(ctx.self_authority);
+            resolved_authorities.contains(authority)
+                || authority.is_multisig_satisfied(ctx.accounts)
+        }) {
+            approve!()
+        } else {
+            reject!()
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::{BTreeMap, HashSet};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{error::MplCoreError, plugins::PluginType, state::Authority};
+use crate::{error::MplCoreError, plugins::PluginType};
 
 use super::{
     abstain, approve, Plugin, PluginValidation, PluginValidationContext, ValidationResult,
@@ -126,10 +126,7 @@ impl PluginValidation for Autograph {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == &(Authority::Address {
-                address: *ctx.authority_info.key,
-            })
+        if ctx.is_self_authority_signer()
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::Autograph
         {
@@ -3,7 +3,7 @@ use solana_program::program_error::ProgramError;
 
 use crate::{
     plugins::{abstain, approve, PluginType},
-    state::{Authority, DataBlob},
+    state::DataBlob,
 };
 
 use super::{PluginValidation, PluginValidationContext, ValidationResult};
@@ -42,11 +42,7 @@ impl PluginValidation for BurnDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == (&Authority::Address {
-                address: *ctx.authority_info.key,
-            })
-        {
+        if ctx.is_self_authority_signer() {
             approve!()
         } else {
             abstain!()
@@ -58,10 +54,7 @@ impl PluginValidation for BurnDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == &(Authority::Address {
-                address: *ctx.authority_info.key,
-            })
+        if ctx.is_self_authority_signer()
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::BurnDelegate
         {
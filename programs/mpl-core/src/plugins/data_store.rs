@@ -50,7 +50,7 @@ impl PluginValidation for DataStore {
 impl From<&DataStoreInitInfo> for DataStore {
     fn from(init_info: &DataStoreInitInfo) -> Self {
         Self {
-            data_authority: init_info.data_authority,
+            data_authority: init_info.data_authority.clone(),
             schema: init_info.schema.unwrap_or_default(),
         }
     }
@@ -1,7 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_error::ProgramError;
 
-use crate::{plugins::approve, state::Authority};
+use crate::plugins::approve;
 
 use super::{
     abstain, reject, Plugin, PluginType, PluginValidation, PluginValidationContext,
@@ -50,10 +50,7 @@ impl PluginValidation for Edition {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == &(Authority::Address {
-                address: *ctx.authority_info.key,
-            })
+        if ctx.is_self_authority_signer()
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::Edition
         {
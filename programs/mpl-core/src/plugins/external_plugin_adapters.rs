@@ -1,7 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::AccountMeta, msg,
+    program_error::ProgramError, pubkey::Pubkey,
 };
 use strum::EnumCount;
 
@@ -180,6 +180,108 @@ impl ExternalPluginAdapter {
         }
     }
 
+    /// Route the validation of the add plugin action to the appropriate external plugin adapter.
+    pub(crate) fn validate_add_plugin(
+        external_plugin_adapter: &ExternalPluginAdapter,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match external_plugin_adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                lifecycle_hook.validate_add_plugin(ctx)
+            }
+            ExternalPluginAdapter::Oracle(oracle) => oracle.validate_add_plugin(ctx),
+            ExternalPluginAdapter::DataStore(data_store) => data_store.validate_add_plugin(ctx),
+        }
+    }
+
+    /// Route the validation of the remove plugin action to the appropriate external plugin adapter.
+    pub(crate) fn validate_remove_plugin(
+        external_plugin_adapter: &ExternalPluginAdapter,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match external_plugin_adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                lifecycle_hook.validate_remove_plugin(ctx)
+            }
+            ExternalPluginAdapter::Oracle(oracle) => oracle.validate_remove_plugin(ctx),
+            ExternalPluginAdapter::DataStore(data_store) => data_store.validate_remove_plugin(ctx),
+        }
+    }
+
+    /// Route the validation of the update plugin action to the appropriate external plugin adapter.
+    pub(crate) fn validate_update_plugin(
+        external_plugin_adapter: &ExternalPluginAdapter,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match external_plugin_adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                lifecycle_hook.validate_update_plugin(ctx)
+            }
+            ExternalPluginAdapter::Oracle(oracle) => oracle.validate_update_plugin(ctx),
+            ExternalPluginAdapter::DataStore(data_store) => data_store.validate_update_plugin(ctx),
+        }
+    }
+
+    /// Route the validation of the approve plugin authority action to the appropriate external plugin adapter.
+    pub(crate) fn validate_approve_plugin_authority(
+        external_plugin_adapter: &ExternalPluginAdapter,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match external_plugin_adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                lifecycle_hook.validate_approve_plugin_authority(ctx)
+            }
+            ExternalPluginAdapter::Oracle(oracle) => oracle.validate_approve_plugin_authority(ctx),
+            ExternalPluginAdapter::DataStore(data_store) => {
+                data_store.validate_approve_plugin_authority(ctx)
+            }
+        }
+    }
+
+    /// Route the validation of the revoke plugin authority action to the appropriate external plugin adapter.
+    pub(crate) fn validate_revoke_plugin_authority(
+        external_plugin_adapter: &ExternalPluginAdapter,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match external_plugin_adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                lifecycle_hook.validate_revoke_plugin_authority(ctx)
+            }
+            ExternalPluginAdapter::Oracle(oracle) => oracle.validate_revoke_plugin_authority(ctx),
+            ExternalPluginAdapter::DataStore(data_store) => {
+                data_store.validate_revoke_plugin_authority(ctx)
+            }
+        }
+    }
+
+    /// Route the validation of the compress action to the appropriate external plugin adapter.
+    pub(crate) fn validate_compress(
+        external_plugin_adapter: &ExternalPluginAdapter,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match external_plugin_adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                lifecycle_hook.validate_compress(ctx)
+            }
+            ExternalPluginAdapter::Oracle(oracle) => oracle.validate_compress(ctx),
+            ExternalPluginAdapter::DataStore(data_store) => data_store.validate_compress(ctx),
+        }
+    }
+
+    /// Route the validation of the decompress action to the appropriate external plugin adapter.
+    pub(crate) fn validate_decompress(
+        external_plugin_adapter: &ExternalPluginAdapter,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match external_plugin_adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                lifecycle_hook.validate_decompress(ctx)
+            }
+            ExternalPluginAdapter::Oracle(oracle) => oracle.validate_decompress(ctx),
+            ExternalPluginAdapter::DataStore(data_store) => data_store.validate_decompress(ctx),
+        }
+    }
+
     /// Validate the add external plugin adapter lifecycle event.
     pub(crate) fn validate_add_external_plugin_adapter(
         external_plugin_adapter: &ExternalPluginAdapter,
@@ -246,6 +348,20 @@ pub enum HookableLifecycleEvent {
     Burn,
     /// Update an Asset or a Collection.
     Update,
+    /// Add a plugin to an Asset or a Collection.
+    AddPlugin,
+    /// Remove a plugin from an Asset or a Collection.
+    RemovePlugin,
+    /// Update a plugin on an Asset or a Collection.
+    UpdatePlugin,
+    /// Approve a new authority for a plugin.
+    ApprovePluginAuthority,
+    /// Revoke the authority of a plugin.
+    RevokePluginAuthority,
+    /// Compress an Asset.
+    Compress,
+    /// Decompress an Asset.
+    Decompress,
 }
 
 /// Prefix used with some of the `ExtraAccounts` that are PDAs.
@@ -372,6 +488,61 @@ impl ExtraAccount {
             ExtraAccount::Address { address, .. } => Ok(*address),
         }
     }
+
+    pub(crate) fn to_account_meta(
+        &self,
+        program_id: &Pubkey,
+        ctx: &PluginValidationContext,
+    ) -> Result<AccountMeta, ProgramError> {
+        let pubkey = self.derive(program_id, ctx)?;
+        let (is_signer, is_writable) = self.flags();
+
+        Ok(AccountMeta {
+            pubkey,
+            is_signer,
+            is_writable,
+        })
+    }
+
+    /// Whether the account is configured as a signer.
+    pub(crate) fn is_signer(&self) -> bool {
+        self.flags().0
+    }
+
+    fn flags(&self) -> (bool, bool) {
+        match self {
+            ExtraAccount::PreconfiguredProgram {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredCollection {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredOwner {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredRecipient {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::PreconfiguredAsset {
+                is_signer,
+                is_writable,
+            }
+            | ExtraAccount::CustomPda {
+                is_signer,
+                is_writable,
+                ..
+            }
+            | ExtraAccount::Address {
+                is_signer,
+                is_writable,
+                ..
+            } => (*is_signer, *is_writable),
+        }
+    }
 }
 
 // Transform seeds from their tokens into actual seeds based on passed-in context values.
@@ -486,7 +657,7 @@ pub enum ExternalPluginAdapterUpdateInfo {
 /// Key used to uniquely specify an external plugin adapter after it is created.
 #[repr(C)]
 #[derive(
-    Clone, Copy, Debug, BorshSerialize, BorshDeserialize, Eq, PartialEq, EnumCount, PartialOrd, Ord,
+    Clone, Debug, BorshSerialize, BorshDeserialize, Eq, PartialEq, EnumCount, PartialOrd, Ord,
 )]
 pub enum ExternalPluginAdapterKey {
     /// Lifecycle Hook.
@@ -538,7 +709,7 @@ impl From<&ExternalPluginAdapterInitInfo> for ExternalPluginAdapterKey {
                 ExternalPluginAdapterKey::Oracle(init_info.base_address)
             }
             ExternalPluginAdapterInitInfo::DataStore(init_info) => {
-                ExternalPluginAdapterKey::DataStore(init_info.data_authority)
+                ExternalPluginAdapterKey::DataStore(init_info.data_authority.clone())
             }
         }
     }
@@ -1,7 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_error::ProgramError;
 
-use crate::state::{Authority, DataBlob};
+use crate::state::DataBlob;
 
 use super::{
     abstain, approve, reject, Plugin, PluginValidation, PluginValidationContext, ValidationResult,
@@ -62,6 +62,17 @@ impl PluginValidation for FreezeDelegate {
         }
     }
 
+    fn validate_execute(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.frozen {
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+
     fn validate_approve_plugin_authority(
         &self,
         ctx: &PluginValidationContext,
@@ -82,11 +93,7 @@ impl PluginValidation for FreezeDelegate {
         if let Some(Plugin::FreezeDelegate(freeze)) = ctx.target_plugin {
             if freeze.frozen {
                 return reject!();
-            } else if ctx.self_authority
-                == &(Authority::Address {
-                    address: *ctx.authority_info.key,
-                })
-            {
+            } else if ctx.is_self_authority_signer() {
                 return approve!();
             }
         }
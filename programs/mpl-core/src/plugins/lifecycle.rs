@@ -84,6 +84,8 @@ impl PluginType {
             PluginType::Edition => CheckResult::CanReject,
             PluginType::Autograph => CheckResult::CanReject,
             PluginType::VerifiedCreators => CheckResult::CanReject,
+            PluginType::TimeLock => CheckResult::CanReject,
+            PluginType::Soulbound => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
@@ -96,6 +98,7 @@ impl PluginType {
             PluginType::FreezeDelegate => CheckResult::CanReject,
             PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
             PluginType::Edition => CheckResult::CanReject,
+            PluginType::TimeLock => CheckResult::CanReject,
             // We default to CanReject because Plugins with Authority::None cannot be removed.
             _ => CheckResult::CanReject,
         }
@@ -132,6 +135,8 @@ impl PluginType {
         #[allow(clippy::match_single_binding)]
         match plugin_type {
             PluginType::Royalties => CheckResult::CanReject,
+            PluginType::Attributes => CheckResult::CanReject,
+            PluginType::AttributesV2 => CheckResult::CanReject,
             PluginType::UpdateDelegate => CheckResult::CanApprove,
             PluginType::Autograph => CheckResult::CanReject,
             PluginType::VerifiedCreators => CheckResult::CanReject,
@@ -156,6 +161,8 @@ impl PluginType {
             PluginType::BurnDelegate => CheckResult::CanApprove,
             PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
             PluginType::PermanentBurnDelegate => CheckResult::CanApprove,
+            PluginType::TimeLock => CheckResult::CanReject,
+            PluginType::UseDelegate => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
@@ -168,6 +175,9 @@ impl PluginType {
             PluginType::TransferDelegate => CheckResult::CanApprove,
             PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
             PluginType::PermanentTransferDelegate => CheckResult::CanApprove,
+            PluginType::TimeLock => CheckResult::CanReject,
+            PluginType::Soulbound => CheckResult::CanReject,
+            PluginType::UseDelegate => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
@@ -188,6 +198,15 @@ impl PluginType {
         }
     }
 
+    /// Check if a plugin is permitted to approve or deny an execute action.
+    pub fn check_execute(plugin_type: &PluginType) -> CheckResult {
+        match plugin_type {
+            PluginType::FreezeDelegate => CheckResult::CanReject,
+            PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
+            _ => CheckResult::None,
+        }
+    }
+
     /// Check permissions for the add external plugin adapter lifecycle event.
     pub fn check_add_external_plugin_adapter(plugin_type: &PluginType) -> CheckResult {
         #[allow(clippy::match_single_binding)]
@@ -245,6 +264,10 @@ impl Plugin {
                 verified_creators.validate_add_plugin(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_add_plugin(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_add_plugin(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_add_plugin(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_add_plugin(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_add_plugin(ctx),
         }
     }
 
@@ -286,6 +309,10 @@ impl Plugin {
                 verified_creators.validate_remove_plugin(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_remove_plugin(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_remove_plugin(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_remove_plugin(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_remove_plugin(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_remove_plugin(ctx),
         }
     }
 
@@ -334,6 +361,12 @@ impl Plugin {
                 verified_creators.validate_approve_plugin_authority(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_approve_plugin_authority(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_approve_plugin_authority(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_approve_plugin_authority(ctx),
+            Plugin::UseDelegate(use_delegate) => {
+                use_delegate.validate_approve_plugin_authority(ctx)
+            }
+            Plugin::AttributesV2(attributes) => attributes.validate_approve_plugin_authority(ctx),
         }
     }
 
@@ -380,6 +413,10 @@ impl Plugin {
                 verified_creators.validate_revoke_plugin_authority(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_revoke_plugin_authority(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_revoke_plugin_authority(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_revoke_plugin_authority(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_revoke_plugin_authority(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_revoke_plugin_authority(ctx),
         }
     }
 
@@ -410,6 +447,10 @@ impl Plugin {
             }
             Plugin::VerifiedCreators(verified_creators) => verified_creators.validate_create(ctx),
             Plugin::Autograph(autograph) => autograph.validate_create(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_create(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_create(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_create(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_create(ctx),
         }
     }
 
@@ -440,6 +481,10 @@ impl Plugin {
             }
             Plugin::VerifiedCreators(verified_creators) => verified_creators.validate_update(ctx),
             Plugin::Autograph(autograph) => autograph.validate_update(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_update(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_update(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_update(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_update(ctx),
         }
     }
 
@@ -485,6 +530,10 @@ impl Plugin {
                 verified_creators.validate_update_plugin(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_update_plugin(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_update_plugin(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_update_plugin(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_update_plugin(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_update_plugin(ctx),
         }?;
 
         match (&base_result, &result) {
@@ -532,6 +581,10 @@ impl Plugin {
             Plugin::ImmutableMetadata(immutable_metadata) => immutable_metadata.validate_burn(ctx),
             Plugin::VerifiedCreators(verified_creators) => verified_creators.validate_burn(ctx),
             Plugin::Autograph(autograph) => autograph.validate_burn(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_burn(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_burn(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_burn(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_burn(ctx),
         }
     }
 
@@ -562,6 +615,10 @@ impl Plugin {
             }
             Plugin::VerifiedCreators(verified_creators) => verified_creators.validate_transfer(ctx),
             Plugin::Autograph(autograph) => autograph.validate_transfer(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_transfer(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_transfer(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_transfer(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_transfer(ctx),
         }
     }
 
@@ -592,6 +649,10 @@ impl Plugin {
             }
             Plugin::VerifiedCreators(verified_creators) => verified_creators.validate_compress(ctx),
             Plugin::Autograph(autograph) => autograph.validate_compress(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_compress(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_compress(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_compress(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_compress(ctx),
         }
     }
 
@@ -626,6 +687,44 @@ impl Plugin {
                 verified_creators.validate_decompress(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_decompress(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_decompress(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_decompress(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_decompress(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_decompress(ctx),
+        }
+    }
+
+    /// Route the validation of the execute action to the appropriate plugin.
+    pub(crate) fn validate_execute(
+        plugin: &Plugin,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match plugin {
+            Plugin::Royalties(royalties) => royalties.validate_execute(ctx),
+            Plugin::FreezeDelegate(freeze) => freeze.validate_execute(ctx),
+            Plugin::BurnDelegate(burn) => burn.validate_execute(ctx),
+            Plugin::TransferDelegate(transfer) => transfer.validate_execute(ctx),
+            Plugin::UpdateDelegate(update_delegate) => update_delegate.validate_execute(ctx),
+            Plugin::PermanentFreezeDelegate(permanent_freeze) => {
+                permanent_freeze.validate_execute(ctx)
+            }
+            Plugin::Attributes(attributes) => attributes.validate_execute(ctx),
+            Plugin::PermanentTransferDelegate(permanent_transfer) => {
+                permanent_transfer.validate_execute(ctx)
+            }
+            Plugin::PermanentBurnDelegate(permanent_burn) => permanent_burn.validate_execute(ctx),
+            Plugin::Edition(edition) => edition.validate_execute(ctx),
+            Plugin::MasterEdition(master_edition) => master_edition.validate_execute(ctx),
+            Plugin::AddBlocker(add_blocker) => add_blocker.validate_execute(ctx),
+            Plugin::ImmutableMetadata(immutable_metadata) => {
+                immutable_metadata.validate_execute(ctx)
+            }
+            Plugin::VerifiedCreators(verified_creators) => verified_creators.validate_execute(ctx),
+            Plugin::Autograph(autograph) => autograph.validate_execute(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_execute(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_execute(ctx),
+            Plugin::UseDelegate(use_delegate) => use_delegate.validate_execute(ctx),
+            Plugin::AttributesV2(attributes) => attributes.validate_execute(ctx),
         }
     }
 
@@ -668,6 +767,14 @@ impl Plugin {
                 verified_creators.validate_add_external_plugin_adapter(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_add_external_plugin_adapter(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_add_external_plugin_adapter(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_add_external_plugin_adapter(ctx),
+            Plugin::UseDelegate(use_delegate) => {
+                use_delegate.validate_add_external_plugin_adapter(ctx)
+            }
+            Plugin::AttributesV2(attributes) => {
+                attributes.validate_add_external_plugin_adapter(ctx)
+            }
         }
     }
 
@@ -712,6 +819,14 @@ impl Plugin {
                 verified_creators.validate_remove_external_plugin_adapter(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_remove_external_plugin_adapter(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_remove_external_plugin_adapter(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_remove_external_plugin_adapter(ctx),
+            Plugin::UseDelegate(use_delegate) => {
+                use_delegate.validate_remove_external_plugin_adapter(ctx)
+            }
+            Plugin::AttributesV2(attributes) => {
+                attributes.validate_remove_external_plugin_adapter(ctx)
+            }
         }
     }
 
@@ -767,6 +882,14 @@ impl Plugin {
                 verified_creators.validate_update_external_plugin_adapter(ctx)
             }
             Plugin::Autograph(autograph) => autograph.validate_update_external_plugin_adapter(ctx),
+            Plugin::TimeLock(time_lock) => time_lock.validate_update_external_plugin_adapter(ctx),
+            Plugin::Soulbound(soulbound) => soulbound.validate_update_external_plugin_adapter(ctx),
+            Plugin::UseDelegate(use_delegate) => {
+                use_delegate.validate_update_external_plugin_adapter(ctx)
+            }
+            Plugin::AttributesV2(attributes) => {
+                attributes.validate_update_external_plugin_adapter(ctx)
+            }
         }?;
 
         match (&base_result, &result) {
@@ -882,6 +1005,18 @@ pub(crate) struct PluginValidationContext<'a, 'b> {
     pub target_plugin: Option<&'b Plugin>,
 }
 
+impl PluginValidationContext<'_, '_> {
+    /// Check if the authority signer holds the authority of the current (self) plugin, either as
+    /// its address or as enough signers of its multisig.
+    pub(crate) fn is_self_authority_signer(&self) -> bool {
+        match self.self_authority {
+            Authority::Address { address } => address == self.authority_info.key,
+            Authority::Multisig { .. } => self.self_authority.is_multisig_satisfied(self.accounts),
+            _ => false,
+        }
+    }
+}
+
 /// Plugin validation trait which is implemented by each plugin.
 pub(crate) trait PluginValidation {
     /// Validate the add plugin lifecycle action.
@@ -997,6 +1132,14 @@ pub(crate) trait PluginValidation {
         abstain!()
     }
 
+    /// Validate the execute lifecycle action.
+    fn validate_execute(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        abstain!()
+    }
+
     /// Validate the add_authority lifecycle action.
     fn validate_add_authority(
         &self,
@@ -1,9 +1,18 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplCoreError;
 
 use super::{
-    abstain, Authority, ExternalCheckResult, ExternalPluginAdapterSchema, ExtraAccount,
-    HookableLifecycleEvent, PluginValidation, PluginValidationContext, ValidationResult,
+    abstain, Authority, ExternalCheckResult, ExternalPluginAdapterSchema, ExternalValidationResult,
+    ExtraAccount, HookableLifecycleEvent, PluginType, PluginValidation, PluginValidationContext,
+    ValidationResult,
 };
 
 /// Lifecycle hook that CPIs into the `hooked_program`.  This hook is used for any lifecycle events
@@ -46,12 +55,172 @@ impl PluginValidation for LifecycleHook {
         abstain!()
     }
 
+    fn validate_create(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::Create)
+    }
+
     fn validate_transfer(
         &self,
-        _ctx: &PluginValidationContext,
+        ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        abstain!()
+        self.validate_helper(ctx, HookableLifecycleEvent::Transfer)
     }
+
+    fn validate_burn(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::Burn)
+    }
+
+    fn validate_update(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::Update)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::AddPlugin)
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::RemovePlugin)
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::UpdatePlugin)
+    }
+
+    fn validate_approve_plugin_authority(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::ApprovePluginAuthority)
+    }
+
+    fn validate_revoke_plugin_authority(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::RevokePluginAuthority)
+    }
+
+    fn validate_compress(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::Compress)
+    }
+
+    fn validate_decompress(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::Decompress)
+    }
+}
+
+impl LifecycleHook {
+    fn validate_helper(
+        &self,
+        ctx: &PluginValidationContext,
+        event: HookableLifecycleEvent,
+    ) -> Result<ValidationResult, ProgramError> {
+        let find_account = |key: &Pubkey| {
+            ctx.accounts
+                .iter()
+                .find(|account| account.key == key)
+                .ok_or(MplCoreError::MissingExternalPluginAdapterAccount)
+        };
+
+        let hooked_program_info = find_account(&self.hooked_program)?;
+
+        // The fixed accounts are always passed in the same order.  Any optional account that is
+        // not available for the lifecycle event is replaced by the hooked program ID.  No
+        // signature is forwarded: the authority was already verified, so the hooked program only
+        // needs to compare keys, and must not be able to act for the signers elsewhere.
+        let mut account_metas = Vec::<AccountMeta>::new();
+        let mut account_infos = Vec::<AccountInfo>::new();
+        for account in [
+            ctx.asset_info,
+            ctx.collection_info,
+            Some(ctx.authority_info),
+            ctx.new_owner,
+        ] {
+            let account = account.unwrap_or(hooked_program_info);
+            account_metas.push(AccountMeta::new_readonly(*account.key, false));
+            account_infos.push(account.clone());
+        }
+
+        if let Some(extra_accounts) = &self.extra_accounts {
+            for extra_account in extra_accounts {
+                let mut account_meta = extra_account.to_account_meta(&self.hooked_program, ctx)?;
+                account_meta.is_signer = false;
+                account_infos.push(find_account(&account_meta.pubkey)?.clone());
+                account_metas.push(account_meta);
+            }
+        }
+
+        account_infos.push(hooked_program_info.clone());
+
+        let instruction = Instruction {
+            program_id: self.hooked_program,
+            accounts: account_metas,
+            data: LifecycleHookInstruction::ExecuteV1 {
+                event,
+                plugin_type: ctx.target_plugin.map(PluginType::from),
+            }
+            .try_to_vec()?,
+        };
+
+        invoke(&instruction, &account_infos)?;
+
+        let (program_id, return_data) =
+            get_return_data().ok_or(MplCoreError::InvalidLifecycleHookResult)?;
+        if program_id != self.hooked_program {
+            return Err(MplCoreError::InvalidLifecycleHookResult.into());
+        }
+
+        let validation_result = ExternalValidationResult::try_from_slice(&return_data)
+            .map_err(|_| MplCoreError::InvalidLifecycleHookResult)?;
+
+        Ok(ValidationResult::from(validation_result))
+    }
+}
+
+/// Instruction data sent to the hooked program when a lifecycle event is executed.
+///
+/// The hooked program receives the following accounts, followed by any extra accounts in the
+/// order in which they are specified on the plugin.  None of the accounts are passed as signers:
+/// 0. `[]` The asset, or the hooked program ID if there is no asset.
+/// 1. `[]` The collection, or the hooked program ID if there is no collection.
+/// 2. `[]` The authority of the lifecycle action, whose signature has already been verified.
+/// 3. `[]` The new owner for transfers, or the hooked program ID for other events.
+///
+/// The hooked program must set an `ExternalValidationResult` as its return data.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, Eq, PartialEq)]
+pub enum LifecycleHookInstruction {
+    /// Execute the lifecycle hook for the given event.
+    ExecuteV1 {
+        /// The lifecycle event being executed.
+        event: HookableLifecycleEvent,
+        /// The type of the plugin being added, removed, updated, or having its authority changed,
+        /// for plugin lifecycle events.
+        plugin_type: Option<PluginType>,
+    },
 }
 
 impl From<&LifecycleHookInitInfo> for LifecycleHook {
@@ -59,7 +228,7 @@ impl From<&LifecycleHookInitInfo> for LifecycleHook {
         Self {
             hooked_program: init_info.hooked_program,
             extra_accounts: init_info.extra_accounts.clone(),
-            data_authority: init_info.data_authority,
+            data_authority: init_info.data_authority.clone(),
             schema: init_info.schema.unwrap_or_default(),
         }
     }
@@ -3,6 +3,8 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use super::PluginValidation;
 
 /// The master edition plugin allows the creator to specify details on the master edition including max supply, name, and uri.
+/// Assets created into the collection are rejected once the collection's `num_minted` reaches
+/// `max_supply`, and `CreateV2` can request that the `Edition` number is assigned from it.
 /// The default authority for this plugin is the creator.
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Default, Debug, PartialEq, Eq)]
@@ -1,5 +1,6 @@
 mod add_blocker;
 mod attributes;
+mod attributes_v2;
 mod burn_delegate;
 mod data_store;
 mod edition;
@@ -20,13 +21,17 @@ mod permanent_transfer_delegate;
 mod plugin_header;
 mod plugin_registry;
 mod royalties;
+mod soulbound;
+mod time_lock;
 mod transfer;
 mod update_delegate;
+mod use_delegate;
 mod utils;
 mod verified_creators;
 
 pub use add_blocker::*;
 pub use attributes::*;
+pub use attributes_v2::*;
 pub use autograph::*;
 pub use burn_delegate::*;
 pub use data_store::*;
@@ -44,8 +49,11 @@ pub use permanent_transfer_delegate::*;
 pub use plugin_header::*;
 pub use plugin_registry::*;
 pub use royalties::*;
+pub use soulbound::*;
+pub use time_lock::*;
 pub use transfer::*;
 pub use update_delegate::*;
+pub use use_delegate::*;
 pub use utils::*;
 pub use verified_creators::*;
 
@@ -95,6 +103,14 @@ pub enum Plugin {
     VerifiedCreators(VerifiedCreators),
     /// Autograph plugin allows anybody to add their signature to the asset with an optional message
     Autograph(Autograph),
+    /// TimeLock plugin prevents transfers and burns until a timestamp or slot passes
+    TimeLock(TimeLock),
+    /// Soulbound plugin prevents transfers except by an optional recovery authority
+    Soulbound(Soulbound),
+    /// UseDelegate plugin grants expiring usage rights to a user other than the owner
+    UseDelegate(UseDelegate),
+    /// AttributesV2 plugin for arbitrary Key-Value pairs with per-key write authorities
+    AttributesV2(AttributesV2),
 }
 
 impl Plugin {
@@ -169,15 +185,23 @@ pub enum PluginType {
     VerifiedCreators,
     /// Autograph plugin.
     Autograph,
+    /// TimeLock plugin.
+    TimeLock,
+    /// Soulbound plugin.
+    Soulbound,
+    /// UseDelegate plugin.
+    UseDelegate,
+    /// AttributesV2 plugin.
+    AttributesV2,
 }
 
 impl DataBlob for PluginType {
     fn get_initial_size() -> usize {
-        2
+        1
     }
 
     fn get_size(&self) -> usize {
-        2
+        1
     }
 }
 
@@ -199,6 +223,10 @@ impl From<&Plugin> for PluginType {
             Plugin::MasterEdition(_) => PluginType::MasterEdition,
             Plugin::VerifiedCreators(_) => PluginType::VerifiedCreators,
             Plugin::Autograph(_) => PluginType::Autograph,
+            Plugin::TimeLock(_) => PluginType::TimeLock,
+            Plugin::Soulbound(_) => PluginType::Soulbound,
+            Plugin::UseDelegate(_) => PluginType::UseDelegate,
+            Plugin::AttributesV2(_) => PluginType::AttributesV2,
         }
     }
 }
@@ -222,6 +250,10 @@ impl PluginType {
             PluginType::MasterEdition => Authority::UpdateAuthority,
             PluginType::VerifiedCreators => Authority::UpdateAuthority,
             PluginType::Autograph => Authority::Owner,
+            PluginType::TimeLock => Authority::UpdateAuthority,
+            PluginType::Soulbound => Authority::None,
+            PluginType::UseDelegate => Authority::Owner,
+            PluginType::AttributesV2 => Authority::UpdateAuthority,
         }
     }
 }
@@ -5,7 +5,8 @@ use crate::error::MplCoreError;
 
 use super::{
     abstain, Authority, ExternalCheckResult, ExternalValidationResult, ExtraAccount,
-    HookableLifecycleEvent, PluginValidation, PluginValidationContext, ValidationResult,
+    HookableLifecycleEvent, PluginType, PluginValidation, PluginValidationContext,
+    ValidationResult,
 };
 
 /// Oracle plugin that allows getting a `ValidationResult` for a lifecycle event from an arbitrary
@@ -71,6 +72,55 @@ impl PluginValidation for Oracle {
     ) -> Result<ValidationResult, ProgramError> {
         self.validate_helper(ctx, HookableLifecycleEvent::Update)
     }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::AddPlugin)
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::RemovePlugin)
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::UpdatePlugin)
+    }
+
+    fn validate_approve_plugin_authority(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::ApprovePluginAuthority)
+    }
+
+    fn validate_revoke_plugin_authority(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::RevokePluginAuthority)
+    }
+
+    fn validate_compress(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::Compress)
+    }
+
+    fn validate_decompress(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_helper(ctx, HookableLifecycleEvent::Decompress)
+    }
 }
 
 impl Oracle {
@@ -116,6 +166,37 @@ impl Oracle {
                 HookableLifecycleEvent::Transfer => Ok(ValidationResult::from(transfer)),
                 HookableLifecycleEvent::Burn => Ok(ValidationResult::from(burn)),
                 HookableLifecycleEvent::Update => Ok(ValidationResult::from(update)),
+                // Version 1 accounts have no results for the remaining events.
+                _ => abstain!(),
+            },
+            OracleValidation::V2 {
+                create,
+                transfer,
+                burn,
+                update,
+                add_plugin,
+                remove_plugin,
+                update_plugin,
+                approve_plugin_authority,
+                revoke_plugin_authority,
+                compress,
+                decompress,
+            } => match event {
+                HookableLifecycleEvent::Create => Ok(ValidationResult::from(create)),
+                HookableLifecycleEvent::Transfer => Ok(ValidationResult::from(transfer)),
+                HookableLifecycleEvent::Burn => Ok(ValidationResult::from(burn)),
+                HookableLifecycleEvent::Update => Ok(ValidationResult::from(update)),
+                HookableLifecycleEvent::AddPlugin => add_plugin.validate(ctx),
+                HookableLifecycleEvent::RemovePlugin => remove_plugin.validate(ctx),
+                HookableLifecycleEvent::UpdatePlugin => update_plugin.validate(ctx),
+                HookableLifecycleEvent::ApprovePluginAuthority => {
+                    approve_plugin_authority.validate(ctx)
+                }
+                HookableLifecycleEvent::RevokePluginAuthority => {
+                    revoke_plugin_authority.validate(ctx)
+                }
+                HookableLifecycleEvent::Compress => Ok(ValidationResult::from(compress)),
+                HookableLifecycleEvent::Decompress => Ok(ValidationResult::from(decompress)),
             },
         }
     }
@@ -205,11 +286,60 @@ pub enum OracleValidation {
         /// Validation for the update lifecycle action.
         update: ExternalValidationResult,
     },
+    /// Version 2 of the format, adding results for plugin management and compression.
+    V2 {
+        /// Validation for the the create lifecycle action.
+        create: ExternalValidationResult,
+        /// Validation for the transfer lifecycle action.
+        transfer: ExternalValidationResult,
+        /// Validation for the burn lifecycle action.
+        burn: ExternalValidationResult,
+        /// Validation for the update lifecycle action.
+        update: ExternalValidationResult,
+        /// Validation for the add plugin lifecycle action, by target plugin type.
+        add_plugin: OraclePluginValidation,
+        /// Validation for the remove plugin lifecycle action, by target plugin type.
+        remove_plugin: OraclePluginValidation,
+        /// Validation for the update plugin lifecycle action, by target plugin type.
+        update_plugin: OraclePluginValidation,
+        /// Validation for the approve plugin authority lifecycle action, by target plugin type.
+        approve_plugin_authority: OraclePluginValidation,
+        /// Validation for the revoke plugin authority lifecycle action, by target plugin type.
+        revoke_plugin_authority: OraclePluginValidation,
+        /// Validation for the compress lifecycle action.
+        compress: ExternalValidationResult,
+        /// Validation for the decompress lifecycle action.
+        decompress: ExternalValidationResult,
+    },
 }
 
 impl OracleValidation {
-    /// Borsh- and Anchor-serialized size of the `OracleValidation` struct.
+    /// Borsh- and Anchor-serialized size of the smallest initialized `OracleValidation` struct
+    /// (`V1`).  `V2` accounts are larger and depend on the number of per-plugin-type results.
     pub fn serialized_size() -> usize {
         5
     }
 }
+
+/// Validation results for a plugin lifecycle action in an Oracle account, selected by the type of
+/// the plugin the action targets.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, Eq, PartialEq)]
+pub struct OraclePluginValidation {
+    /// Validation for plugin types that are not listed in `plugin_types`.
+    pub default: ExternalValidationResult,
+    /// Validation for specific plugin types.
+    pub plugin_types: Vec<(PluginType, ExternalValidationResult)>,
+}
+
+impl OraclePluginValidation {
+    fn validate(&self, ctx: &PluginValidationContext) -> Result<ValidationResult, ProgramError> {
+        let target_plugin_type = ctx.target_plugin.map(PluginType::from);
+        let result = self
+            .plugin_types
+            .iter()
+            .find(|(plugin_type, _)| Some(*plugin_type) == target_plugin_type)
+            .map_or(&self.default, |(_, result)| result);
+
+        Ok(ValidationResult::from(result.clone()))
+    }
+}
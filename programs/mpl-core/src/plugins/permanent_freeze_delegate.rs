@@ -3,7 +3,7 @@ use solana_program::program_error::ProgramError;
 
 use crate::{
     plugins::{reject, PluginType},
-    state::{Authority, DataBlob},
+    state::DataBlob,
 };
 
 use super::{abstain, approve, PluginValidation, PluginValidationContext, ValidationResult};
@@ -63,6 +63,17 @@ impl PluginValidation for PermanentFreezeDelegate {
         }
     }
 
+    fn validate_execute(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.frozen {
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+
     fn validate_add_plugin(
         &self,
         ctx: &PluginValidationContext,
@@ -83,10 +94,7 @@ impl PluginValidation for PermanentFreezeDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == &(Authority::Address {
-                address: *ctx.authority_info.key,
-            })
+        if ctx.is_self_authority_signer()
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::PermanentFreezeDelegate
         {
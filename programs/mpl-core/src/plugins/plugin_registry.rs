@@ -4,7 +4,8 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 use std::{cmp::Ordering, collections::BTreeMap};
 
 use crate::{
-    plugins::validate_lifecycle_checks,
+    error::MplCoreError,
+    plugins::{validate_extra_accounts, validate_lifecycle_checks},
     state::{Authority, DataBlob, Key, SolanaAccount},
 };
 
@@ -76,11 +77,22 @@ impl PluginRegistryV1 {
 
 impl DataBlob for PluginRegistryV1 {
     fn get_initial_size() -> usize {
-        9
+        1 + 4 + 4
     }
 
     fn get_size(&self) -> usize {
-        9 //TODO: Fix this
+        1 + 4
+            + self
+                .registry
+                .iter()
+                .map(RegistryRecord::get_size)
+                .sum::<usize>()
+            + 4
+            + self
+                .external_registry
+                .iter()
+                .map(ExternalRegistryRecord::get_size)
+                .sum::<usize>()
     }
 }
 
@@ -95,13 +107,23 @@ impl SolanaAccount for PluginRegistryV1 {
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
 pub struct RegistryRecord {
     /// The type of plugin.
-    pub plugin_type: PluginType, // 2
+    pub plugin_type: PluginType, // 1
     /// The authority who has permission to utilize a plugin.
     pub authority: Authority, // Variable
     /// The offset to the plugin in the account.
     pub offset: usize, // 8
 }
 
+impl DataBlob for RegistryRecord {
+    fn get_initial_size() -> usize {
+        PluginType::get_initial_size() + Authority::get_initial_size() + 8
+    }
+
+    fn get_size(&self) -> usize {
+        self.plugin_type.get_size() + self.authority.get_size() + 8
+    }
+}
+
 impl RegistryRecord {
     /// Associated function for sorting `RegistryRecords` by offset.
     pub fn compare_offsets(a: &RegistryRecord, b: &RegistryRecord) -> Ordering {
@@ -127,7 +149,48 @@ pub struct ExternalRegistryRecord {
     pub data_len: Option<usize>,
 }
 
+impl DataBlob for ExternalRegistryRecord {
+    fn get_initial_size() -> usize {
+        1 + Authority::get_initial_size() + 1 + 8 + 1 + 1
+    }
+
+    fn get_size(&self) -> usize {
+        // Each lifecycle check is a 1 byte event and a 4 byte result bitfield.
+        let lifecycle_checks_size = match &self.lifecycle_checks {
+            Some(checks) => 1 + 4 + checks.len() * (1 + 4),
+            None => 1,
+        };
+        let option_usize_size = |value: &Option<usize>| match value {
+            Some(_) => 1 + 8,
+            None => 1,
+        };
+
+        1 + self.authority.get_size()
+            + lifecycle_checks_size
+            + 8
+            + option_usize_size(&self.data_offset)
+            + option_usize_size(&self.data_len)
+    }
+}
+
 impl ExternalRegistryRecord {
+    /// Shift the offsets of the plugin and its data by the given amount.
+    pub(crate) fn bump_offsets(&mut self, size_diff: isize) -> ProgramResult {
+        let new_offset = (self.offset as isize)
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+        self.offset = new_offset as usize;
+
+        if let Some(data_offset) = self.data_offset {
+            let new_data_offset = (data_offset as isize)
+                .checked_add(size_diff)
+                .ok_or(MplCoreError::NumericalOverflow)?;
+            self.data_offset = Some(new_data_offset as usize);
+        }
+
+        Ok(())
+    }
+
     /// Update the adapter registry record with the new info, if relevant.
     pub fn update(&mut self, update_info: &ExternalPluginAdapterUpdateInfo) -> ProgramResult {
         match update_info {
@@ -136,6 +199,7 @@ impl ExternalRegistryRecord {
                     validate_lifecycle_checks(checks, false)?;
                     self.lifecycle_checks = update_info.lifecycle_checks.clone()
                 }
+                validate_extra_accounts(&update_info.extra_accounts)?;
             }
             ExternalPluginAdapterUpdateInfo::Oracle(update_info) => {
                 if let Some(checks) = &update_info.lifecycle_checks {
@@ -1,9 +1,17 @@
 use std::collections::HashSet;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction,
+    sysvar::instructions::{self, load_current_index_checked, load_instruction_at_checked},
+};
 
-use crate::{error::MplCoreError, plugins::PluginType, state::Authority};
+use crate::{error::MplCoreError, plugins::PluginType};
 
 use super::{
     abstain, approve, reject, Plugin, PluginValidation, PluginValidationContext, ValidationResult,
@@ -25,6 +33,29 @@ pub enum RuleSet {
     ProgramAllowList(Vec<Pubkey>),
     /// Deny list of programs that are not allowed to transfer, receive, or send the asset.
     ProgramDenyList(Vec<Pubkey>),
+    /// Allow list of programs that are allowed to invoke a transfer of the asset. The invoking
+    /// program is the program of the top-level instruction read from the instructions sysvar,
+    /// which is MPL Core itself when the transfer is not made through a CPI.
+    InvokingProgramAllowList(Vec<Pubkey>),
+    /// Deny list of programs that are not allowed to invoke a transfer of the asset. The invoking
+    /// program is the program of the top-level instruction read from the instructions sysvar.
+    InvokingProgramDenyList(Vec<Pubkey>),
+}
+
+impl RuleSet {
+    /// Find the program of the currently executing top-level instruction. The instructions
+    /// sysvar must be among the accounts passed to the instruction.
+    fn invoking_program(accounts: &[AccountInfo]) -> Result<Pubkey, ProgramError> {
+        let instructions_info = accounts
+            .iter()
+            .find(|account| instructions::check_id(account.key))
+            .ok_or(MplCoreError::MissingInstructionsSysvar)?;
+
+        let current_index = load_current_index_checked(instructions_info)?;
+        let instruction = load_instruction_at_checked(current_index as usize, instructions_info)?;
+
+        Ok(instruction.program_id)
+    }
 }
 
 /// Traditional royalties structure for an asset.
@@ -36,6 +67,54 @@ pub struct Royalties {
     creators: Vec<Creator>,
     /// The rule set for the asset to enforce royalties.
     rule_set: RuleSet,
+    /// Whether the creators opted in to being paid their royalties by `TransferV1` on sales with
+    /// a sale price.  The rule set is enforced either way.
+    royalty_payments: bool,
+}
+
+impl Royalties {
+    /// Pay the royalties owed on a sale of `sale_price` lamports from the payer to the creators.
+    /// Royalties are only paid when the creators opted in with `royalty_payments`.
+    /// Each creator must have their account passed in `creator_accounts`.
+    pub(crate) fn pay<'a>(
+        &self,
+        sale_price: u64,
+        payer: &AccountInfo<'a>,
+        creator_accounts: &[AccountInfo<'a>],
+        system_program: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !self.royalty_payments {
+            return Ok(());
+        }
+
+        for creator in &self.creators {
+            if creator.percentage == 0 || self.basis_points == 0 {
+                continue;
+            }
+
+            let creator_info = creator_accounts
+                .iter()
+                .find(|account| account.key == &creator.address)
+                .ok_or(MplCoreError::InvalidRoyaltyCreator)?;
+
+            // The share is at most the sale price so it always fits in a u64.
+            let share =
+                ((sale_price as u128) * self.basis_points as u128 * creator.percentage as u128
+                    / 1_000_000) as u64;
+
+            // A sale price too low to pay every creator would let royalties be skipped.
+            if share == 0 {
+                return Err(MplCoreError::RoyaltyUnderpaid.into());
+            }
+
+            invoke(
+                &system_instruction::transfer(payer.key, creator_info.key, share),
+                &[payer.clone(), creator_info.clone(), system_program.clone()],
+            )?;
+        }
+
+        Ok(())
+    }
 }
 
 fn validate_royalties(royalties: &Royalties) -> Result<ValidationResult, ProgramError> {
@@ -99,6 +178,20 @@ impl PluginValidation for Royalties {
                     abstain!()
                 }
             }
+            RuleSet::InvokingProgramAllowList(allow_list) => {
+                if allow_list.contains(&RuleSet::invoking_program(ctx.accounts)?) {
+                    abstain!()
+                } else {
+                    reject!()
+                }
+            }
+            RuleSet::InvokingProgramDenyList(deny_list) => {
+                if deny_list.contains(&RuleSet::invoking_program(ctx.accounts)?) {
+                    reject!()
+                } else {
+                    abstain!()
+                }
+            }
         }
     }
 
@@ -138,10 +231,7 @@ impl PluginValidation for Royalties {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == &(Authority::Address {
-                address: *ctx.authority_info.key,
-            })
+        if ctx.is_self_authority_signer()
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::Royalties
         {
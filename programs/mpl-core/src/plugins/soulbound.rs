@@ -0,0 +1,58 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::state::{Authority, DataBlob};
+
+use super::{
+    abstain, approve, reject, PluginType, PluginValidation, PluginValidationContext,
+    ValidationResult,
+};
+
+/// The soulbound plugin makes the asset non-transferable while still allowing it to be burned.
+/// The plugin authority acts as a recovery authority that can still move the asset, for example
+/// after a lost wallet. Recovery does not override other plugins, so a frozen asset stays frozen.
+/// The default authority for this plugin is `None`, which disables recovery entirely.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Soulbound {}
+
+impl DataBlob for Soulbound {
+    fn get_initial_size() -> usize {
+        0
+    }
+
+    fn get_size(&self) -> usize {
+        0
+    }
+}
+
+impl PluginValidation for Soulbound {
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // This plugin can only be added at creation time, so we
+        // always reject it.
+        match ctx.target_plugin {
+            Some(plugin) if PluginType::from(plugin) == PluginType::Soulbound => reject!(),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // The owner can never act as the recovery authority, otherwise the asset
+        // would be freely transferable.
+        if *ctx.self_authority != Authority::Owner {
+            if let Some(resolved_authorities) = ctx.resolved_authorities {
+                if resolved_authorities.contains(ctx.self_authority) {
+                    return approve!();
+                }
+            }
+        }
+
+        reject!()
+    }
+}
@@ -0,0 +1,105 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
+
+use crate::{plugins::PluginType, state::DataBlob};
+
+use super::{abstain, reject, Plugin, PluginValidation, PluginValidationContext, ValidationResult};
+
+/// The point in time at which a time lock expires.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum LockExpiry {
+    /// The lock expires once the cluster's unix timestamp reaches this value.
+    UnixTimestamp(i64),
+    /// The lock expires once the cluster reaches this slot.
+    Slot(u64),
+}
+
+/// The time lock plugin prevents the asset from being transferred or burned until its expiry
+/// passes, after which it unlocks without any further action.  The expiry cannot be updated.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct TimeLock {
+    /// The point in time at which the asset unlocks.
+    pub expiry: LockExpiry, // 9
+}
+
+impl TimeLock {
+    /// Check if the lock has not yet expired according to the `Clock` sysvar.
+    pub fn is_locked(&self) -> Result<bool, ProgramError> {
+        let clock = Clock::get()?;
+        Ok(match self.expiry {
+            LockExpiry::UnixTimestamp(timestamp) => clock.unix_timestamp < timestamp,
+            LockExpiry::Slot(slot) => clock.slot < slot,
+        })
+    }
+
+    fn validate_locked(&self) -> Result<ValidationResult, ProgramError> {
+        if self.is_locked()? {
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+}
+
+impl DataBlob for TimeLock {
+    fn get_initial_size() -> usize {
+        9
+    }
+
+    fn get_size(&self) -> usize {
+        9
+    }
+}
+
+impl PluginValidation for TimeLock {
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // This plugin can only be added at creation time, so we
+        // always reject it.
+        match ctx.target_plugin {
+            Some(plugin) if PluginType::from(plugin) == PluginType::TimeLock => reject!(),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::TimeLock(_)) => self.validate_locked(),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // The expiry cannot be changed after creation, otherwise an expired
+        // lock could be locked again.
+        match ctx.target_plugin {
+            Some(Plugin::TimeLock(_)) => reject!(),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_locked()
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_locked()
+    }
+}
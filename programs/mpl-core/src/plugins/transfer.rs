@@ -1,10 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_error::ProgramError;
 
-use crate::{
-    plugins::PluginType,
-    state::{Authority, DataBlob},
-};
+use crate::{plugins::PluginType, state::DataBlob};
 
 use super::{abstain, approve, PluginValidation, PluginValidationContext, ValidationResult};
 
@@ -42,11 +39,7 @@ impl PluginValidation for TransferDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == (&Authority::Address {
-                address: *ctx.authority_info.key,
-            })
-        {
+        if ctx.is_self_authority_signer() {
             approve!()
         } else {
             abstain!()
@@ -57,11 +50,7 @@ impl PluginValidation for TransferDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == (&Authority::Address {
-                address: *ctx.authority_info.key,
-            })
-        {
+        if ctx.is_self_authority_signer() {
             approve!()
         } else {
             abstain!()
@@ -73,10 +62,7 @@ impl PluginValidation for TransferDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == &(Authority::Address {
-                address: *ctx.authority_info.key,
-            })
+        if ctx.is_self_authority_signer()
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::TransferDelegate
         {
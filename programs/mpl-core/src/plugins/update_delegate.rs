@@ -16,7 +16,8 @@ use super::{
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
 pub struct UpdateDelegate {
-    /// Additional update delegates.  Not currently available to be used.
+    /// Additional update delegates.  These have the same permissions as the plugin authority,
+    /// but only the plugin authority can manage the set through `UpdatePluginV1`.
     pub additional_delegates: Vec<Pubkey>, // 4
 }
 
@@ -27,6 +28,22 @@ impl UpdateDelegate {
             additional_delegates: vec![],
         }
     }
+
+    /// Check whether the signing authority is the plugin authority or an additional delegate.
+    fn is_delegate(&self, ctx: &PluginValidationContext) -> bool {
+        ctx.is_self_authority_signer() || self.additional_delegates.contains(ctx.authority_info.key)
+    }
+
+    /// Check whether the signing authority can act on the target plugin.  Additional delegates
+    /// cannot manage the `UpdateDelegate` plugin itself.
+    fn can_manage(&self, ctx: &PluginValidationContext, target_plugin: &Plugin) -> bool {
+        if ctx.is_self_authority_signer() {
+            return true;
+        }
+
+        self.additional_delegates.contains(ctx.authority_info.key)
+            && PluginType::from(target_plugin) != PluginType::UpdateDelegate
+    }
 }
 
 impl Default for UpdateDelegate {
@@ -37,40 +54,21 @@ impl Default for UpdateDelegate {
 
 impl DataBlob for UpdateDelegate {
     fn get_initial_size() -> usize {
-        0
+        4
     }
 
     fn get_size(&self) -> usize {
-        0
+        4 + self.additional_delegates.len() * 32
     }
 }
 
 impl PluginValidation for UpdateDelegate {
-    fn validate_create(
-        &self,
-        _ctx: &PluginValidationContext,
-    ) -> Result<ValidationResult, ProgramError> {
-        if !self.additional_delegates.is_empty() {
-            return Err(MplCoreError::NotAvailable.into());
-        }
-        abstain!()
-    }
-
     fn validate_add_plugin(
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
         if let Some(new_plugin) = ctx.target_plugin {
-            if let Plugin::UpdateDelegate(update_delegate) = new_plugin {
-                if !update_delegate.additional_delegates.is_empty() {
-                    return Err(MplCoreError::NotAvailable.into());
-                }
-            }
-
-            if ctx.self_authority
-                == (&Authority::Address {
-                    address: *ctx.authority_info.key,
-                })
+            if self.can_manage(ctx, new_plugin)
                 && new_plugin.manager() == Authority::UpdateAuthority
             {
                 approve!()
@@ -87,10 +85,7 @@ impl PluginValidation for UpdateDelegate {
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
         if let Some(plugin_to_remove) = ctx.target_plugin {
-            if ctx.self_authority
-                == (&Authority::Address {
-                    address: *ctx.authority_info.key,
-                })
+            if self.can_manage(ctx, plugin_to_remove)
                 && plugin_to_remove.manager() == Authority::UpdateAuthority
             {
                 approve!()
@@ -107,10 +102,7 @@ impl PluginValidation for UpdateDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == &(Authority::Address {
-                address: *ctx.authority_info.key,
-            })
+        if ctx.is_self_authority_signer()
             && ctx.target_plugin.is_some()
             && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::UpdateDelegate
         {
@@ -124,11 +116,7 @@ impl PluginValidation for UpdateDelegate {
         &self,
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
-        if ctx.self_authority
-            == (&Authority::Address {
-                address: *ctx.authority_info.key,
-            })
-        {
+        if self.is_delegate(ctx) {
             approve!()
         } else {
             abstain!()
@@ -140,12 +128,16 @@ impl PluginValidation for UpdateDelegate {
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
         let plugin_to_update = ctx.target_plugin.ok_or(MplCoreError::InvalidPlugin)?;
-        if let Plugin::UpdateDelegate(update_delegate) = plugin_to_update {
-            if !update_delegate.additional_delegates.is_empty() {
-                return Err(MplCoreError::NotAvailable.into());
-            }
-        }
 
-        abstain!()
+        // The plugin authority is already approved by the base validation, so only the
+        // additional delegates need to be checked here.
+        if self.additional_delegates.contains(ctx.authority_info.key)
+            && self.can_manage(ctx, plugin_to_update)
+            && plugin_to_update.manager() == Authority::UpdateAuthority
+        {
+            approve!()
+        } else {
+            abstain!()
+        }
     }
 }
@@ -0,0 +1,88 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar};
+
+use crate::state::DataBlob;
+
+use super::{
+    abstain, approve, reject, Plugin, PluginValidation, PluginValidationContext, ValidationResult,
+};
+
+/// The use delegate plugin grants usage rights over the asset to a user other than the owner
+/// until an expiry timestamp. While the rental is active the asset cannot be transferred or burned,
+/// and the owner cannot remove or shorten it. The user can end the rental early by removing it.
+/// The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct UseDelegate {
+    /// The address that currently holds the usage rights.
+    pub user: Pubkey, // 32
+    /// The unix timestamp at which the usage rights expire.
+    pub expiry: i64, // 8
+}
+
+impl UseDelegate {
+    /// Check if the usage rights have not yet expired according to the `Clock` sysvar.
+    pub fn is_active(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.unix_timestamp < self.expiry)
+    }
+
+    fn validate_inactive(&self) -> Result<ValidationResult, ProgramError> {
+        if self.is_active()? {
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+}
+
+impl DataBlob for UseDelegate {
+    fn get_initial_size() -> usize {
+        40
+    }
+
+    fn get_size(&self) -> usize {
+        40
+    }
+}
+
+impl PluginValidation for UseDelegate {
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::UseDelegate(_)) if self.is_active()? => {
+                if ctx.authority_info.key == &self.user {
+                    approve!()
+                } else {
+                    reject!()
+                }
+            }
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::UseDelegate(_)) => self.validate_inactive(),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_inactive()
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_inactive()
+    }
+}
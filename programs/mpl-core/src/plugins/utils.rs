@@ -13,9 +13,9 @@ use crate::{
 };
 
 use super::{
-    ExternalPluginAdapter, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey,
-    ExternalPluginAdapterType, ExternalRegistryRecord, Plugin, PluginHeaderV1, PluginRegistryV1,
-    PluginType, RegistryRecord,
+    DataStore, ExternalPluginAdapter, ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey,
+    ExternalPluginAdapterType, ExternalRegistryRecord, ExtraAccount, LifecycleHook, Plugin,
+    PluginHeaderV1, PluginRegistryV1, PluginType, RegistryRecord,
 };
 
 /// Create plugin header and registry if it doesn't exist
@@ -141,7 +141,11 @@ pub fn fetch_plugin<T: DataBlob + SolanaAccount, U: BorshDeserialize>(
     )?;
 
     // Return the plugin and its authority.
-    Ok((registry_record.authority, inner, registry_record.offset))
+    Ok((
+        registry_record.authority.clone(),
+        inner,
+        registry_record.offset,
+    ))
 }
 
 /// Fetch the plugin from the registry.
@@ -177,7 +181,7 @@ pub fn fetch_wrapped_plugin<T: DataBlob + SolanaAccount>(
     let plugin = Plugin::deserialize(&mut &(*account.data).borrow()[registry_record.offset..])?;
 
     // Return the plugin and its authority.
-    Ok((registry_record.authority, plugin))
+    Ok((registry_record.authority.clone(), plugin))
 }
 
 /// Fetch the external plugin adapter from the registry.
@@ -211,7 +215,7 @@ pub fn fetch_wrapped_external_plugin_adapter<T: DataBlob + SolanaAccount>(
             ExternalPluginAdapter::deserialize(&mut &(*account.data).borrow()[record.offset..])?;
 
         // Return the plugin and its authority.
-        Ok((record.authority, plugin))
+        Ok((record.authority.clone(), plugin))
     } else {
         Err(MplCoreError::ExternalPluginAdapterNotFound.into())
     }
@@ -260,6 +264,8 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
     payer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
 ) -> ProgramResult {
+    authority.validate()?;
+
     let core = T::load(account, 0)?;
     let header_offset = core.get_size();
     let plugin_type = plugin.into();
@@ -280,7 +286,7 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
     let new_registry_record = RegistryRecord {
         plugin_type,
         offset: old_registry_offset,
-        authority: *authority,
+        authority: authority.clone(),
     };
 
     let size_increase = plugin_size
@@ -322,16 +328,6 @@ pub fn initialize_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
     let header_offset = core.get_size();
     let plugin_type = init_info.into();
 
-    // Note currently we are blocking adding LifecycleHook and DataStore external plugin adapters as they
-    // are still in development.
-    match init_info {
-        ExternalPluginAdapterInitInfo::LifecycleHook(_)
-        | ExternalPluginAdapterInitInfo::DataStore(_) => {
-            return Err(MplCoreError::NotAvailable.into());
-        }
-        ExternalPluginAdapterInitInfo::Oracle(_) => (),
-    }
-
     // You cannot add a duplicate plugin.
     for record in plugin_registry.external_registry.iter() {
         if ExternalPluginAdapterKey::from_record(account, record)?
@@ -344,20 +340,21 @@ pub fn initialize_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
     let (authority, lifecycle_checks) = match init_info {
         ExternalPluginAdapterInitInfo::LifecycleHook(init_info) => {
             validate_lifecycle_checks(&init_info.lifecycle_checks, false)?;
+            validate_extra_accounts(&init_info.extra_accounts)?;
             (
-                init_info.init_plugin_authority,
+                init_info.init_plugin_authority.clone(),
                 Some(init_info.lifecycle_checks.clone()),
             )
         }
         ExternalPluginAdapterInitInfo::Oracle(init_info) => {
             validate_lifecycle_checks(&init_info.lifecycle_checks, true)?;
             (
-                init_info.init_plugin_authority,
+                init_info.init_plugin_authority.clone(),
                 Some(init_info.lifecycle_checks.clone()),
             )
         }
         ExternalPluginAdapterInitInfo::DataStore(init_info) => {
-            (init_info.init_plugin_authority, None)
+            (init_info.init_plugin_authority.clone(), None)
         }
     };
 
@@ -372,20 +369,37 @@ pub fn initialize_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
         data_len: None,
     };
 
-    let mut plugin = ExternalPluginAdapter::from(init_info);
+    new_registry_record.authority.validate()?;
+
+    let plugin = ExternalPluginAdapter::from(init_info);
+    match &plugin {
+        ExternalPluginAdapter::LifecycleHook(LifecycleHook {
+            data_authority: Some(data_authority),
+            ..
+        })
+        | ExternalPluginAdapter::DataStore(DataStore { data_authority, .. }) => {
+            data_authority.validate()?
+        }
+        _ => (),
+    }
+
+    let plugin_metadata = plugin.try_to_vec()?;
+    let plugin_size = plugin_metadata.len();
 
     // If the plugin is a LifecycleHook or DataStore, then we need to set the data offset and length.
-    match &mut plugin {
+    // The data is stored immediately after the plugin.
+    match &plugin {
         ExternalPluginAdapter::LifecycleHook(_) | ExternalPluginAdapter::DataStore(_) => {
-            new_registry_record.data_offset = Some(old_registry_offset);
+            new_registry_record.data_offset = Some(
+                old_registry_offset
+                    .checked_add(plugin_size)
+                    .ok_or(MplCoreError::NumericalOverflow)?,
+            );
             new_registry_record.data_len = Some(0);
         }
         _ => {}
     };
 
-    let plugin_metadata = plugin.try_to_vec()?;
-    let plugin_size = plugin_metadata.len();
-
     let size_increase = plugin_size
         .checked_add(new_registry_record.try_to_vec()?.len())
         .ok_or(MplCoreError::NumericalOverflow)?;
@@ -442,6 +456,15 @@ pub(crate) fn validate_lifecycle_checks(
     Ok(())
 }
 
+pub(crate) fn validate_extra_accounts(extra_accounts: &Option<Vec<ExtraAccount>>) -> ProgramResult {
+    // Signatures are never forwarded to a hooked program, so an extra account cannot be a signer.
+    if extra_accounts.iter().flatten().any(ExtraAccount::is_signer) {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    Ok(())
+}
+
 /// Remove a plugin from the registry and delete it.
 pub fn delete_plugin<'a, T: DataBlob>(
     plugin_type: &PluginType,
@@ -514,7 +537,7 @@ pub fn delete_plugin<'a, T: DataBlob>(
 
         for record in &mut plugin_registry.external_registry {
             if plugin_offset < record.offset {
-                record.offset -= serialized_plugin.len()
+                record.bump_offsets(-(serialized_plugin.len() as isize))?;
             }
         }
 
@@ -553,11 +576,17 @@ pub fn delete_external_plugin_adapter<'a, T: DataBlob>(
         // Fetch the offset of the plugin to be removed.
         let plugin_offset = registry_record.offset;
         let plugin = ExternalPluginAdapter::load(account, plugin_offset)?;
-        let serialized_plugin = plugin.try_to_vec()?;
+
+        // The plugin's data, if any, is removed along with the plugin.
+        let plugin_size = plugin
+            .try_to_vec()?
+            .len()
+            .checked_add(registry_record.data_len.unwrap_or(0))
+            .ok_or(MplCoreError::NumericalOverflow)?;
 
         // Get the offset of the plugin after the one being removed.
         let next_plugin_offset = plugin_offset
-            .checked_add(serialized_plugin.len())
+            .checked_add(plugin_size)
             .ok_or(MplCoreError::NumericalOverflow)?;
 
         // Calculate the new size of the account.
@@ -565,12 +594,12 @@ pub fn delete_external_plugin_adapter<'a, T: DataBlob>(
             .data_len()
             .checked_sub(serialized_registry_record.len())
             .ok_or(MplCoreError::NumericalOverflow)?
-            .checked_sub(serialized_plugin.len())
+            .checked_sub(plugin_size)
             .ok_or(MplCoreError::NumericalOverflow)?;
 
         let new_registry_offset = header
             .plugin_registry_offset
-            .checked_sub(serialized_plugin.len())
+            .checked_sub(plugin_size)
             .ok_or(MplCoreError::NumericalOverflow)?;
 
         let data_to_move = header
@@ -592,13 +621,13 @@ pub fn delete_external_plugin_adapter<'a, T: DataBlob>(
         // Move offsets for existing registry records.
         for record in &mut plugin_registry.external_registry {
             if plugin_offset < record.offset {
-                record.offset -= serialized_plugin.len()
+                record.bump_offsets(-(plugin_size as isize))?;
             }
         }
 
         for record in &mut plugin_registry.registry {
             if plugin_offset < record.offset {
-                record.offset -= serialized_plugin.len()
+                record.offset -= plugin_size
             }
         }
 
@@ -629,15 +658,20 @@ pub fn approve_authority_on_plugin<'a, T: CoreAsset>(
         .find(|record| record.plugin_type == *plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
-    registry_record.authority = *new_authority;
+    new_authority.validate()?;
 
-    let authority_bytes = new_authority.try_to_vec()?;
+    let old_authority_bytes = registry_record.authority.try_to_vec()?;
+    registry_record.authority = new_authority.clone();
+    let new_authority_bytes = new_authority.try_to_vec()?;
 
-    let new_size = account
-        .data_len()
-        .checked_add(authority_bytes.len())
+    let size_diff = (new_authority_bytes.len() as isize)
+        .checked_sub(old_authority_bytes.len() as isize)
         .ok_or(MplCoreError::NumericalOverflow)?;
-    resize_or_reallocate_account(account, payer, system_program, new_size)?;
+
+    let new_size = (account.data_len() as isize)
+        .checked_add(size_diff)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
 
     plugin_registry.save(account, plugin_header.plugin_registry_offset)?;
 
@@ -9,10 +9,13 @@ use crate::{
     },
     plugins::{
         create_meta_idempotent, initialize_external_plugin_adapter, ExternalPluginAdapter,
-        ExternalPluginAdapterInitInfo, Plugin, PluginType, PluginValidationContext,
-        ValidationResult,
+        ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey, Plugin, PluginType,
+        PluginValidationContext, ValidationResult,
+    },
+    state::{
+        AssetV1, Authority, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1,
+        SolanaAccount,
     },
-    state::{AssetV1, Authority, CollectionV1, DataBlob, Key, SolanaAccount},
     utils::{
         load_key, resolve_authority, validate_asset_permissions, validate_collection_permissions,
     },
@@ -98,6 +101,15 @@ pub(crate) fn add_external_plugin_adapter<'a>(
         ctx.accounts.payer,
         ctx.accounts.system_program,
         &args.init_info,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::AddExternalPluginAdapter {
+            key: ExternalPluginAdapterKey::from(&args.init_info),
+        },
     )
 }
 
@@ -169,6 +181,15 @@ pub(crate) fn add_collection_external_plugin_adapter<'a>(
         ctx.accounts.payer,
         ctx.accounts.system_program,
         &args.init_info,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::AddExternalPluginAdapter {
+            key: ExternalPluginAdapterKey::from(&args.init_info),
+        },
     )
 }
 
@@ -6,10 +6,13 @@ use crate::{
     error::MplCoreError,
     instruction::accounts::{AddCollectionPluginV1Accounts, AddPluginV1Accounts},
     plugins::{
-        create_meta_idempotent, initialize_plugin, Plugin, PluginType, PluginValidationContext,
-        ValidationResult,
+        create_meta_idempotent, initialize_plugin, ExternalPluginAdapter, HookableLifecycleEvent,
+        Plugin, PluginType, PluginValidationContext, ValidationResult,
+    },
+    state::{
+        AssetV1, Authority, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1,
+        SolanaAccount,
     },
-    state::{AssetV1, Authority, CollectionV1, DataBlob, Key, SolanaAccount},
     utils::{
         load_key, resolve_authority, validate_asset_permissions, validate_collection_permissions,
     },
@@ -59,7 +62,7 @@ pub(crate) fn add_plugin<'a>(
         accounts,
         asset_info: Some(ctx.accounts.asset),
         collection_info: ctx.accounts.collection,
-        self_authority: &args.init_authority.unwrap_or(args.plugin.manager()),
+        self_authority: &args.init_authority.clone().unwrap_or(args.plugin.manager()),
         authority_info: authority,
         resolved_authorities: None,
         new_owner: None,
@@ -84,19 +87,30 @@ pub(crate) fn add_plugin<'a>(
         AssetV1::validate_add_plugin,
         CollectionV1::validate_add_plugin,
         Plugin::validate_add_plugin,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_add_plugin),
+        Some(HookableLifecycleEvent::AddPlugin),
     )?;
 
     // Increment sequence number and save only if it is `Some(_)`.
     asset.increment_seq_and_save(ctx.accounts.asset)?;
 
+    let authority = args.init_authority.unwrap_or(args.plugin.manager());
     process_add_plugin::<AssetV1>(
         ctx.accounts.asset,
         ctx.accounts.payer,
         ctx.accounts.system_program,
         &args.plugin,
-        &args.init_authority.unwrap_or(args.plugin.manager()),
+        &authority,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::AddPlugin {
+            plugin_type,
+            authority,
+        },
     )
 }
 
@@ -131,7 +145,7 @@ pub(crate) fn add_collection_plugin<'a>(
         accounts,
         asset_info: None,
         collection_info: Some(ctx.accounts.collection),
-        self_authority: &args.init_authority.unwrap_or(args.plugin.manager()),
+        self_authority: &args.init_authority.clone().unwrap_or(args.plugin.manager()),
         authority_info: authority,
         resolved_authorities: None,
         new_owner: None,
@@ -157,16 +171,27 @@ pub(crate) fn add_collection_plugin<'a>(
         PluginType::check_add_plugin,
         CollectionV1::validate_add_plugin,
         Plugin::validate_add_plugin,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_add_plugin),
+        Some(HookableLifecycleEvent::AddPlugin),
     )?;
 
+    let authority = args.init_authority.unwrap_or(args.plugin.manager());
     process_add_plugin::<CollectionV1>(
         ctx.accounts.collection,
         ctx.accounts.payer,
         ctx.accounts.system_program,
         &args.plugin,
-        &args.init_authority.unwrap_or(args.plugin.manager()),
+        &authority,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::AddPlugin {
+            plugin_type: PluginType::from(&args.plugin),
+            authority,
+        },
     )
 }
 
@@ -7,8 +7,14 @@ use crate::{
     instruction::accounts::{
         ApproveCollectionPluginAuthorityV1Accounts, ApprovePluginAuthorityV1Accounts,
     },
-    plugins::{approve_authority_on_plugin, fetch_wrapped_plugin, Plugin, PluginType},
-    state::{AssetV1, Authority, CollectionV1, CoreAsset, DataBlob, Key, SolanaAccount},
+    plugins::{
+        approve_authority_on_plugin, fetch_wrapped_plugin, ExternalPluginAdapter,
+        HookableLifecycleEvent, Plugin, PluginType,
+    },
+    state::{
+        AssetV1, Authority, CollectionV1, CoreAsset, DataBlob, Key, MplCoreEvent,
+        MplCoreEventPayloadV1, SolanaAccount,
+    },
     utils::{
         fetch_core_data, load_key, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
@@ -64,8 +70,8 @@ pub(crate) fn approve_plugin_authority<'a>(
         AssetV1::validate_approve_plugin_authority,
         CollectionV1::validate_approve_plugin_authority,
         Plugin::validate_approve_plugin_authority,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_approve_plugin_authority),
+        Some(HookableLifecycleEvent::ApprovePluginAuthority),
     )?;
 
     // Increment sequence number and save only if it is `Some(_)`.
@@ -77,6 +83,16 @@ pub(crate) fn approve_plugin_authority<'a>(
         ctx.accounts.system_program,
         &args.plugin_type,
         &args.new_authority,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::ApprovePluginAuthority {
+            plugin_type: args.plugin_type,
+            new_authority: args.new_authority,
+        },
     )
 }
 
@@ -121,8 +137,8 @@ pub(crate) fn approve_collection_plugin_authority<'a>(
         PluginType::check_approve_plugin_authority,
         CollectionV1::validate_approve_plugin_authority,
         Plugin::validate_approve_plugin_authority,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_approve_plugin_authority),
+        Some(HookableLifecycleEvent::ApprovePluginAuthority),
     )?;
 
     process_approve_plugin_authority::<CollectionV1>(
@@ -131,6 +147,16 @@ pub(crate) fn approve_collection_plugin_authority<'a>(
         ctx.accounts.system_program,
         &args.plugin_type,
         &args.new_authority,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::ApprovePluginAuthority {
+            plugin_type: args.plugin_type,
+            new_authority: args.new_authority,
+        },
     )
 }
 
@@ -1,12 +1,15 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::{BurnCollectionV1Accounts, BurnV1Accounts},
     plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
-    state::{AssetV1, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable},
+    state::{
+        AssetV1, CollectionV1, CompressionProof, Key, MplCoreEvent, MplCoreEventPayloadV1,
+        SolanaAccount, Wrappable,
+    },
     utils::{
         close_program_account, load_key, rebuild_account_state_from_proof_data, resolve_authority,
         validate_asset_permissions, validate_collection_permissions, verify_proof,
@@ -43,7 +46,7 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
         }
     }
 
-    match load_key(ctx.accounts.asset, 0)? {
+    let compression_proof = match load_key(ctx.accounts.asset, 0)? {
         Key::HashedAssetV1 => {
             let mut compression_proof = args
                 .compression_proof
@@ -71,19 +74,14 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
             // instruction.
             compression_proof.seq = compression_proof.seq.saturating_add(1);
 
-            // Send the spl-noop event for indexing the compressed asset.
-            compression_proof.wrap()?;
-
-            // TODO Enable compressed burn.
-            msg!("Error: Burning compressed is currently not available");
-            return Err(MplCoreError::NotAvailable.into());
+            Some(compression_proof)
         }
-        Key::AssetV1 => (),
+        Key::AssetV1 => None,
         _ => return Err(MplCoreError::IncorrectAccount.into()),
-    }
+    };
 
     // Validate asset permissions.
-    let _ = validate_asset_permissions(
+    let (asset, _, _) = validate_asset_permissions(
         accounts,
         authority,
         ctx.accounts.asset,
@@ -106,7 +104,18 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
         collection.decrement()?;
         collection.save(ctx.accounts.collection.unwrap(), 0)?;
     };
-    Ok(())
+
+    match compression_proof {
+        // Send the final spl-noop event so indexers can drop the compressed asset.
+        Some(compression_proof) => compression_proof.wrap(),
+        // Like the compressed path, the burn event carries the next sequence number.
+        None => MplCoreEvent::emit_v1(
+            ctx.accounts.log_wrapper,
+            ctx.accounts.asset.key,
+            asset.seq.map(|seq| seq.saturating_add(1)),
+            MplCoreEventPayloadV1::Burn,
+        ),
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
@@ -146,7 +155,14 @@ pub(crate) fn burn_collection<'a>(
         Some(HookableLifecycleEvent::Burn),
     )?;
 
-    process_burn(ctx.accounts.collection, ctx.accounts.payer)
+    process_burn(ctx.accounts.collection, ctx.accounts.payer)?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::Burn,
+    )
 }
 
 fn process_burn<'a>(core_info: &AccountInfo<'a>, authority: &AccountInfo<'a>) -> ProgramResult {
@@ -1,4 +1,4 @@
-use solana_program::{rent::Rent, system_program, sysvar::Sysvar};
+use solana_program::{program_error::ProgramError, rent::Rent, system_program, sysvar::Sysvar};
 
 use super::*;
 use crate::state::{DataBlob, COLLECT_RECIPIENT1, COLLECT_RECIPIENT2};
@@ -6,7 +6,7 @@ use crate::state::{DataBlob, COLLECT_RECIPIENT1, COLLECT_RECIPIENT2};
 use crate::{
     error::MplCoreError,
     instruction::accounts::CollectAccounts,
-    state::{AssetV1, HashedAssetV1, Key},
+    state::{AssetV1, CollectionV1, HashedAssetV1, Key, SolanaAccount},
     utils::{fetch_core_data, load_key},
     ID,
 };
@@ -56,25 +56,7 @@ fn collect_from_account(
             (fee_amount, uninitialized_rent)
         }
         Key::AssetV1 => {
-            let (asset, header, registry) = fetch_core_data::<AssetV1>(account_info)?;
-            let header_size = match header {
-                Some(header) => header.get_size(),
-                None => 0,
-            };
-
-            let registry_size = match registry {
-                Some(registry) => registry.get_size(),
-                None => 0,
-            };
-
-            let asset_rent = rent.minimum_balance(
-                asset
-                    .get_size()
-                    .checked_add(header_size)
-                    .ok_or(MplCoreError::NumericalOverflowError)?
-                    .checked_add(registry_size)
-                    .ok_or(MplCoreError::NumericalOverflowError)?,
-            );
+            let asset_rent = rent.minimum_balance(core_account_size::<AssetV1>(account_info)?);
             let fee_amount = account_info
                 .lamports()
                 .checked_sub(asset_rent)
@@ -82,6 +64,16 @@ fn collect_from_account(
 
             (fee_amount, asset_rent)
         }
+        Key::CollectionV1 => {
+            let collection_rent =
+                rent.minimum_balance(core_account_size::<CollectionV1>(account_info)?);
+            let fee_amount = account_info
+                .lamports()
+                .checked_sub(collection_rent)
+                .ok_or(MplCoreError::NumericalOverflowError)?;
+
+            (fee_amount, collection_rent)
+        }
         Key::HashedAssetV1 => {
             // TODO use DataBlob trait instead?
             let hashed_rent = rent.minimum_balance(HashedAssetV1::LENGTH);
@@ -116,3 +108,20 @@ fn collect_from_account(
 
     Ok(())
 }
+
+/// Compute the serialized size of an asset or collection account, including its plugin header,
+/// the plugins stored after it and the plugin registry at the end of the account.
+fn core_account_size<T: DataBlob + SolanaAccount>(
+    account_info: &AccountInfo,
+) -> Result<usize, ProgramError> {
+    let (core, header, registry) = fetch_core_data::<T>(account_info)?;
+
+    match (header, registry) {
+        // The registry offset already covers the core data, the header and all plugin data.
+        (Some(header), Some(registry)) => header
+            .plugin_registry_offset
+            .checked_add(registry.get_size())
+            .ok_or(MplCoreError::NumericalOverflowError.into()),
+        _ => Ok(core.get_size()),
+    }
+}
@@ -1,11 +1,11 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::CompressV1Accounts,
-    plugins::{Plugin, PluginType},
+    plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
     state::{AssetV1, CollectionV1, Key, Wrappable},
     utils::{
         compress_into_account_space, fetch_core_data, load_key, resolve_authority,
@@ -57,11 +57,12 @@ pub(crate) fn compress<'a>(
                 AssetV1::validate_compress,
                 CollectionV1::validate_compress,
                 Plugin::validate_compress,
-                None,
-                None,
+                Some(ExternalPluginAdapter::validate_compress),
+                Some(HookableLifecycleEvent::Compress),
             )?;
 
-            // Compress the asset and plugin registry into account space.
+            // Compress the asset and plugin registry into account space.  Any rent freed by
+            // shrinking the account is returned to the payer.
             let compression_proof = compress_into_account_space(
                 asset,
                 plugin_registry,
@@ -71,11 +72,7 @@ pub(crate) fn compress<'a>(
             )?;
 
             // Send the spl-noop event for indexing the compressed asset.
-            compression_proof.wrap()?;
-
-            // TODO Enable compression.
-            msg!("Error: Compression currently not available");
-            Err(MplCoreError::NotAvailable.into())
+            compression_proof.wrap()
         }
         Key::HashedAssetV1 => Err(MplCoreError::AlreadyCompressed.into()),
         _ => Err(MplCoreError::IncorrectAccount.into()),
@@ -1,7 +1,9 @@
+use std::io::Read;
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
     program_memory::sol_memcpy, rent::Rent, system_instruction, system_program, sysvar::Sysvar,
 };
 
@@ -15,9 +17,10 @@ use crate::{
         PluginValidationContext, ValidationResult,
     },
     state::{
-        AssetV1, Authority, CollectionV1, DataState, SolanaAccount, UpdateAuthority, COLLECT_AMOUNT,
+        AssetV1, Authority, CollectionV1, DataState, MplCoreEvent, MplCoreEventPayloadV1,
+        SolanaAccount, UpdateAuthority, COLLECT_AMOUNT,
     },
-    utils::resolve_authority,
+    utils::{assert_master_edition_supply, deserialize_trailing_option, resolve_authority},
 };
 
 #[repr(C)]
@@ -30,13 +33,40 @@ pub(crate) struct CreateV1Args {
 }
 
 #[repr(C)]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, PartialEq, Eq, Debug, Clone)]
 pub(crate) struct CreateV2Args {
     pub(crate) data_state: DataState,
     pub(crate) name: String,
     pub(crate) uri: String,
     pub(crate) plugins: Option<Vec<PluginAuthorityPair>>,
     pub(crate) external_plugin_adapters: Option<Vec<ExternalPluginAdapterInitInfo>>,
+    /// When true, the number of the `Edition` plugin is assigned from the mint counter of the
+    /// collection's `MasterEdition` instead of the number passed in by the client.
+    pub(crate) auto_number_edition: Option<bool>,
+}
+
+// The auto edition numbering flag was added after the original args, so it is read as `None`
+// when a client does not send it.
+impl BorshDeserialize for CreateV2Args {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let data_state = DataState::deserialize_reader(reader)?;
+        let name = String::deserialize_reader(reader)?;
+        let uri = String::deserialize_reader(reader)?;
+        let plugins = Option::<Vec<PluginAuthorityPair>>::deserialize_reader(reader)?;
+        let external_plugin_adapters =
+            Option::<Vec<ExternalPluginAdapterInitInfo>>::deserialize_reader(reader)?;
+
+        let auto_number_edition = deserialize_trailing_option(reader)?;
+
+        Ok(Self {
+            data_state,
+            name,
+            uri,
+            plugins,
+            external_plugin_adapters,
+            auto_number_edition,
+        })
+    }
 }
 
 impl From<CreateV1Args> for CreateV2Args {
@@ -47,6 +77,7 @@ impl From<CreateV1Args> for CreateV2Args {
             uri: item.uri,
             plugins: item.plugins,
             external_plugin_adapters: None,
+            auto_number_edition: None,
         }
     }
 }
@@ -105,6 +136,14 @@ pub(crate) fn process_create<'a>(
         return Err(MplCoreError::InvalidAuthority.into());
     }
 
+    let mut args = args;
+    if let (Some(collection_info), Some(collection)) = (ctx.accounts.collection, &collection) {
+        enforce_master_edition(collection_info, collection, &mut args)?;
+    } else if args.auto_number_edition == Some(true) {
+        msg!("Error: Edition numbers can only be assigned by a collection");
+        return Err(MplCoreError::MissingCollection.into());
+    }
+
     let new_asset = AssetV1::new(
         *ctx.accounts
             .owner
@@ -121,7 +160,7 @@ pub(crate) fn process_create<'a>(
         DataState::AccountState => serialized_data,
         DataState::LedgerState => {
             // TODO: Implement minting compressed.
-            solana_program::msg!("Error: Minting compressed is currently not available");
+            msg!("Error: Minting compressed is currently not available");
             return Err(MplCoreError::NotAvailable.into());
         }
     };
@@ -175,7 +214,10 @@ pub(crate) fn process_create<'a>(
                             accounts,
                             asset_info: Some(ctx.accounts.asset),
                             collection_info: ctx.accounts.collection,
-                            self_authority: &plugin.authority.unwrap_or(plugin.plugin.manager()),
+                            self_authority: &plugin
+                                .authority
+                                .clone()
+                                .unwrap_or(plugin.plugin.manager()),
                             authority_info: authority,
                             resolved_authorities: None,
                             new_owner: None,
@@ -189,7 +231,7 @@ pub(crate) fn process_create<'a>(
                     }
                     initialize_plugin::<AssetV1>(
                         &plugin.plugin,
-                        &plugin.authority.unwrap_or(plugin.plugin.manager()),
+                        &plugin.authority.clone().unwrap_or(plugin.plugin.manager()),
                         &mut plugin_header,
                         &mut plugin_registry,
                         ctx.accounts.asset,
@@ -254,5 +296,54 @@ pub(crate) fn process_create<'a>(
         collection.save(ctx.accounts.collection.unwrap(), 0)?;
     };
 
-    Ok(())
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        new_asset.seq,
+        MplCoreEventPayloadV1::Create {
+            owner: new_asset.owner,
+            update_authority: new_asset.update_authority,
+        },
+    )
+}
+
+// Enforce the collection's master edition supply and assign auto-numbered editions.
+fn enforce_master_edition(
+    collection_info: &AccountInfo,
+    collection: &CollectionV1,
+    args: &mut CreateV2Args,
+) -> ProgramResult {
+    let master_edition = assert_master_edition_supply(collection_info, collection)?;
+
+    if args.auto_number_edition != Some(true) {
+        return Ok(());
+    }
+
+    if master_edition.is_none() {
+        msg!("Error: Edition numbers can only be assigned by a collection with a master edition");
+        return Err(MplCoreError::PluginNotFound.into());
+    }
+
+    let edition = args
+        .plugins
+        .iter_mut()
+        .flatten()
+        .find_map(|plugin| match &mut plugin.plugin {
+            Plugin::Edition(edition) => Some(edition),
+            _ => None,
+        });
+
+    match edition {
+        Some(edition) => {
+            edition.number = collection
+                .num_minted
+                .checked_add(1)
+                .ok_or(MplCoreError::NumericalOverflow)?;
+            Ok(())
+        }
+        None => {
+            msg!("Error: Edition numbers can only be assigned to an asset with an edition plugin");
+            Err(MplCoreError::PluginNotFound.into())
+        }
+    }
 }
@@ -7,14 +7,14 @@ use solana_program::{
 
 use crate::{
     error::MplCoreError,
-    instruction::accounts::CreateCollectionV2Accounts,
+    instruction::accounts::CreateCollectionV1Accounts,
     plugins::{
         create_meta_idempotent, create_plugin_meta, initialize_external_plugin_adapter,
         initialize_plugin, CheckResult, ExternalCheckResultBits, ExternalPluginAdapter,
         ExternalPluginAdapterInitInfo, Plugin, PluginAuthorityPair, PluginType,
         PluginValidationContext, ValidationResult,
     },
-    state::{Authority, CollectionV1, Key},
+    state::{Authority, CollectionV1, Key, MplCoreEvent, MplCoreEventPayloadV1},
 };
 
 #[repr(C)]
@@ -62,8 +62,13 @@ pub(crate) fn process_create_collection<'a>(
     accounts: &'a [AccountInfo<'a>],
     args: CreateCollectionV2Args,
 ) -> ProgramResult {
-    // Accounts.
-    let ctx = CreateCollectionV2Accounts::context(accounts)?;
+    // Accounts.  The optional log wrapper was added after the first four accounts, so it is read
+    // from the remaining accounts to keep accepting callers that do not pass it.
+    let ctx = CreateCollectionV1Accounts::context(accounts)?;
+    let log_wrapper = ctx
+        .remaining_accounts
+        .first()
+        .filter(|log_wrapper| log_wrapper.key == &spl_noop::ID);
     let rent = Rent::get()?;
 
     // Guards.
@@ -137,7 +142,10 @@ pub(crate) fn process_create_collection<'a>(
                         accounts,
                         asset_info: None,
                         collection_info: Some(ctx.accounts.collection),
-                        self_authority: &plugin.authority.unwrap_or(plugin.plugin.manager()),
+                        self_authority: &plugin
+                            .authority
+                            .clone()
+                            .unwrap_or(plugin.plugin.manager()),
                         authority_info: ctx.accounts.payer,
                         resolved_authorities: None,
                         new_owner: None,
@@ -151,7 +159,7 @@ pub(crate) fn process_create_collection<'a>(
                 }
                 initialize_plugin::<CollectionV1>(
                     &plugin.plugin,
-                    &plugin.authority.unwrap_or(plugin.plugin.manager()),
+                    &plugin.authority.clone().unwrap_or(plugin.plugin.manager()),
                     &mut plugin_header,
                     &mut plugin_registry,
                     ctx.accounts.collection,
@@ -210,5 +218,12 @@ pub(crate) fn process_create_collection<'a>(
         return Err(MplCoreError::InvalidAuthority.into());
     }
 
-    Ok(())
+    MplCoreEvent::emit_v1(
+        log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::CreateCollection {
+            update_authority: new_collection.update_authority,
+        },
+    )
 }
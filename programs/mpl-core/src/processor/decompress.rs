@@ -1,12 +1,12 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, system_program};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, system_program};
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::DecompressV1Accounts,
-    plugins::{Plugin, PluginType},
-    state::{AssetV1, CollectionV1, CompressionProof, Key},
+    plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
+    state::{AssetV1, CollectionV1, CompressionProof, Key, MplCoreEvent, MplCoreEventPayloadV1},
     utils::{
         load_key, rebuild_account_state_from_proof_data, resolve_authority,
         validate_asset_permissions, verify_proof,
@@ -48,6 +48,7 @@ pub(crate) fn decompress<'a>(
             // Increment sequence number.  Note `Asset`` will always be `Some(_)`` here
             // after rebuilding from a compression proof.
             asset.seq = asset.seq.map(|seq| seq.saturating_add(1));
+            let (owner, seq) = (asset.owner, asset.seq);
 
             // Use the data from the compression proof to rebuild the account.
             rebuild_account_state_from_proof_data(
@@ -73,13 +74,16 @@ pub(crate) fn decompress<'a>(
                 AssetV1::validate_decompress,
                 CollectionV1::validate_decompress,
                 Plugin::validate_decompress,
-                None,
-                None,
+                Some(ExternalPluginAdapter::validate_decompress),
+                Some(HookableLifecycleEvent::Decompress),
             )?;
 
-            // TODO Enable compression.
-            msg!("Error: Decompression currently not available");
-            Err(MplCoreError::NotAvailable.into())
+            MplCoreEvent::emit_v1(
+                ctx.accounts.log_wrapper,
+                ctx.accounts.asset.key,
+                seq,
+                MplCoreEventPayloadV1::Decompress { owner },
+            )
         }
         Key::AssetV1 => Err(MplCoreError::AlreadyDecompressed.into()),
         _ => Err(MplCoreError::IncorrectAccount.into()),
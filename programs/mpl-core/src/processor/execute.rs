@@ -0,0 +1,125 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke_signed,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ExecuteV1Accounts,
+    plugins::{Plugin, PluginType},
+    state::{AssetV1, CollectionV1, Key, MplCoreEvent, MplCoreEventPayloadV1, ASSET_SIGNER_PREFIX},
+    utils::{load_key, resolve_authority, validate_asset_permissions},
+};
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ExecuteV1Args {
+    /// The instruction data to pass to the invoked program.
+    pub instruction_data: Vec<u8>,
+}
+
+pub(crate) fn execute<'a>(accounts: &'a [AccountInfo<'a>], args: ExecuteV1Args) -> ProgramResult {
+    // Accounts.
+    let ctx = ExecuteV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    // The asset signer is derived from the asset address, so the asset and collection data can
+    // only be trusted when the accounts are owned by this program.
+    if ctx.accounts.asset.owner != &crate::ID {
+        return Err(MplCoreError::IncorrectAccount.into());
+    }
+
+    if let Some(collection) = ctx.accounts.collection {
+        if collection.owner != &crate::ID {
+            return Err(MplCoreError::IncorrectAccount.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Execute for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let (asset_signer, bump) = AssetV1::find_signer_address(ctx.accounts.asset.key);
+    if ctx.accounts.asset_signer.key != &asset_signer {
+        return Err(MplCoreError::InvalidExecutePda.into());
+    }
+
+    // Validate asset permissions.
+    let (asset, _, _) = validate_asset_permissions(
+        accounts,
+        authority,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        None,
+        None,
+        None,
+        AssetV1::check_execute,
+        CollectionV1::check_execute,
+        PluginType::check_execute,
+        AssetV1::validate_execute,
+        CollectionV1::validate_execute,
+        Plugin::validate_execute,
+        None,
+        None,
+    )?;
+
+    // The remaining accounts are passed through to the invoked program, with the asset signer
+    // marked as a signer wherever it appears.
+    let account_metas = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer || account.key == &asset_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+
+    let instruction = Instruction {
+        program_id: *ctx.accounts.program_id.key,
+        accounts: account_metas,
+        data: args.instruction_data,
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.asset_signer.clone());
+    account_infos.push(ctx.accounts.program_id.clone());
+
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[
+            ASSET_SIGNER_PREFIX.as_bytes(),
+            ctx.accounts.asset.key.as_ref(),
+            &[bump],
+        ]],
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::Execute {
+            program_id: *ctx.accounts.program_id.key,
+        },
+    )
+}
@@ -7,11 +7,13 @@ mod compress;
 mod create;
 mod create_collection;
 mod decompress;
+mod execute;
 mod remove_external_plugin_adapter;
 mod remove_plugin;
 mod revoke_plugin_authority;
 mod transfer;
 mod update;
+mod update_attribute;
 mod update_external_plugin_adapter;
 mod update_plugin;
 mod write_external_plugin_adapter_data;
@@ -25,11 +27,13 @@ pub(crate) use compress::*;
 pub(crate) use create::*;
 pub(crate) use create_collection::*;
 pub(crate) use decompress::*;
+pub(crate) use execute::*;
 pub(crate) use remove_external_plugin_adapter::*;
 pub(crate) use remove_plugin::*;
 pub(crate) use revoke_plugin_authority::*;
 pub(crate) use transfer::*;
 pub(crate) use update::*;
+pub(crate) use update_attribute::*;
 pub(crate) use update_external_plugin_adapter::*;
 pub(crate) use update_plugin::*;
 pub(crate) use write_external_plugin_adapter_data::*;
@@ -109,7 +113,7 @@ pub fn process_instruction<'a>(
         }
         MplAssetInstruction::UpdateV1(args) => {
             msg!("Instruction: Update");
-            update(accounts, args)
+            update_v1(accounts, args)
         }
         MplAssetInstruction::UpdateCollectionV1(args) => {
             msg!("Instruction: UpdateCollection");
@@ -164,5 +168,29 @@ pub fn process_instruction<'a>(
             msg!("Instruction: WriteCollectionExternalPluginAdapterDataV1");
             write_collection_external_plugin_adapter_data(accounts, args)
         }
+        MplAssetInstruction::UpdateV2(args) => {
+            msg!("Instruction: UpdateV2");
+            update_v2(accounts, args)
+        }
+        MplAssetInstruction::ExecuteV1(args) => {
+            msg!("Instruction: Execute");
+            execute(accounts, args)
+        }
+        MplAssetInstruction::UpsertAttributeV1(args) => {
+            msg!("Instruction: UpsertAttribute");
+            upsert_attribute(accounts, args)
+        }
+        MplAssetInstruction::UpsertCollectionAttributeV1(args) => {
+            msg!("Instruction: UpsertCollectionAttribute");
+            upsert_collection_attribute(accounts, args)
+        }
+        MplAssetInstruction::RemoveAttributeV1(args) => {
+            msg!("Instruction: RemoveAttribute");
+            remove_attribute(accounts, args)
+        }
+        MplAssetInstruction::RemoveCollectionAttributeV1(args) => {
+            msg!("Instruction: RemoveCollectionAttribute");
+            remove_collection_attribute(accounts, args)
+        }
     }
 }
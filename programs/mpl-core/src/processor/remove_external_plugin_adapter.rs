@@ -11,7 +11,7 @@ use crate::{
         delete_external_plugin_adapter, fetch_wrapped_external_plugin_adapter,
         ExternalPluginAdapterKey, Plugin, PluginType,
     },
-    state::{AssetV1, CollectionV1, DataBlob, Key},
+    state::{AssetV1, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1},
     utils::{
         fetch_core_data, load_key, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
@@ -88,6 +88,13 @@ pub(crate) fn remove_external_plugin_adapter<'a>(
         ctx.accounts.asset,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::RemoveExternalPluginAdapter { key: args.key },
     )
 }
 
@@ -153,6 +160,13 @@ pub(crate) fn remove_collection_external_plugin_adapter<'a>(
         ctx.accounts.collection,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::RemoveExternalPluginAdapter { key: args.key },
     )
 }
 
@@ -5,8 +5,11 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
 use crate::{
     error::MplCoreError,
     instruction::accounts::{RemoveCollectionPluginV1Accounts, RemovePluginV1Accounts},
-    plugins::{delete_plugin, fetch_wrapped_plugin, Plugin, PluginType},
-    state::{AssetV1, CollectionV1, DataBlob, Key},
+    plugins::{
+        delete_plugin, fetch_wrapped_plugin, ExternalPluginAdapter, HookableLifecycleEvent, Plugin,
+        PluginType,
+    },
+    state::{AssetV1, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1},
     utils::{
         fetch_core_data, load_key, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
@@ -70,8 +73,8 @@ pub(crate) fn remove_plugin<'a>(
         AssetV1::validate_remove_plugin,
         CollectionV1::validate_remove_plugin,
         Plugin::validate_remove_plugin,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_remove_plugin),
+        Some(HookableLifecycleEvent::RemovePlugin),
     )?;
 
     // Increment sequence number and save only if it is `Some(_)`.
@@ -83,6 +86,15 @@ pub(crate) fn remove_plugin<'a>(
         ctx.accounts.asset,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::RemovePlugin {
+            plugin_type: args.plugin_type,
+        },
     )
 }
 
@@ -137,8 +149,8 @@ pub(crate) fn remove_collection_plugin<'a>(
         PluginType::check_remove_plugin,
         CollectionV1::validate_remove_plugin,
         Plugin::validate_remove_plugin,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_remove_plugin),
+        Some(HookableLifecycleEvent::RemovePlugin),
     )?;
 
     process_remove_plugin(
@@ -147,6 +159,15 @@ pub(crate) fn remove_collection_plugin<'a>(
         ctx.accounts.collection,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::RemovePlugin {
+            plugin_type: args.plugin_type,
+        },
     )
 }
 
@@ -8,10 +8,10 @@ use crate::{
         RevokeCollectionPluginAuthorityV1Accounts, RevokePluginAuthorityV1Accounts,
     },
     plugins::{
-        fetch_wrapped_plugin, revoke_authority_on_plugin, Plugin, PluginHeaderV1, PluginRegistryV1,
-        PluginType,
+        fetch_wrapped_plugin, revoke_authority_on_plugin, ExternalPluginAdapter,
+        HookableLifecycleEvent, Plugin, PluginHeaderV1, PluginRegistryV1, PluginType,
     },
-    state::{AssetV1, CollectionV1, Key},
+    state::{AssetV1, CollectionV1, Key, MplCoreEvent, MplCoreEventPayloadV1},
     utils::{
         fetch_core_data, load_key, resolve_authority, resolve_pubkey_to_authorities,
         resolve_pubkey_to_authorities_collection, validate_asset_permissions,
@@ -71,8 +71,8 @@ pub(crate) fn revoke_plugin_authority<'a>(
         AssetV1::validate_revoke_plugin_authority,
         CollectionV1::validate_revoke_plugin_authority,
         Plugin::validate_revoke_plugin_authority,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_revoke_plugin_authority),
+        Some(HookableLifecycleEvent::RevokePluginAuthority),
     )?;
 
     // Increment sequence number and save only if it is `Some(_)`.
@@ -93,6 +93,15 @@ pub(crate) fn revoke_plugin_authority<'a>(
         &args.plugin_type,
         plugin_header.as_ref(),
         plugin_registry.as_mut(),
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::RevokePluginAuthority {
+            plugin_type: args.plugin_type,
+        },
     )
 }
 
@@ -142,8 +151,8 @@ pub(crate) fn revoke_collection_plugin_authority<'a>(
         PluginType::check_revoke_plugin_authority,
         CollectionV1::validate_revoke_plugin_authority,
         Plugin::validate_revoke_plugin_authority,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_revoke_plugin_authority),
+        Some(HookableLifecycleEvent::RevokePluginAuthority),
     )?;
 
     let resolved_authorities =
@@ -161,6 +170,15 @@ pub(crate) fn revoke_collection_plugin_authority<'a>(
         &args.plugin_type,
         plugin_header.as_ref(),
         plugin_registry.as_mut(),
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::RevokePluginAuthority {
+            plugin_type: args.plugin_type,
+        },
     )
 }
 
@@ -1,22 +1,50 @@
+use std::io::Read;
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::TransferV1Accounts,
-    plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
-    state::{AssetV1, Authority, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable},
+    plugins::{
+        fetch_plugin, ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType, Royalties,
+    },
+    state::{
+        AssetV1, Authority, CollectionV1, CompressionProof, DataBlob, Key, MplCoreEvent,
+        MplCoreEventPayloadV1, SolanaAccount, Wrappable,
+    },
     utils::{
-        compress_into_account_space, load_key, rebuild_account_state_from_proof_data,
-        resolve_authority, validate_asset_permissions, verify_proof,
+        compress_into_account_space, deserialize_trailing_option, load_key,
+        rebuild_account_state_from_proof_data, resolve_authority, validate_asset_permissions,
+        verify_proof,
     },
 };
 
 #[repr(C)]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, PartialEq, Eq, Debug, Clone)]
 pub struct TransferV1Args {
     compression_proof: Option<CompressionProof>,
+    /// The price in lamports the asset was sold for. When it is non-zero and the creators opted
+    /// in to royalty payments, the royalties are paid to the creators.
+    sale_price: Option<u64>,
+}
+
+// The sale price was added after the original args, so it is read as `None` when a client
+// does not send it.
+impl BorshDeserialize for TransferV1Args {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let compression_proof = Option::<CompressionProof>::deserialize_reader(reader)?;
+
+        let sale_price = deserialize_trailing_option(reader)?;
+
+        Ok(Self {
+            compression_proof,
+            sale_price,
+        })
+    }
 }
 
 pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args) -> ProgramResult {
@@ -41,8 +69,15 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
 
     let key = load_key(ctx.accounts.asset, 0)?;
 
+    let sale_price = args.sale_price.filter(|price| *price > 0);
+
     match key {
         Key::HashedAssetV1 => {
+            if sale_price.is_some() {
+                msg!("Error: Royalty payments are not available for compressed assets");
+                return Err(MplCoreError::NotAvailable.into());
+            }
+
             let compression_proof = args
                 .compression_proof
                 .ok_or(MplCoreError::MissingCompressionProof)?;
@@ -53,12 +88,11 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
                 .ok_or(MplCoreError::MissingSystemProgram)?;
 
             // Verify the proof and rebuild Asset struct in account space.
-            let (mut asset, plugins) = verify_proof(ctx.accounts.asset, &compression_proof)?;
-
-            // Set the new owner.
-            asset.owner = *ctx.accounts.new_owner.key;
+            let (asset, plugins) = verify_proof(ctx.accounts.asset, &compression_proof)?;
 
-            // Use the data from the compression proof to rebuild the account.
+            // Use the data from the compression proof to rebuild the account.  The current
+            // owner is kept so that the lifecycle checks below validate against it, and the
+            // account is compressed again with the new owner once validation passes.
             rebuild_account_state_from_proof_data(
                 asset,
                 plugins,
@@ -66,17 +100,13 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
                 ctx.accounts.payer,
                 system_program,
             )?;
-
-            // TODO Enable compressed transfer.
-            msg!("Error: Transferring compressed is currently not available");
-            return Err(MplCoreError::NotAvailable.into());
         }
         Key::AssetV1 => (),
         _ => return Err(MplCoreError::IncorrectAccount.into()),
     }
 
     // Validate asset permissions.
-    let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+    let (mut asset, plugin_header, mut plugin_registry) = validate_asset_permissions(
         accounts,
         authority,
         ctx.accounts.asset,
@@ -94,9 +124,33 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
         Some(HookableLifecycleEvent::Transfer),
     )?;
 
+    if let Some(sale_price) = sale_price {
+        // Royalties on the asset override the ones on the collection.
+        let royalties = match fetch_royalties::<AssetV1>(ctx.accounts.asset)? {
+            Some(royalties) => Some(royalties),
+            None => match ctx.accounts.collection {
+                Some(collection) => fetch_royalties::<CollectionV1>(collection)?,
+                None => None,
+            },
+        };
+
+        if let Some(royalties) = royalties {
+            let system_program = ctx
+                .accounts
+                .system_program
+                .ok_or(MplCoreError::MissingSystemProgram)?;
+
+            royalties.pay(
+                sale_price,
+                ctx.accounts.payer,
+                ctx.remaining_accounts,
+                system_program,
+            )?;
+        }
+    }
+
     // Reset every owner-managed plugin in the registry.
-    if let (Some(plugin_header), Some(mut plugin_registry)) =
-        (plugin_header, plugin_registry.clone())
+    if let (Some(plugin_header), Some(plugin_registry)) = (plugin_header, plugin_registry.as_mut())
     {
         plugin_registry.registry.iter_mut().for_each(|record| {
             if record.plugin_type.manager() == Authority::Owner {
@@ -134,8 +188,28 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
         Key::AssetV1 => {
             // Increment sequence number only if it is `Some(_)`.
             asset.seq = asset.seq.map(|seq| seq.saturating_add(1));
-            asset.save(ctx.accounts.asset, 0)
+            asset.save(ctx.accounts.asset, 0)?;
+
+            MplCoreEvent::emit_v1(
+                ctx.accounts.log_wrapper,
+                ctx.accounts.asset.key,
+                asset.seq,
+                MplCoreEventPayloadV1::Transfer {
+                    new_owner: asset.owner,
+                },
+            )
         }
         _ => unreachable!(),
     }
 }
+
+// Fetch the royalties plugin, if there is one on the account.
+fn fetch_royalties<T: DataBlob + SolanaAccount>(
+    account: &AccountInfo,
+) -> Result<Option<Royalties>, ProgramError> {
+    match fetch_plugin::<T, Royalties>(account, PluginType::Royalties) {
+        Ok((_, royalties, _)) => Ok(Some(royalties)),
+        Err(err) if err == MplCoreError::PluginNotFound.into() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
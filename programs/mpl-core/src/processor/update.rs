@@ -6,15 +6,18 @@ use solana_program::{
 
 use crate::{
     error::MplCoreError,
-    instruction::accounts::{UpdateCollectionV1Accounts, UpdateV1Accounts},
+    instruction::accounts::{UpdateCollectionV1Accounts, UpdateV1Accounts, UpdateV2Accounts},
     plugins::{
         ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginHeaderV1, PluginRegistryV1,
         PluginType,
     },
-    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
+    state::{
+        AssetV1, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1, SolanaAccount,
+        UpdateAuthority,
+    },
     utils::{
-        load_key, resize_or_reallocate_account, resolve_authority, validate_asset_permissions,
-        validate_collection_permissions,
+        assert_master_edition_supply, load_key, resize_or_reallocate_account, resolve_authority,
+        validate_asset_permissions, validate_collection_permissions,
     },
 };
 
@@ -26,25 +29,102 @@ pub(crate) struct UpdateV1Args {
     pub new_update_authority: Option<UpdateAuthority>,
 }
 
-pub(crate) fn update<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV1Args) -> ProgramResult {
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct UpdateV2Args {
+    pub new_name: Option<String>,
+    pub new_uri: Option<String>,
+    pub new_update_authority: Option<UpdateAuthority>,
+}
+
+impl From<UpdateV1Args> for UpdateV2Args {
+    fn from(item: UpdateV1Args) -> Self {
+        UpdateV2Args {
+            new_name: item.new_name,
+            new_uri: item.new_uri,
+            new_update_authority: item.new_update_authority,
+        }
+    }
+}
+
+pub(crate) fn update_v1<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV1Args) -> ProgramResult {
     // Accounts.
     let ctx = UpdateV1Accounts::context(accounts)?;
 
+    // The collection account is not writable in V1 so collection membership cannot change.
+    if let Some(new_update_authority) = &args.new_update_authority {
+        if let UpdateAuthority::Collection(_) = new_update_authority {
+            msg!("Error: Use UpdateV2 to add an asset to a collection");
+            return Err(MplCoreError::NotAvailable.into());
+        }
+
+        if let Key::AssetV1 = load_key(ctx.accounts.asset, 0)? {
+            if let UpdateAuthority::Collection(_) =
+                AssetV1::load(ctx.accounts.asset, 0)?.update_authority
+            {
+                msg!("Error: Use UpdateV2 to remove an asset from a collection");
+                return Err(MplCoreError::NotAvailable.into());
+            }
+        }
+    }
+
+    update(
+        accounts,
+        UpdateV2Args::from(args),
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.authority,
+        None,
+        ctx.accounts.system_program,
+        ctx.accounts.log_wrapper,
+    )
+}
+
+pub(crate) fn update_v2<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV2Args) -> ProgramResult {
+    // Accounts.
+    let ctx = UpdateV2Accounts::context(accounts)?;
+
+    update(
+        accounts,
+        args,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.authority,
+        ctx.accounts.new_collection,
+        ctx.accounts.system_program,
+        ctx.accounts.log_wrapper,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: UpdateV2Args,
+    asset_info: &'a AccountInfo<'a>,
+    collection_info: Option<&'a AccountInfo<'a>>,
+    payer: &'a AccountInfo<'a>,
+    authority_info: Option<&'a AccountInfo<'a>>,
+    new_collection_info: Option<&'a AccountInfo<'a>>,
+    system_program: &'a AccountInfo<'a>,
+    log_wrapper: Option<&'a AccountInfo<'a>>,
+) -> ProgramResult {
     // Guards.
-    assert_signer(ctx.accounts.payer)?;
-    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+    assert_signer(payer)?;
+    let authority = resolve_authority(payer, authority_info)?;
 
-    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+    if system_program.key != &solana_program::system_program::ID {
         return Err(MplCoreError::InvalidSystemProgram.into());
     }
 
-    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+    if let Some(log_wrapper) = log_wrapper {
         if log_wrapper.key != &spl_noop::ID {
             return Err(MplCoreError::InvalidLogWrapperProgram.into());
         }
     }
 
-    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+    if let Key::HashedAssetV1 = load_key(asset_info, 0)? {
         msg!("Error: Update for compressed is not available");
         return Err(MplCoreError::NotAvailable.into());
     }
@@ -52,8 +132,8 @@ pub(crate) fn update<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV1Args) ->
     let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
         accounts,
         authority,
-        ctx.accounts.asset,
-        ctx.accounts.collection,
+        asset_info,
+        collection_info,
         None,
         None,
         None,
@@ -68,21 +148,39 @@ pub(crate) fn update<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV1Args) ->
     )?;
 
     // Increment sequence number and save only if it is `Some(_)`.
-    asset.increment_seq_and_save(ctx.accounts.asset)?;
+    asset.increment_seq_and_save(asset_info)?;
+
+    let seq = asset.seq;
+    let event_payload = MplCoreEventPayloadV1::Update {
+        new_name: args.new_name.clone(),
+        new_uri: args.new_uri.clone(),
+        new_update_authority: args.new_update_authority.clone(),
+    };
 
     let asset_size = asset.get_size() as isize;
 
     let mut dirty = false;
     if let Some(new_update_authority) = args.new_update_authority {
-        if let UpdateAuthority::Collection(_collection_address) = new_update_authority {
-            // Updating collections is not currently available.
-            return Err(MplCoreError::NotAvailable.into());
-        };
-
-        if let UpdateAuthority::Collection(_collection_address) = asset.update_authority {
-            // Removing from collection is not currently available.
-            // will require the collection size to be updated
-            return Err(MplCoreError::NotAvailable.into());
+        if new_update_authority != asset.update_authority {
+            // Remove the asset from its current collection.  The collection authority has
+            // already approved the update as the asset's update authority.
+            if let UpdateAuthority::Collection(_) = asset.update_authority {
+                let collection_info = collection_info.ok_or(MplCoreError::MissingCollection)?;
+                let mut collection = CollectionV1::load(collection_info, 0)?;
+                collection.decrement()?;
+                collection.save(collection_info, 0)?;
+            }
+
+            // Add the asset to the new collection.
+            if let UpdateAuthority::Collection(new_collection_address) = new_update_authority {
+                let new_collection_info =
+                    new_collection_info.ok_or(MplCoreError::MissingCollection)?;
+                if new_collection_info.key != &new_collection_address {
+                    return Err(MplCoreError::InvalidCollection.into());
+                }
+
+                add_to_collection(accounts, authority, new_collection_info)?;
+            }
         }
 
         asset.update_authority = new_update_authority;
@@ -102,13 +200,54 @@ pub(crate) fn update<'a>(accounts: &'a [AccountInfo<'a>], args: UpdateV1Args) ->
             &plugin_header,
             &plugin_registry,
             asset_size,
-            ctx.accounts.asset,
-            ctx.accounts.payer,
-            ctx.accounts.system_program,
+            asset_info,
+            payer,
+            system_program,
         )?;
     }
 
-    Ok(())
+    MplCoreEvent::emit_v1(log_wrapper, asset_info.key, seq, event_payload)
+}
+
+/// Validate that the authority can add an asset to the collection and increment its size.
+fn add_to_collection<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    authority: &'a AccountInfo<'a>,
+    collection_info: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    // The authority must also be able to update the new collection.
+    let (mut collection, _, plugin_registry) = validate_collection_permissions(
+        accounts,
+        authority,
+        collection_info,
+        None,
+        None,
+        CollectionV1::check_update,
+        PluginType::check_update,
+        CollectionV1::validate_update,
+        Plugin::validate_update,
+        Some(ExternalPluginAdapter::validate_update),
+        Some(HookableLifecycleEvent::Update),
+    )?;
+
+    // Permanent delegates can only be granted over an asset when it is created.
+    if let Some(plugin_registry) = plugin_registry {
+        if plugin_registry.registry.iter().any(|record| {
+            matches!(
+                record.plugin_type,
+                PluginType::PermanentFreezeDelegate
+                    | PluginType::PermanentTransferDelegate
+                    | PluginType::PermanentBurnDelegate
+            )
+        }) {
+            return Err(MplCoreError::PermanentDelegatesPreventMove.into());
+        }
+    }
+
+    assert_master_edition_supply(collection_info, &collection)?;
+
+    collection.increment_current_size()?;
+    collection.save(collection_info, 0)
 }
 
 #[repr(C)]
@@ -153,6 +292,15 @@ pub(crate) fn update_collection<'a>(
         Some(HookableLifecycleEvent::Update),
     )?;
 
+    let event_payload = MplCoreEventPayloadV1::Update {
+        new_name: args.new_name.clone(),
+        new_uri: args.new_uri.clone(),
+        new_update_authority: ctx
+            .accounts
+            .new_update_authority
+            .map(|new_update_authority| UpdateAuthority::Address(*new_update_authority.key)),
+    };
+
     let collection_size = collection.get_size() as isize;
 
     let mut dirty = false;
@@ -180,7 +328,12 @@ pub(crate) fn update_collection<'a>(
         )?;
     }
 
-    Ok(())
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        event_payload,
+    )
 }
 
 fn process_update<'a, T: DataBlob + SolanaAccount>(
@@ -239,11 +392,7 @@ fn process_update<'a, T: DataBlob + SolanaAccount>(
 
         // Move offsets for existing registry records.
         for record in &mut plugin_registry.external_registry {
-            let new_offset = (record.offset as isize)
-                .checked_add(size_diff)
-                .ok_or(MplCoreError::NumericalOverflow)?;
-
-            record.offset = new_offset as usize;
+            record.bump_offsets(size_diff)?;
         }
 
         for record in &mut plugin_registry.registry {
@@ -0,0 +1,327 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{
+        RemoveAttributeV1Accounts, RemoveCollectionAttributeV1Accounts, UpsertAttributeV1Accounts,
+        UpsertCollectionAttributeV1Accounts,
+    },
+    plugins::{
+        self, fetch_plugin, Attribute, Attributes, AttributesV2, ExternalPluginAdapter,
+        HookableLifecycleEvent, Plugin, PluginType,
+    },
+    state::{
+        AssetV1, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1, SolanaAccount,
+    },
+    utils::{
+        load_key, resolve_authority, validate_asset_permissions, validate_collection_permissions,
+    },
+};
+
+use super::process_update_plugin;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct UpsertAttributeV1Args {
+    /// The key of the attribute to set.
+    pub key: String,
+    /// The new value of the attribute.
+    pub value: String,
+}
+
+pub(crate) fn upsert_attribute<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: UpsertAttributeV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = UpsertAttributeV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    update_asset_attributes(
+        accounts,
+        authority,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.log_wrapper,
+        |attribute_list| {
+            plugins::upsert_attribute(attribute_list, args.key, args.value);
+            Ok(())
+        },
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct UpsertCollectionAttributeV1Args {
+    /// The key of the attribute to set.
+    pub key: String,
+    /// The new value of the attribute.
+    pub value: String,
+}
+
+pub(crate) fn upsert_collection_attribute<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: UpsertCollectionAttributeV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = UpsertCollectionAttributeV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    update_collection_attributes(
+        accounts,
+        authority,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.log_wrapper,
+        |attribute_list| {
+            plugins::upsert_attribute(attribute_list, args.key, args.value);
+            Ok(())
+        },
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct RemoveAttributeV1Args {
+    /// The key of the attribute to remove.
+    pub key: String,
+}
+
+pub(crate) fn remove_attribute<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: RemoveAttributeV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = RemoveAttributeV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    update_asset_attributes(
+        accounts,
+        authority,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.log_wrapper,
+        |attribute_list| {
+            plugins::remove_attribute(attribute_list, &args.key)
+                .ok_or(MplCoreError::AttributeNotFound)?;
+            Ok(())
+        },
+    )
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct RemoveCollectionAttributeV1Args {
+    /// The key of the attribute to remove.
+    pub key: String,
+}
+
+pub(crate) fn remove_collection_attribute<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: RemoveCollectionAttributeV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = RemoveCollectionAttributeV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    update_collection_attributes(
+        accounts,
+        authority,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.log_wrapper,
+        |attribute_list| {
+            plugins::remove_attribute(attribute_list, &args.key)
+                .ok_or(MplCoreError::AttributeNotFound)?;
+            Ok(())
+        },
+    )
+}
+
+/// Fetch the plugin holding the attributes of an account.  The `Attributes` plugin is used when
+/// present, otherwise the `AttributesV2` plugin.
+fn fetch_attributes<T: DataBlob + SolanaAccount>(
+    account: &AccountInfo,
+) -> Result<Plugin, ProgramError> {
+    match fetch_plugin::<T, Attributes>(account, PluginType::Attributes) {
+        Ok((_, attributes, _)) => Ok(Plugin::Attributes(attributes)),
+        Err(err) if err == MplCoreError::PluginNotFound.into() => {
+            let (_, attributes, _) =
+                fetch_plugin::<T, AttributesV2>(account, PluginType::AttributesV2)?;
+            Ok(Plugin::AttributesV2(attributes))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+// Get the attribute list of a plugin returned by `fetch_attributes`.
+fn attribute_list(plugin: &mut Plugin) -> &mut Vec<Attribute> {
+    match plugin {
+        Plugin::Attributes(attributes) => &mut attributes.attribute_list,
+        Plugin::AttributesV2(attributes) => &mut attributes.attribute_list,
+        _ => unreachable!(),
+    }
+}
+
+/// Apply a change to the attributes plugin of an asset, validated as an update of the plugin,
+/// and rewrite the plugin in place.
+#[allow(clippy::too_many_arguments)]
+fn update_asset_attributes<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    authority: &'a AccountInfo<'a>,
+    asset_info: &'a AccountInfo<'a>,
+    collection_info: Option<&'a AccountInfo<'a>>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    log_wrapper: Option<&'a AccountInfo<'a>>,
+    update: impl FnOnce(&mut Vec<Attribute>) -> ProgramResult,
+) -> ProgramResult {
+    if system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Key::HashedAssetV1 = load_key(asset_info, 0)? {
+        msg!("Error: Update attribute for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let mut new_plugin = fetch_attributes::<AssetV1>(asset_info)?;
+    update(attribute_list(&mut new_plugin))?;
+
+    let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+        accounts,
+        authority,
+        asset_info,
+        collection_info,
+        None,
+        Some(&new_plugin),
+        None,
+        AssetV1::check_update_plugin,
+        CollectionV1::check_update_plugin,
+        PluginType::check_update_plugin,
+        AssetV1::validate_update_plugin,
+        CollectionV1::validate_update_plugin,
+        Plugin::validate_update_plugin,
+        Some(ExternalPluginAdapter::validate_update_plugin),
+        Some(HookableLifecycleEvent::UpdatePlugin),
+    )?;
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(asset_info)?;
+
+    let seq = asset.seq;
+    let plugin_type = PluginType::from(&new_plugin);
+    process_update_plugin(
+        asset,
+        new_plugin,
+        plugin_header,
+        plugin_registry,
+        asset_info,
+        payer,
+        system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        log_wrapper,
+        asset_info.key,
+        seq,
+        MplCoreEventPayloadV1::UpdatePlugin { plugin_type },
+    )
+}
+
+/// Apply a change to the attributes plugin of a collection, validated as an update of the
+/// plugin, and rewrite the plugin in place.
+fn update_collection_attributes<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    authority: &'a AccountInfo<'a>,
+    collection_info: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    log_wrapper: Option<&'a AccountInfo<'a>>,
+    update: impl FnOnce(&mut Vec<Attribute>) -> ProgramResult,
+) -> ProgramResult {
+    if system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    let mut new_plugin = fetch_attributes::<CollectionV1>(collection_info)?;
+    update(attribute_list(&mut new_plugin))?;
+
+    let (collection, plugin_header, plugin_registry) = validate_collection_permissions(
+        accounts,
+        authority,
+        collection_info,
+        Some(&new_plugin),
+        None,
+        CollectionV1::check_update_plugin,
+        PluginType::check_update_plugin,
+        CollectionV1::validate_update_plugin,
+        Plugin::validate_update_plugin,
+        Some(ExternalPluginAdapter::validate_update_plugin),
+        Some(HookableLifecycleEvent::UpdatePlugin),
+    )?;
+
+    let plugin_type = PluginType::from(&new_plugin);
+    process_update_plugin(
+        collection,
+        new_plugin,
+        plugin_header,
+        plugin_registry,
+        collection_info,
+        payer,
+        system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        log_wrapper,
+        collection_info.key,
+        None,
+        MplCoreEventPayloadV1::UpdatePlugin { plugin_type },
+    )
+}
@@ -14,7 +14,9 @@ use crate::{
         ExternalPluginAdapterKey, ExternalPluginAdapterUpdateInfo, Plugin, PluginHeaderV1,
         PluginRegistryV1, PluginType,
     },
-    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount},
+    state::{
+        AssetV1, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1, SolanaAccount,
+    },
     utils::{
         load_key, resize_or_reallocate_account, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
@@ -80,16 +82,24 @@ pub(crate) fn update_external_plugin_adapter<'a>(
     // Increment sequence number and save only if it is `Some(_)`.
     asset.increment_seq_and_save(ctx.accounts.asset)?;
 
+    let seq = asset.seq;
     process_update_external_plugin_adapter(
         asset,
         plugin,
-        args.key,
+        args.key.clone(),
         args.update_info,
         plugin_header,
         plugin_registry,
         ctx.accounts.asset,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        seq,
+        MplCoreEventPayloadV1::UpdateExternalPluginAdapter { key: args.key },
     )
 }
 
@@ -147,13 +157,20 @@ pub(crate) fn update_collection_external_plugin_adapter<'a>(
     process_update_external_plugin_adapter(
         collection,
         plugin,
-        args.key,
+        args.key.clone(),
         args.update_info,
         plugin_header,
         plugin_registry,
         ctx.accounts.collection,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::UpdateExternalPluginAdapter { key: args.key },
     )
 }
 
@@ -173,7 +190,8 @@ fn process_update_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
     let mut plugin_header = plugin_header.ok_or(MplCoreError::PluginsNotInitialized)?;
 
     let plugin_registry_clone = plugin_registry.clone();
-    let (_, record) = find_external_plugin_adapter(&plugin_registry_clone, &key, account)?;
+    let (index, record) = find_external_plugin_adapter(&plugin_registry_clone, &key, account)?;
+    let index = index.ok_or(MplCoreError::PluginNotFound)?;
     let mut registry_record = record.ok_or(MplCoreError::PluginNotFound)?.clone();
     registry_record.update(&update_info)?;
 
@@ -226,11 +244,7 @@ fn process_update_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
     // Move offsets for existing registry records.
     for record in &mut plugin_registry.external_registry {
         if registry_record.offset < record.offset {
-            let new_offset = (record.offset as isize)
-                .checked_add(size_diff)
-                .ok_or(MplCoreError::NumericalOverflow)?;
-
-            record.offset = new_offset as usize;
+            record.bump_offsets(size_diff)?;
         }
     }
 
@@ -244,8 +258,19 @@ fn process_update_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
         }
     }
 
+    // The plugin's data immediately follows the plugin so its offset moves as well.
+    if let Some(data_offset) = registry_record.data_offset {
+        let new_data_offset = (data_offset as isize)
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+        registry_record.data_offset = Some(new_data_offset as usize);
+    }
+
+    let plugin_offset = registry_record.offset;
+    plugin_registry.external_registry[index] = registry_record;
+
     plugin_registry.save(account, new_registry_offset as usize)?;
-    new_plugin.save(account, registry_record.offset)?;
+    new_plugin.save(account, plugin_offset)?;
 
     Ok(())
 }
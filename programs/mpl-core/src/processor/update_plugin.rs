@@ -7,8 +7,13 @@ use solana_program::{
 use crate::{
     error::MplCoreError,
     instruction::accounts::{UpdateCollectionPluginV1Accounts, UpdatePluginV1Accounts},
-    plugins::{Plugin, PluginHeaderV1, PluginRegistryV1, PluginType},
-    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount},
+    plugins::{
+        ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginHeaderV1, PluginRegistryV1,
+        PluginType,
+    },
+    state::{
+        AssetV1, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1, SolanaAccount,
+    },
     utils::{
         load_key, resize_or_reallocate_account, resolve_authority, validate_asset_permissions,
         validate_collection_permissions,
@@ -61,13 +66,15 @@ pub(crate) fn update_plugin<'a>(
         AssetV1::validate_update_plugin,
         CollectionV1::validate_update_plugin,
         Plugin::validate_update_plugin,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_update_plugin),
+        Some(HookableLifecycleEvent::UpdatePlugin),
     )?;
 
     // Increment sequence number and save only if it is `Some(_)`.
     asset.increment_seq_and_save(ctx.accounts.asset)?;
 
+    let seq = asset.seq;
+    let plugin_type = PluginType::from(&args.plugin);
     process_update_plugin(
         asset,
         args.plugin,
@@ -76,6 +83,13 @@ pub(crate) fn update_plugin<'a>(
         ctx.accounts.asset,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        seq,
+        MplCoreEventPayloadV1::UpdatePlugin { plugin_type },
     )
 }
 
@@ -117,10 +131,11 @@ pub(crate) fn update_collection_plugin<'a>(
         PluginType::check_update_plugin,
         CollectionV1::validate_update_plugin,
         Plugin::validate_update_plugin,
-        None,
-        None,
+        Some(ExternalPluginAdapter::validate_update_plugin),
+        Some(HookableLifecycleEvent::UpdatePlugin),
     )?;
 
+    let plugin_type = PluginType::from(&args.plugin);
     process_update_plugin(
         collection,
         args.plugin,
@@ -129,10 +144,17 @@ pub(crate) fn update_collection_plugin<'a>(
         ctx.accounts.collection,
         ctx.accounts.payer,
         ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::UpdatePlugin { plugin_type },
     )
 }
 
-fn process_update_plugin<'a, T: DataBlob + SolanaAccount>(
+pub(crate) fn process_update_plugin<'a, T: DataBlob + SolanaAccount>(
     core: T,
     new_plugin: Plugin,
     plugin_header: Option<PluginHeaderV1>,
@@ -199,11 +221,7 @@ fn process_update_plugin<'a, T: DataBlob + SolanaAccount>(
     // Move offsets for existing registry records.
     for record in &mut plugin_registry.external_registry {
         if registry_record.offset < record.offset {
-            let new_offset = (record.offset as isize)
-                .checked_add(size_diff)
-                .ok_or(MplCoreError::NumericalOverflow)?;
-
-            record.offset = new_offset as usize;
+            record.bump_offsets(size_diff)?;
         }
     }
 
@@ -1,7 +1,28 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_memory::sol_memcpy,
+};
 
-use crate::{error::MplCoreError, plugins::ExternalPluginAdapterKey};
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{
+        WriteCollectionExternalPluginAdapterDataV1Accounts,
+        WriteExternalPluginAdapterDataV1Accounts,
+    },
+    plugins::{
+        fetch_wrapped_external_plugin_adapter, find_external_plugin_adapter, ExternalPluginAdapter,
+        ExternalPluginAdapterKey, PluginHeaderV1, PluginRegistryV1,
+    },
+    state::{
+        AssetV1, Authority, CollectionV1, DataBlob, Key, MplCoreEvent, MplCoreEventPayloadV1,
+        SolanaAccount,
+    },
+    utils::{
+        load_key, resize_or_reallocate_account, resolve_authority, resolve_pubkey_to_authorities,
+        resolve_pubkey_to_authorities_collection,
+    },
+};
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
@@ -13,10 +34,61 @@ pub(crate) struct WriteExternalPluginAdapterDataV1Args {
 }
 
 pub(crate) fn write_external_plugin_adapter_data<'a>(
-    _accounts: &'a [AccountInfo<'a>],
-    _args: WriteExternalPluginAdapterDataV1Args,
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteExternalPluginAdapterDataV1Args,
 ) -> ProgramResult {
-    Err(MplCoreError::NotAvailable.into())
+    // Accounts.
+    let ctx = WriteExternalPluginAdapterDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+        msg!("Error: Writing external plugin adapter data for compressed is not available");
+        return Err(MplCoreError::NotAvailable.into());
+    }
+
+    let mut asset = AssetV1::load(ctx.accounts.asset, 0)?;
+
+    let (_, plugin) = fetch_wrapped_external_plugin_adapter::<AssetV1>(
+        ctx.accounts.asset,
+        Some(&asset),
+        &args.key,
+    )?;
+
+    let resolved_authorities =
+        resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset)?;
+    assert_data_authority(accounts, &plugin, &resolved_authorities)?;
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    process_write_external_plugin_adapter_data(
+        &asset,
+        &args.key,
+        &args.data,
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.asset.key,
+        asset.seq,
+        MplCoreEventPayloadV1::WriteExternalPluginAdapterData { key: args.key },
+    )
 }
 
 #[repr(C)]
@@ -29,8 +101,171 @@ pub(crate) struct WriteCollectionExternalPluginAdapterDataV1Args {
 }
 
 pub(crate) fn write_collection_external_plugin_adapter_data<'a>(
-    _accounts: &'a [AccountInfo<'a>],
-    _args: WriteCollectionExternalPluginAdapterDataV1Args,
+    accounts: &'a [AccountInfo<'a>],
+    args: WriteCollectionExternalPluginAdapterDataV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = WriteCollectionExternalPluginAdapterDataV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_program::system_program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &spl_noop::ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    let collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+
+    let (_, plugin) = fetch_wrapped_external_plugin_adapter::<CollectionV1>(
+        ctx.accounts.collection,
+        Some(&collection),
+        &args.key,
+    )?;
+
+    let resolved_authorities =
+        resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?;
+    assert_data_authority(accounts, &plugin, &resolved_authorities)?;
+
+    process_write_external_plugin_adapter_data(
+        &collection,
+        &args.key,
+        &args.data,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+
+    MplCoreEvent::emit_v1(
+        ctx.accounts.log_wrapper,
+        ctx.accounts.collection.key,
+        None,
+        MplCoreEventPayloadV1::WriteExternalPluginAdapterData { key: args.key },
+    )
+}
+
+/// Check that the signer resolves to the data authority of the external plugin adapter.
+fn assert_data_authority(
+    accounts: &[AccountInfo],
+    plugin: &ExternalPluginAdapter,
+    resolved_authorities: &[Authority],
 ) -> ProgramResult {
-    Err(MplCoreError::NotAvailable.into())
+    let data_authority = match plugin {
+        ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => lifecycle_hook
+            .data_authority
+            .as_ref()
+            .ok_or(MplCoreError::InvalidAuthority)?,
+        ExternalPluginAdapter::DataStore(data_store) => &data_store.data_authority,
+        ExternalPluginAdapter::Oracle(_) => {
+            msg!("Error: Oracle external plugin adapters do not store data");
+            return Err(MplCoreError::InvalidPluginOperation.into());
+        }
+    };
+
+    if !resolved_authorities.contains(data_authority)
+        && !data_authority.is_multisig_satisfied(accounts)
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    Ok(())
+}
+
+fn process_write_external_plugin_adapter_data<'a, T: DataBlob + SolanaAccount>(
+    core: &T,
+    key: &ExternalPluginAdapterKey,
+    data: &[u8],
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let mut plugin_header = PluginHeaderV1::load(account, core.get_size())?;
+    let mut plugin_registry =
+        PluginRegistryV1::load(account, plugin_header.plugin_registry_offset)?;
+
+    let (index, record) = find_external_plugin_adapter(&plugin_registry, key, account)?;
+    let index = index.ok_or(MplCoreError::ExternalPluginAdapterNotFound)?;
+    let mut registry_record = record
+        .ok_or(MplCoreError::ExternalPluginAdapterNotFound)?
+        .clone();
+
+    let data_offset = registry_record
+        .data_offset
+        .ok_or(MplCoreError::InvalidPluginOperation)?;
+    let data_len = registry_record
+        .data_len
+        .ok_or(MplCoreError::InvalidPluginOperation)?;
+
+    // The difference in size between the new and old data which is used to calculate the new size of the account.
+    let size_diff = (data.len() as isize)
+        .checked_sub(data_len as isize)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    // The new size of the account.
+    let new_size = (account.data_len() as isize)
+        .checked_add(size_diff)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    // The new offset of the plugin registry is the old offset plus the size difference.
+    let registry_offset = plugin_header.plugin_registry_offset;
+    let new_registry_offset = (registry_offset as isize)
+        .checked_add(size_diff)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+    plugin_header.plugin_registry_offset = new_registry_offset as usize;
+
+    // The offset of the next plugin is the data offset plus the size of the existing data.
+    let next_plugin_offset = data_offset
+        .checked_add(data_len)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    let new_next_plugin_offset = data_offset
+        .checked_add(data.len())
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    //TODO: This is memory intensive, we should use memmove instead probably.
+    let src = account.data.borrow()[next_plugin_offset..registry_offset].to_vec();
+
+    resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+
+    sol_memcpy(
+        &mut account.data.borrow_mut()[new_next_plugin_offset..],
+        &src,
+        src.len(),
+    );
+
+    sol_memcpy(
+        &mut account.data.borrow_mut()[data_offset..],
+        data,
+        data.len(),
+    );
+
+    plugin_header.save(account, core.get_size())?;
+
+    // Move offsets for existing registry records.
+    for record in &mut plugin_registry.external_registry {
+        if registry_record.offset < record.offset {
+            record.bump_offsets(size_diff)?;
+        }
+    }
+
+    for record in &mut plugin_registry.registry {
+        if registry_record.offset < record.offset {
+            let new_offset = (record.offset as isize)
+                .checked_add(size_diff)
+                .ok_or(MplCoreError::NumericalOverflow)?;
+
+            record.offset = new_offset as usize;
+        }
+    }
+
+    registry_record.data_len = Some(data.len());
+    plugin_registry.external_registry[index] = registry_record;
+
+    plugin_registry.save(account, new_registry_offset as usize)
 }
@@ -14,6 +14,9 @@ use crate::{
 
 use super::{Authority, CoreAsset, UpdateAuthority};
 
+/// The seed prefix for the asset signer PDA, which is derived from the asset address.
+pub const ASSET_SIGNER_PREFIX: &str = "mpl-core-execute";
+
 /// The Core Asset structure that exists at the beginning of every asset account.
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, Eq, PartialEq)]
 pub struct AssetV1 {
@@ -60,6 +63,14 @@ impl AssetV1 {
         Ok(())
     }
 
+    /// Find the signer PDA for the asset, which the program signs for in `ExecuteV1`.
+    pub fn find_signer_address(asset: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[ASSET_SIGNER_PREFIX.as_bytes(), asset.as_ref()],
+            &crate::ID,
+        )
+    }
+
     /// The base length of the asset account with an empty name and uri and no seq.
     pub const BASE_LENGTH: usize = 1 + 32 + 33 + 4 + 4 + 1;
 
@@ -113,6 +124,11 @@ impl AssetV1 {
         CheckResult::CanApprove
     }
 
+    /// Check permissions for the execute lifecycle event.
+    pub fn check_execute() -> CheckResult {
+        CheckResult::CanApprove
+    }
+
     /// Check permissions for the add external plugin adapter lifecycle event.
     pub fn check_add_external_plugin_adapter() -> CheckResult {
         CheckResult::CanApprove
@@ -296,6 +312,20 @@ impl AssetV1 {
         }
     }
 
+    /// Validate the execute lifecycle event.
+    pub fn validate_execute(
+        &self,
+        authority_info: &AccountInfo,
+        _: Option<&Plugin>,
+        _: Option<&ExternalPluginAdapter>,
+    ) -> Result<ValidationResult, ProgramError> {
+        if authority_info.key == &self.owner {
+            approve!()
+        } else {
+            abstain!()
+        }
+    }
+
     /// Validate the add external plugin adapter lifecycle event.
     pub fn validate_add_external_plugin_adapter(
         &self,
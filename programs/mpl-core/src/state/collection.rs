@@ -98,6 +98,11 @@ impl CollectionV1 {
         CheckResult::None
     }
 
+    /// Check permissions for the execute lifecycle event.
+    pub fn check_execute() -> CheckResult {
+        CheckResult::None
+    }
+
     /// Check permissions for the add external plugin adapter lifecycle event.
     pub fn check_add_external_plugin_adapter() -> CheckResult {
         CheckResult::CanApprove
@@ -261,6 +266,16 @@ impl CollectionV1 {
         abstain!()
     }
 
+    /// Validate the execute lifecycle event.
+    pub fn validate_execute(
+        &self,
+        _authority_info: &AccountInfo,
+        _: Option<&Plugin>,
+        _: Option<&ExternalPluginAdapter>,
+    ) -> Result<ValidationResult, ProgramError> {
+        abstain!()
+    }
+
     /// Validate the add external plugin adapter lifecycle event.
     pub fn validate_add_external_plugin_adapter(
         &self,
@@ -319,6 +334,17 @@ impl CollectionV1 {
         Ok(())
     }
 
+    /// Increment the current size of the Collection for an existing asset moved into it, without
+    /// counting it as minted.
+    pub fn increment_current_size(&mut self) -> Result<(), ProgramError> {
+        self.current_size = self
+            .current_size
+            .checked_add(1)
+            .ok_or(MplCoreError::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
     /// Decrements size of the Collection
     pub fn decrement(&mut self) -> Result<(), ProgramError> {
         self.current_size = self
@@ -0,0 +1,152 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke, pubkey::Pubkey,
+};
+
+use crate::{
+    plugins::{ExternalPluginAdapterKey, PluginType},
+    state::{Authority, UpdateAuthority},
+};
+
+/// The tag written before every event in the log wrapper data, so events can be told apart from
+/// the compression proofs that are also written through the log wrapper.
+pub const MPL_CORE_EVENT_TAG: [u8; 8] = *b"mplcevt1";
+
+/// A versioned event describing a state change to an asset or collection.  It is emitted through
+/// the log wrapper so indexers can follow changes without re-fetching the account.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum MplCoreEvent {
+    /// Version 1 of the event.
+    V1(MplCoreEventV1),
+}
+
+/// Version 1 of the `MplCoreEvent`.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct MplCoreEventV1 {
+    /// The address of the asset or collection that changed.
+    pub asset: Pubkey, //32
+    /// The sequence number of the asset after the change, if it is tracked.
+    pub seq: Option<u64>, //9
+    /// The change that was made.
+    pub payload: MplCoreEventPayloadV1,
+}
+
+/// The change described by a version 1 event.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum MplCoreEventPayloadV1 {
+    /// An asset was created.
+    Create {
+        /// The owner of the new asset.
+        owner: Pubkey,
+        /// The update authority of the new asset.
+        update_authority: UpdateAuthority,
+    },
+    /// An asset was transferred.
+    Transfer {
+        /// The new owner of the asset.
+        new_owner: Pubkey,
+    },
+    /// The base data of an asset or collection was updated.
+    Update {
+        /// The new name, if it was updated.
+        new_name: Option<String>,
+        /// The new URI, if it was updated.
+        new_uri: Option<String>,
+        /// The new update authority, if it was updated.
+        new_update_authority: Option<UpdateAuthority>,
+    },
+    /// An asset or collection was burned.
+    Burn,
+    /// A plugin was added.
+    AddPlugin {
+        /// The type of the plugin.
+        plugin_type: PluginType,
+        /// The authority of the plugin.
+        authority: Authority,
+    },
+    /// A plugin was removed.
+    RemovePlugin {
+        /// The type of the plugin.
+        plugin_type: PluginType,
+    },
+    /// A plugin was updated.
+    UpdatePlugin {
+        /// The type of the plugin.
+        plugin_type: PluginType,
+    },
+    /// A new authority was approved for a plugin.
+    ApprovePluginAuthority {
+        /// The type of the plugin.
+        plugin_type: PluginType,
+        /// The new authority of the plugin.
+        new_authority: Authority,
+    },
+    /// The authority of a plugin was revoked back to its manager.
+    RevokePluginAuthority {
+        /// The type of the plugin.
+        plugin_type: PluginType,
+    },
+    /// An external plugin adapter was added.
+    AddExternalPluginAdapter {
+        /// The key of the external plugin adapter.
+        key: ExternalPluginAdapterKey,
+    },
+    /// An external plugin adapter was removed.
+    RemoveExternalPluginAdapter {
+        /// The key of the external plugin adapter.
+        key: ExternalPluginAdapterKey,
+    },
+    /// An external plugin adapter was updated.
+    UpdateExternalPluginAdapter {
+        /// The key of the external plugin adapter.
+        key: ExternalPluginAdapterKey,
+    },
+    /// Data was written to an external plugin adapter.
+    WriteExternalPluginAdapterData {
+        /// The key of the external plugin adapter.
+        key: ExternalPluginAdapterKey,
+    },
+    /// A collection was created.
+    CreateCollection {
+        /// The update authority of the new collection.
+        update_authority: Pubkey,
+    },
+    /// A compressed asset was decompressed.
+    Decompress {
+        /// The owner of the asset.
+        owner: Pubkey,
+    },
+    /// An instruction was invoked as the asset signer.
+    Execute {
+        /// The program that was invoked.
+        program_id: Pubkey,
+    },
+}
+
+impl MplCoreEvent {
+    /// Emit a version 1 event through the log wrapper, prefixed with `MPL_CORE_EVENT_TAG`.
+    /// Nothing is emitted when the log wrapper was not passed to the instruction.
+    pub(crate) fn emit_v1(
+        log_wrapper: Option<&AccountInfo>,
+        asset: &Pubkey,
+        seq: Option<u64>,
+        payload: MplCoreEventPayloadV1,
+    ) -> ProgramResult {
+        if log_wrapper.is_none() {
+            return Ok(());
+        }
+
+        let mut data = MPL_CORE_EVENT_TAG.to_vec();
+        Self::V1(MplCoreEventV1 {
+            asset: *asset,
+            seq,
+            payload,
+        })
+        .serialize(&mut data)?;
+
+        invoke(&spl_noop::instruction(data), &[])
+    }
+}
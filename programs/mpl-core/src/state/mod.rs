@@ -10,6 +10,9 @@ pub use collection::*;
 mod compression_proof;
 pub use compression_proof::*;
 
+mod event;
+pub use event::*;
+
 mod hashable_plugin_schema;
 pub use hashable_plugin_schema::*;
 
@@ -27,7 +30,9 @@ pub use update_authority::*;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use num_derive::{FromPrimitive, ToPrimitive};
-use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::error::MplCoreError;
 
 /// An enum representing the two types of data, compressed (stored in ledger) and uncompressed (stored in account state).
 #[repr(C)]
@@ -41,7 +46,7 @@ pub enum DataState {
 
 /// Variants representing the different types of authority that can have permissions over plugins.
 #[repr(u8)]
-#[derive(Copy, Clone, BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum Authority {
     /// No authority, used for immutability.
     None,
@@ -54,6 +59,70 @@ pub enum Authority {
         /// The address of the authority.
         address: Pubkey,
     },
+    /// A set of addresses, at least `threshold` of which must sign the instruction.
+    Multisig {
+        /// The number of listed signers required.
+        threshold: u8,
+        /// The addresses that can sign for the authority.
+        signers: Vec<Pubkey>,
+    },
+}
+
+/// The maximum number of signers in a multisig authority.
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+impl Authority {
+    /// Validate that a multisig authority has a usable threshold and a unique signer set.
+    pub(crate) fn validate(&self) -> ProgramResult {
+        if let Authority::Multisig { threshold, signers } = self {
+            let mut unique_signers = signers.clone();
+            unique_signers.sort();
+            unique_signers.dedup();
+
+            if *threshold == 0
+                || usize::from(*threshold) > signers.len()
+                || signers.len() > MAX_MULTISIG_SIGNERS
+                || unique_signers.len() != signers.len()
+            {
+                return Err(MplCoreError::InvalidMultisigAuthority.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check if this is a multisig authority with enough of its signers among the instruction's
+    /// signing accounts.
+    pub(crate) fn is_multisig_satisfied(&self, accounts: &[AccountInfo]) -> bool {
+        match self {
+            Authority::Multisig { threshold, signers } => {
+                let signed = signers
+                    .iter()
+                    .filter(|signer| {
+                        accounts
+                            .iter()
+                            .any(|account| account.is_signer && account.key == *signer)
+                    })
+                    .count();
+                *threshold > 0 && signed >= usize::from(*threshold)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl DataBlob for Authority {
+    fn get_initial_size() -> usize {
+        1
+    }
+
+    fn get_size(&self) -> usize {
+        match self {
+            Authority::None | Authority::Owner | Authority::UpdateAuthority => 1,
+            Authority::Address { .. } => 1 + 32,
+            Authority::Multisig { signers, .. } => 1 + 1 + 4 + signers.len() * 32,
+        }
+    }
 }
 
 /// An enum representing account discriminators.
@@ -1,10 +1,10 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, io::Read};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
 use num_traits::{FromPrimitive, ToPrimitive};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
     program_error::ProgramError, program_memory::sol_memcpy, rent::Rent, system_instruction,
     sysvar::Sysvar,
 };
@@ -12,11 +12,11 @@ use solana_program::{
 use crate::{
     error::MplCoreError,
     plugins::{
-        create_meta_idempotent, initialize_plugin, validate_external_plugin_adapter_checks,
-        validate_plugin_checks, CheckResult, ExternalCheckResultBits, ExternalPluginAdapter,
-        ExternalPluginAdapterKey, ExternalRegistryRecord, HookableLifecycleEvent, Plugin,
-        PluginHeaderV1, PluginRegistryV1, PluginType, PluginValidationContext, RegistryRecord,
-        ValidationResult,
+        create_meta_idempotent, fetch_plugin, initialize_plugin,
+        validate_external_plugin_adapter_checks, validate_plugin_checks, CheckResult,
+        ExternalCheckResultBits, ExternalPluginAdapter, ExternalPluginAdapterKey,
+        ExternalRegistryRecord, HookableLifecycleEvent, MasterEdition, Plugin, PluginHeaderV1,
+        PluginRegistryV1, PluginType, PluginValidationContext, RegistryRecord, ValidationResult,
     },
     state::{
         AssetV1, Authority, CollectionV1, Compressible, CompressionProof, CoreAsset, DataBlob,
@@ -40,7 +40,8 @@ pub fn assert_authority<T: CoreAsset>(
     authority: &Authority,
 ) -> ProgramResult {
     match authority {
-        Authority::None => (),
+        // A multisig authority cannot be satisfied by a single authority account.
+        Authority::None | Authority::Multisig { .. } => (),
         Authority::Owner => {
             if asset.owner() == authority_info.key {
                 return Ok(());
@@ -68,7 +69,7 @@ pub fn assert_collection_authority(
     authority: &Authority,
 ) -> ProgramResult {
     match authority {
-        Authority::None | Authority::Owner => (),
+        Authority::None | Authority::Owner | Authority::Multisig { .. } => (),
         Authority::UpdateAuthority => {
             if &asset.update_authority == authority_info.key {
                 return Ok(());
@@ -84,6 +85,31 @@ pub fn assert_collection_authority(
     Err(MplCoreError::InvalidAuthority.into())
 }
 
+/// Assert that another asset can be added to the collection without exceeding the max supply
+/// of its `MasterEdition` plugin.  Returns the `MasterEdition` if the collection has one.
+pub(crate) fn assert_master_edition_supply(
+    collection_info: &AccountInfo,
+    collection: &CollectionV1,
+) -> Result<Option<MasterEdition>, ProgramError> {
+    let master_edition = match fetch_plugin::<CollectionV1, MasterEdition>(
+        collection_info,
+        PluginType::MasterEdition,
+    ) {
+        Ok((_, master_edition, _)) => master_edition,
+        Err(err) if err == MplCoreError::PluginNotFound.into() => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    if let Some(max_supply) = master_edition.max_supply {
+        if collection.num_minted >= max_supply {
+            msg!("Error: Collection has minted its max supply of editions");
+            return Err(MplCoreError::MaxSupplyReached.into());
+        }
+    }
+
+    Ok(Some(master_edition))
+}
+
 /// Fetch the core data from the account; asset, plugin header (if present), and plugin registry (if present).
 pub fn fetch_core_data<T: DataBlob + SolanaAccount>(
     account: &AccountInfo,
@@ -243,7 +269,7 @@ pub(crate) fn validate_asset_permissions<'a>(
     }
 
     let (deserialized_asset, plugin_header, plugin_registry) = fetch_core_data::<AssetV1>(asset)?;
-    let resolved_authorities =
+    let mut resolved_authorities =
         resolve_pubkey_to_authorities(authority_info, collection, &deserialized_asset)?;
 
     // If the asset is part of a collection, the collection must be passed in and it must be correct.
@@ -276,6 +302,7 @@ pub(crate) fn validate_asset_permissions<'a>(
         let (_, _, registry) = fetch_core_data::<CollectionV1>(collection_info)?;
 
         if let Some(r) = registry {
+            resolve_multisig_authorities(accounts, &r, &mut resolved_authorities);
             r.check_registry(Key::CollectionV1, plugin_check_fp, &mut checks);
 
             if let Some(lifecycle_event) = &hookable_lifecycle_event {
@@ -292,6 +319,7 @@ pub(crate) fn validate_asset_permissions<'a>(
     // Next check the asset plugins. Plugins on the asset override the collection plugins,
     // so we don't need to validate the collection plugins if the asset has a plugin.
     if let Some(registry) = plugin_registry.as_ref() {
+        resolve_multisig_authorities(accounts, registry, &mut resolved_authorities);
         registry.check_registry(Key::AssetV1, plugin_check_fp, &mut checks);
         if let Some(lifecycle_event) = &hookable_lifecycle_event {
             registry.check_adapter_registry(
@@ -470,7 +498,7 @@ pub(crate) fn validate_collection_permissions<'a>(
 
     let (deserialized_collection, plugin_header, plugin_registry) =
         fetch_core_data::<CollectionV1>(collection)?;
-    let resolved_authorities =
+    let mut resolved_authorities =
         resolve_pubkey_to_authorities_collection(authority_info, collection)?;
     let mut checks: BTreeMap<PluginType, (Key, CheckResult, RegistryRecord)> = BTreeMap::new();
     let mut external_checks: BTreeMap<
@@ -482,6 +510,7 @@ pub(crate) fn validate_collection_permissions<'a>(
 
     // Check the collection plugins.
     if let Some(registry) = plugin_registry.as_ref() {
+        resolve_multisig_authorities(accounts, registry, &mut resolved_authorities);
         registry.check_registry(Key::CollectionV1, plugin_check_fp, &mut checks);
         if let Some(lifecycle_event) = hookable_lifecycle_event {
             registry.check_adapter_registry(
@@ -624,6 +653,11 @@ pub fn compress_into_account_space<'a>(
     let mut compression_proof = CompressionProof::new(asset, seq, vec![]);
     let mut plugin_hashes = vec![];
     if let Some(plugin_registry) = plugin_registry {
+        // External plugin adapters and their data are not part of the hashed asset schema.
+        if !plugin_registry.external_registry.is_empty() {
+            return Err(MplCoreError::CannotCompressExternalPluginAdapters.into());
+        }
+
         let mut registry_records = plugin_registry.registry;
 
         // It should already be sorted but we just want to make sure.
@@ -719,6 +753,31 @@ pub(crate) fn resolve_pubkey_to_authorities_collection(
     Ok(authorities)
 }
 
+/// Add the multisig plugin authorities in the registry that are satisfied by the signers of the
+/// instruction to the resolved authorities.
+pub(crate) fn resolve_multisig_authorities(
+    accounts: &[AccountInfo],
+    registry: &PluginRegistryV1,
+    authorities: &mut Vec<Authority>,
+) {
+    let registry_authorities = registry
+        .registry
+        .iter()
+        .map(|record| &record.authority)
+        .chain(
+            registry
+                .external_registry
+                .iter()
+                .map(|record| &record.authority),
+        );
+
+    for authority in registry_authorities {
+        if authority.is_multisig_satisfied(accounts) && !authorities.contains(authority) {
+            authorities.push(authority.clone());
+        }
+    }
+}
+
 /// Resolves the authority for the transaction for an optional authority pattern.
 pub(crate) fn resolve_authority<'a>(
     payer: &'a AccountInfo<'a>,
@@ -732,3 +791,17 @@ pub(crate) fn resolve_authority<'a>(
         None => Ok(payer),
     }
 }
+
+/// Deserialize an `Option` field that was appended to existing instruction args.  Clients built
+/// before the field existed do not send it, so reaching the end of the data reads as `None`.
+pub(crate) fn deserialize_trailing_option<T: BorshDeserialize, R: Read>(
+    reader: &mut R,
+) -> std::io::Result<Option<T>> {
+    let mut tag = [0u8; 1];
+    if reader.read(&mut tag)? == 0 {
+        return Ok(None);
+    }
+
+    // Put the tag back in front of the reader so the `Option` is read as usual.
+    Option::<T>::deserialize_reader(&mut tag.as_slice().chain(reader))
+}